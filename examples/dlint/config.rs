@@ -3,6 +3,7 @@ use anyhow::bail;
 use anyhow::Error as AnyError;
 use deno_lint::rules::{get_all_rules, LintRule};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -12,6 +13,7 @@ pub struct RulesConfig {
   pub tags: Vec<String>,
   pub include: Vec<String>,
   pub exclude: Vec<String>,
+  pub options: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -29,7 +31,7 @@ pub struct Config {
 }
 
 impl Config {
-  pub fn get_rules(&self) -> Vec<Box<dyn LintRule>> {
+  pub fn get_rules(&self) -> Result<Vec<Box<dyn LintRule>>, AnyError> {
     let mut rules = get_all_rules();
 
     if !self.rules.tags.is_empty() {
@@ -64,7 +66,15 @@ impl Config {
       }
     }
 
-    rules
+    for rule in rules.iter_mut() {
+      if let Some(options) = self.rules.options.get(rule.code()) {
+        if let Err(err) = rule.set_options(options) {
+          bail!("Invalid options for \"{}\": {}", rule.code(), err);
+        }
+      }
+    }
+
+    Ok(rules)
   }
 
   pub fn get_files(&self) -> Result<Vec<PathBuf>, AnyError> {
@@ -7,6 +7,7 @@ use clap::App;
 use clap::AppSettings;
 use clap::Arg;
 use clap::SubCommand;
+use deno_lint::ast_parser::{get_default_ts_config, get_default_tsx_config};
 use deno_lint::diagnostic::LintDiagnostic;
 use deno_lint::diagnostic::Range;
 use deno_lint::linter::LinterBuilder;
@@ -113,6 +114,36 @@ fn display_diagnostics(
       vec![]
     };
 
+    let mut slices = vec![snippet::Slice {
+      source: &slice_source,
+      line_start: diagnostic.range.start.line,
+      origin: Some(&diagnostic.filename),
+      fold: false,
+      annotations: vec![snippet::SourceAnnotation {
+        range,
+        label: "",
+        annotation_type: snippet::AnnotationType::Error,
+      }],
+    }];
+    for related in &diagnostic.related {
+      let (slice_source, range) = get_slice_source_and_range(
+        &line_start_indexes,
+        source_code,
+        &related.range,
+      );
+      slices.push(snippet::Slice {
+        source: slice_source,
+        line_start: related.range.start.line,
+        origin: Some(&diagnostic.filename),
+        fold: false,
+        annotations: vec![snippet::SourceAnnotation {
+          range,
+          label: &related.message,
+          annotation_type: snippet::AnnotationType::Info,
+        }],
+      });
+    }
+
     let snippet = snippet::Snippet {
       title: Some(snippet::Annotation {
         label: Some(&diagnostic.message),
@@ -120,17 +151,7 @@ fn display_diagnostics(
         annotation_type: snippet::AnnotationType::Error,
       }),
       footer,
-      slices: vec![snippet::Slice {
-        source: &slice_source,
-        line_start: diagnostic.range.start.line,
-        origin: Some(&diagnostic.filename),
-        fold: false,
-        annotations: vec![snippet::SourceAnnotation {
-          range,
-          label: "",
-          annotation_type: snippet::AnnotationType::Error,
-        }],
-      }],
+      slices,
       opt: display_list::FormatOptions {
         color: true,
         anonymized_line_numbers: false,
@@ -152,6 +173,12 @@ fn run_linter(
 
   if let Some(config) = maybe_config.clone() {
     paths.extend(config.get_files()?);
+
+    // Report invalid rule options once, before linting any file.
+    if let Err(e) = config.get_rules() {
+      eprintln!("Failed to configure rules: {}", e);
+      std::process::exit(1);
+    }
   }
 
   let error_counts = Arc::new(AtomicUsize::new(0));
@@ -168,7 +195,7 @@ fn run_linter(
       std::fs::read_to_string(&file_path).expect("Failed to load file");
 
     let mut rules = if let Some(config) = maybe_config.clone() {
-      config.get_rules()
+      config.get_rules().expect("Failed to configure rules")
     } else {
       get_recommended_rules()
     };
@@ -182,7 +209,13 @@ fn run_linter(
 
     debug!("Configured rules: {}", rules.len());
 
+    let syntax = match file_path.extension().and_then(|ext| ext.to_str()) {
+      Some("jsx") | Some("tsx") => get_default_tsx_config(),
+      _ => get_default_ts_config(),
+    };
+
     let mut linter_builder = LinterBuilder::default()
+      .syntax(syntax)
       .rules(rules)
       .lint_unknown_rules(true)
       .lint_unused_ignore_directives(true);
@@ -54,6 +54,16 @@ pub fn get_default_ts_config() -> Syntax {
   Syntax::Typescript(ts_config)
 }
 
+pub fn get_default_tsx_config() -> Syntax {
+  let ts_config = TsConfig {
+    dynamic_import: true,
+    decorators: true,
+    tsx: true,
+    ..Default::default()
+  };
+  Syntax::Typescript(ts_config)
+}
+
 #[derive(Clone, Debug)]
 pub struct SwcDiagnosticBuffer {
   pub diagnostics: Vec<String>,
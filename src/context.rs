@@ -1,5 +1,7 @@
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{
+  LintDiagnostic, LintFix, LintFixChange, LintRelated, Position, Range,
+};
 use crate::ignore_directives::IgnoreDirective;
 use crate::scopes::Scope;
 use dprint_swc_ecma_ast_view::{self as AstView, BytePos, RootNode};
@@ -136,14 +138,55 @@ impl<'view> Context<'view> {
     self.diagnostics.push(diagnostic);
   }
 
-  pub(crate) fn create_diagnostic(
-    &self,
+  /// Adds a diagnostic along with a fix for it. Each change of the fix
+  /// replaces the source code covered by the span with the given text.
+  pub fn add_diagnostic_with_fix(
+    &mut self,
     span: Span,
     code: impl ToString,
     message: impl ToString,
-    maybe_hint: Option<String>,
-  ) -> LintDiagnostic {
-    let time_start = Instant::now();
+    hint: impl ToString,
+    fix_description: impl ToString,
+    changes: Vec<(Span, String)>,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, Some(hint.to_string()));
+    diagnostic.fixes.push(LintFix {
+      description: fix_description.to_string(),
+      changes: changes
+        .into_iter()
+        .map(|(span, new_text)| LintFixChange {
+          new_text,
+          range: self.create_range(span),
+        })
+        .collect(),
+    });
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Adds a diagnostic that points to other relevant locations too, each
+  /// described by the given message.
+  pub fn add_diagnostic_with_related(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    hint: impl ToString,
+    related: Vec<(Span, String)>,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, Some(hint.to_string()));
+    diagnostic.related = related
+      .into_iter()
+      .map(|(span, message)| LintRelated {
+        message,
+        range: self.create_range(span),
+      })
+      .collect();
+    self.diagnostics.push(diagnostic);
+  }
+
+  fn create_range(&self, span: Span) -> Range {
     let start = Position::new(
       self.source_map.lookup_byte_offset(span.lo()).pos,
       self.source_map.lookup_char_pos(span.lo()),
@@ -152,13 +195,25 @@ impl<'view> Context<'view> {
       self.source_map.lookup_byte_offset(span.hi()).pos,
       self.source_map.lookup_char_pos(span.hi()),
     );
+    Range { start, end }
+  }
 
+  pub(crate) fn create_diagnostic(
+    &self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    maybe_hint: Option<String>,
+  ) -> LintDiagnostic {
+    let time_start = Instant::now();
     let diagnostic = LintDiagnostic {
-      range: Range { start, end },
+      range: self.create_range(span),
       filename: self.file_name.clone(),
       message: message.to_string(),
       code: code.to_string(),
       hint: maybe_hint,
+      fixes: vec![],
+      related: vec![],
     };
 
     let time_end = Instant::now();
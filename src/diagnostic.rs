@@ -30,6 +30,28 @@ pub struct Range {
   pub end: Position,
 }
 
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFixChange {
+  pub new_text: String,
+  pub range: Range,
+}
+
+/// A set of changes to the source code that resolves a diagnostic.
+#[derive(Clone, Debug, Serialize)]
+pub struct LintFix {
+  pub description: String,
+  pub changes: Vec<LintFixChange>,
+}
+
+/// Another location in the source code that explains a diagnostic, e.g. the
+/// other occurrence of a duplicate.
+#[derive(Clone, Debug, Serialize)]
+pub struct LintRelated {
+  pub message: String,
+  pub range: Range,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct LintDiagnostic {
   pub range: Range,
@@ -37,4 +59,6 @@ pub struct LintDiagnostic {
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  pub fixes: Vec<LintFix>,
+  pub related: Vec<LintRelated>,
 }
@@ -1,30 +1,49 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::context::Context;
+use anyhow::Error as AnyError;
 use dprint_swc_ecma_ast_view::Program as ProgramView;
 
 pub mod adjacent_overload_signatures;
+pub mod array_callback_return;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod camelcase;
+pub mod consistent_type_definitions;
+pub mod consistent_type_imports;
 pub mod constructor_super;
+pub mod default_case;
+pub mod default_case_last;
 pub mod default_param_last;
 pub mod eqeqeq;
+pub mod exhaustive_deps;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod getter_return;
+pub mod guard_for_in;
+pub mod jsx_key;
+pub mod jsx_no_duplicate_props;
+pub mod member_ordering;
+pub mod method_signature_style;
+pub mod naming_convention;
+pub mod no_alert;
 pub mod no_array_constructor;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
+pub mod no_bitwise;
+pub mod no_boolean_literal_for_arguments;
+pub mod no_caller;
 pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
+pub mod no_console;
 pub mod no_const_assign;
 pub mod no_constant_condition;
 pub mod no_control_regex;
+pub mod no_danger;
 pub mod no_debugger;
 pub mod no_delete_var;
 pub mod no_deprecated_deno_api;
@@ -35,37 +54,57 @@ pub mod no_dupe_keys;
 pub mod no_duplicate_case;
 pub mod no_empty;
 pub mod no_empty_character_class;
+pub mod no_empty_function;
 pub mod no_empty_interface;
 pub mod no_empty_pattern;
+pub mod no_eq_null;
 pub mod no_eval;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
+pub mod no_extend_native;
 pub mod no_extra_boolean_cast;
 pub mod no_extra_non_null_assertion;
 pub mod no_extra_semi;
 pub mod no_fallthrough;
+pub mod no_floating_promises;
 pub mod no_func_assign;
 pub mod no_global_assign;
+pub mod no_implied_eval;
 pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
+pub mod no_invalid_void_type;
 pub mod no_irregular_whitespace;
+pub mod no_iterator;
+pub mod no_labels;
 pub mod no_misused_new;
 pub mod no_mixed_spaces_and_tabs;
+pub mod no_multi_assign;
 pub mod no_namespace;
+pub mod no_negated_condition;
 pub mod no_new_symbol;
+pub mod no_new_wrappers;
+pub mod no_node_globals;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_proto;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_remote_import;
+pub mod no_return_assign;
+pub mod no_return_await;
 pub mod no_self_assign;
+pub mod no_self_compare;
+pub mod no_sequences;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_sync_fn_in_async_fn;
+pub mod no_template_curly_in_string;
 pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
@@ -73,19 +112,39 @@ pub mod no_undef;
 pub mod no_unreachable;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
+pub mod no_unused_expressions;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_useless_call;
+pub mod no_useless_computed_key;
+pub mod no_useless_rename;
 pub mod no_var;
+pub mod no_void;
 pub mod no_with;
+pub mod object_shorthand;
 pub mod prefer_as_const;
+pub mod prefer_ascii;
 pub mod prefer_const;
+pub mod prefer_exponentiation_operator;
 pub mod prefer_namespace_keyword;
+pub mod prefer_numeric_literals;
+pub mod prefer_object_spread;
+pub mod prefer_promise_reject_errors;
+pub mod prefer_readonly;
+pub mod prefer_regex_literals;
+pub mod prefer_rest_params;
+pub mod prefer_spread;
+pub mod radix;
 pub mod require_await;
 pub mod require_yield;
+pub mod rules_of_hooks;
 pub mod single_var_declarator;
+pub mod symbol_description;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
+pub mod verbatim_module_syntax;
+pub mod yoda;
 
 const DUMMY_NODE: () = ();
 
@@ -126,6 +185,15 @@ pub trait LintRule {
   /// Returns the unique code that identifies the rule
   fn code(&self) -> &'static str;
 
+  /// Configures the rule with rule-specific options, e.g. ones loaded from a
+  /// config file. Rules that don't accept any options ignore them.
+  fn set_options(
+    &mut self,
+    _options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    Ok(())
+  }
+
   /// Returns the tags this rule belongs to, e.g. `recommended`
   fn tags(&self) -> &'static [&'static str] {
     &[]
@@ -141,28 +209,46 @@ pub trait LintRule {
 pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
   vec![
     adjacent_overload_signatures::AdjacentOverloadSignatures::new(),
+    array_callback_return::ArrayCallbackReturn::new(),
     ban_ts_comment::BanTsComment::new(),
     ban_types::BanTypes::new(),
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
     camelcase::Camelcase::new(),
+    consistent_type_definitions::ConsistentTypeDefinitions::new(),
+    consistent_type_imports::ConsistentTypeImports::new(),
     constructor_super::ConstructorSuper::new(),
+    default_case::DefaultCase::new(),
+    default_case_last::DefaultCaseLast::new(),
     default_param_last::DefaultParamLast::new(),
     eqeqeq::Eqeqeq::new(),
+    exhaustive_deps::ExhaustiveDeps::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
     getter_return::GetterReturn::new(),
+    guard_for_in::GuardForIn::new(),
+    jsx_key::JsxKey::new(),
+    jsx_no_duplicate_props::JsxNoDuplicateProps::new(),
+    member_ordering::MemberOrdering::new(),
+    method_signature_style::MethodSignatureStyle::new(),
+    naming_convention::NamingConvention::new(),
+    no_alert::NoAlert::new(),
     no_array_constructor::NoArrayConstructor::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
+    no_bitwise::NoBitwise::new(),
+    no_boolean_literal_for_arguments::NoBooleanLiteralForArguments::new(),
+    no_caller::NoCaller::new(),
     no_case_declarations::NoCaseDeclarations::new(),
     no_class_assign::NoClassAssign::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
+    no_console::NoConsole::new(),
     no_const_assign::NoConstAssign::new(),
     no_constant_condition::NoConstantCondition::new(),
     no_control_regex::NoControlRegex::new(),
+    no_danger::NoDanger::new(),
     no_debugger::NoDebugger::new(),
     no_delete_var::NoDeleteVar::new(),
     no_deprecated_deno_api::NoDeprecatedDenoApi::new(),
@@ -173,37 +259,57 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_duplicate_case::NoDuplicateCase::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
+    no_empty_function::NoEmptyFunction::new(),
     no_empty_interface::NoEmptyInterface::new(),
     no_empty_pattern::NoEmptyPattern::new(),
+    no_eq_null::NoEqNull::new(),
     no_eval::NoEval::new(),
     no_ex_assign::NoExAssign::new(),
     no_explicit_any::NoExplicitAny::new(),
+    no_extend_native::NoExtendNative::new(),
     no_extra_boolean_cast::NoExtraBooleanCast::new(),
     no_extra_non_null_assertion::NoExtraNonNullAssertion::new(),
     no_extra_semi::NoExtraSemi::new(),
     no_fallthrough::NoFallthrough::new(),
+    no_floating_promises::NoFloatingPromises::new(),
     no_func_assign::NoFuncAssign::new(),
     no_global_assign::NoGlobalAssign::new(),
+    no_implied_eval::NoImpliedEval::new(),
     no_import_assign::NoImportAssign::new(),
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
+    no_invalid_void_type::NoInvalidVoidType::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
+    no_iterator::NoIterator::new(),
+    no_labels::NoLabels::new(),
     no_misused_new::NoMisusedNew::new(),
     no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::new(),
+    no_multi_assign::NoMultiAssign::new(),
     no_namespace::NoNamespace::new(),
+    no_negated_condition::NoNegatedCondition::new(),
     no_new_symbol::NoNewSymbol::new(),
+    no_new_wrappers::NoNewWrappers::new(),
+    no_node_globals::NoNodeGlobals::new(),
     no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain::new(),
     no_non_null_assertion::NoNonNullAssertion::new(),
     no_obj_calls::NoObjCalls::new(),
     no_octal::NoOctal::new(),
+    no_proto::NoProto::new(),
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_remote_import::NoRemoteImport::new(),
+    no_return_assign::NoReturnAssign::new(),
+    no_return_await::NoReturnAwait::new(),
     no_self_assign::NoSelfAssign::new(),
+    no_self_compare::NoSelfCompare::new(),
+    no_sequences::NoSequences::new(),
     no_setter_return::NoSetterReturn::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
     no_sparse_arrays::NoSparseArrays::new(),
+    no_sync_fn_in_async_fn::NoSyncFnInAsyncFn::new(),
+    no_template_curly_in_string::NoTemplateCurlyInString::new(),
     no_this_alias::NoThisAlias::new(),
     no_this_before_super::NoThisBeforeSuper::new(),
     no_throw_literal::NoThrowLiteral::new(),
@@ -211,19 +317,39 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_unreachable::NoUnreachable::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
+    no_unused_expressions::NoUnusedExpressions::new(),
     no_unused_labels::NoUnusedLabels::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_useless_call::NoUselessCall::new(),
+    no_useless_computed_key::NoUselessComputedKey::new(),
+    no_useless_rename::NoUselessRename::new(),
     no_var::NoVar::new(),
+    no_void::NoVoid::new(),
     no_with::NoWith::new(),
+    object_shorthand::ObjectShorthand::new(),
     prefer_as_const::PreferAsConst::new(),
+    prefer_ascii::PreferAscii::new(),
     prefer_const::PreferConst::new(),
+    prefer_exponentiation_operator::PreferExponentiationOperator::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_numeric_literals::PreferNumericLiterals::new(),
+    prefer_object_spread::PreferObjectSpread::new(),
+    prefer_promise_reject_errors::PreferPromiseRejectErrors::new(),
+    prefer_readonly::PreferReadonly::new(),
+    prefer_regex_literals::PreferRegexLiterals::new(),
+    prefer_rest_params::PreferRestParams::new(),
+    prefer_spread::PreferSpread::new(),
+    radix::Radix::new(),
     require_await::RequireAwait::new(),
     require_yield::RequireYield::new(),
+    rules_of_hooks::RulesOfHooks::new(),
     single_var_declarator::SingleVarDeclarator::new(),
+    symbol_description::SymbolDescription::new(),
     triple_slash_reference::TripleSlashReference::new(),
     use_isnan::UseIsNaN::new(),
     valid_typeof::ValidTypeof::new(),
+    verbatim_module_syntax::VerbatimModuleSyntax::new(),
+    yoda::Yoda::new(),
   ]
 }
 
@@ -0,0 +1,497 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{skip_wrappers, static_member_prop_name};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Class, Constructor, Expr, Function,
+  ReturnStmt, UnaryOp,
+};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Default)]
+pub struct ArrayCallbackReturn {
+  options: ArrayCallbackReturnOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct ArrayCallbackReturnOptions {
+  /// Also reports `forEach()` callbacks that return a value.
+  check_for_each: bool,
+}
+
+const CODE: &str = "array-callback-return";
+
+/// Methods whose callback is expected to return a value.
+const METHODS: &[&str] = &[
+  "every",
+  "filter",
+  "find",
+  "findIndex",
+  "findLast",
+  "findLastIndex",
+  "flatMap",
+  "map",
+  "reduce",
+  "reduceRight",
+  "some",
+  "sort",
+  "toSorted",
+];
+
+#[derive(Display)]
+enum ArrayCallbackReturnMessage {
+  #[display(fmt = "Expected a return value in the `{}()` callback", _0)]
+  Expected(String),
+  #[display(fmt = "Expected the `{}()` callback to always return a value", _0)]
+  ExpectedAlways(String),
+  #[display(fmt = "Unexpected return value in the `forEach()` callback")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum ArrayCallbackReturnHint {
+  #[display(fmt = "Return a value on every code path of the callback")]
+  Return,
+  #[display(
+    fmt = "Remove the returned value, or use a `for...of` loop instead"
+  )]
+  NoReturn,
+}
+
+impl LintRule for ArrayCallbackReturn {
+  fn new() -> Box<Self> {
+    Box::new(ArrayCallbackReturn::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    ArrayCallbackReturnHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces returning a value from callbacks of array methods
+
+Methods like `map()`, `filter()` and `reduce()` use the value returned by their
+callback. A callback that doesn't return anything on some code path produces
+`undefined` there, which is usually a mistake, e.g. a forgotten `return` in a
+block body. Callbacks of `every`, `filter`, `find`, `findIndex`, `findLast`,
+`findLastIndex`, `flatMap`, `map`, `reduce`, `reduceRight`, `some`, `sort`,
+`toSorted` and `Array.from` are checked.
+
+With `checkForEach` (`false` by default), `forEach()` callbacks are checked for
+the opposite, since the value they return is ignored.
+
+### Invalid:
+```typescript
+const doubled = numbers.map((n) => {
+  n * 2;
+});
+
+const positive = numbers.filter((n) => {
+  if (n > 0) {
+    return true;
+  }
+});
+
+// with { "checkForEach": true }
+numbers.forEach((n) => console.log(n));
+numbers.forEach((n) => {
+  return n * 2;
+});
+```
+
+### Valid:
+```typescript
+const doubled = numbers.map((n) => n * 2);
+
+const positive = numbers.filter((n) => {
+  if (n > 0) {
+    return true;
+  }
+  return false;
+});
+
+// with { "checkForEach": true }
+numbers.forEach((n) => {
+  console.log(n);
+});
+numbers.forEach((n) => void console.log(n));
+```
+"#
+  }
+}
+
+/// Returns the name of the method and its callback if the call is one of the
+/// checked array methods.
+fn method_and_callback<'a>(
+  call_expr: &'a AstView::CallExpr,
+  check_for_each: bool,
+) -> Option<(String, &'a Expr)> {
+  let callee = match &call_expr.callee {
+    AstView::ExprOrSuper::Expr(callee) => skip_wrappers(callee),
+    AstView::ExprOrSuper::Super(_) => return None,
+  };
+  let member_expr = match callee {
+    AstView::Expr::Member(member_expr) => member_expr,
+    _ => return None,
+  };
+  let method = static_member_prop_name(member_expr.inner)?;
+
+  let is_array_from = method == "from"
+    && matches!(
+      &member_expr.obj,
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj))
+        if *obj.sym() == *"Array"
+    );
+  let index = if is_array_from {
+    1
+  } else if METHODS.contains(&method.as_str())
+    || (check_for_each && method == "forEach")
+  {
+    0
+  } else {
+    return None;
+  };
+
+  let callback = call_expr.inner.args.get(index)?;
+  if callback.spread.is_some() {
+    return None;
+  }
+  Some((method, &*callback.expr))
+}
+
+/// Collects the return statements of a function body, skipping nested
+/// functions and classes.
+#[derive(Default)]
+struct ReturnCollector {
+  with_value: Vec<Span>,
+  without_value: Vec<Span>,
+}
+
+impl ReturnCollector {
+  fn collect(body: &BlockStmt) -> Self {
+    let mut collector = Self::default();
+    body.visit_children_with(&mut collector);
+    collector
+  }
+}
+
+impl Visit for ReturnCollector {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
+    if return_stmt.arg.is_some() {
+      self.with_value.push(return_stmt.span);
+    } else {
+      self.without_value.push(return_stmt.span);
+    }
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+  fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+/// The body of a callback, either a block or the expression an arrow function
+/// returns.
+enum CallbackBody<'a> {
+  Block(&'a BlockStmt),
+  Expr(&'a Expr),
+}
+
+/// Returns the body of the callback, unless it's an async function or a
+/// generator, whose return values are wrapped anyway.
+fn callback_body(callback: &Expr) -> Option<CallbackBody<'_>> {
+  match callback {
+    Expr::Paren(paren) => callback_body(&paren.expr),
+    Expr::Fn(fn_expr) => {
+      let function = &fn_expr.function;
+      if function.is_async || function.is_generator {
+        return None;
+      }
+      function.body.as_ref().map(CallbackBody::Block)
+    }
+    Expr::Arrow(arrow_expr) => {
+      if arrow_expr.is_async || arrow_expr.is_generator {
+        return None;
+      }
+      match &arrow_expr.body {
+        BlockStmtOrExpr::BlockStmt(block_stmt) => {
+          Some(CallbackBody::Block(block_stmt))
+        }
+        BlockStmtOrExpr::Expr(expr) => Some(CallbackBody::Expr(expr)),
+      }
+    }
+    _ => None,
+  }
+}
+
+struct ArrayCallbackReturnHandler {
+  options: ArrayCallbackReturnOptions,
+}
+
+impl ArrayCallbackReturnHandler {
+  fn check_returns_value(
+    &self,
+    method: String,
+    callback: &Expr,
+    body: &BlockStmt,
+    ctx: &mut Context,
+  ) {
+    let returns = ReturnCollector::collect(body);
+    let reaches_end = ctx
+      .control_flow()
+      .meta(body.span.lo)
+      .map_or(true, |meta| meta.continues_execution());
+    if !reaches_end && returns.without_value.is_empty() {
+      return;
+    }
+
+    let message = if returns.with_value.is_empty() {
+      ArrayCallbackReturnMessage::Expected(method)
+    } else {
+      ArrayCallbackReturnMessage::ExpectedAlways(method)
+    };
+    ctx.add_diagnostic_with_hint(
+      callback.span(),
+      CODE,
+      message,
+      ArrayCallbackReturnHint::Return,
+    );
+  }
+
+  fn check_returns_nothing(
+    &self,
+    callback: &Expr,
+    body: CallbackBody,
+    ctx: &mut Context,
+  ) {
+    let returns_value = match body {
+      CallbackBody::Block(block_stmt) => {
+        !ReturnCollector::collect(block_stmt).with_value.is_empty()
+      }
+      CallbackBody::Expr(expr) => !matches!(
+        expr,
+        Expr::Unary(unary_expr)
+          if unary_expr.op == UnaryOp::Void
+      ),
+    };
+    if returns_value {
+      ctx.add_diagnostic_with_hint(
+        callback.span(),
+        CODE,
+        ArrayCallbackReturnMessage::Unexpected,
+        ArrayCallbackReturnHint::NoReturn,
+      );
+    }
+  }
+}
+
+impl Handler for ArrayCallbackReturnHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let (method, callback) =
+      match method_and_callback(call_expr, self.options.check_for_each) {
+        Some(found) => found,
+        None => return,
+      };
+    let body = match callback_body(callback) {
+      Some(body) => body,
+      None => return,
+    };
+
+    if method == "forEach" {
+      self.check_returns_nothing(callback, body, ctx);
+    } else if let CallbackBody::Block(block_stmt) = body {
+      self.check_returns_value(method, callback, block_stmt, ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn array_callback_return_valid() {
+    assert_lint_ok! {
+      ArrayCallbackReturn,
+      "foo.map((x) => x * 2);",
+      "foo.map(function (x) { return x * 2; });",
+      "foo.filter((x) => { if (x) { return true; } return false; });",
+      "foo.every((x) => { if (x) { return true; } else { return false; } });",
+      "foo.find((x) => { switch (x) { case 1: return true; default: return false; } });",
+      "foo.map(() => { throw new Error(); });",
+      "foo.reduce((acc, x) => { acc.push(x); return acc; }, []);",
+      "foo.sort((a, b) => { return a - b; });",
+      "Array.from(foo, (x) => { return x; });",
+      "Array.from(foo);",
+      "foo.map(async (x) => { await x; });",
+      "foo.map(function* (x) { yield x; });",
+      "foo.map((x) => { return () => { x; }; });",
+      "foo.map((x) => { function bar() { return; } return bar; });",
+      "foo.map(callback);",
+      "foo.map(...callbacks);",
+      "foo.forEach((x) => { x; });",
+      "foo.forEach((x) => x);",
+      "foo.bar((x) => { x; });",
+      "foo[map]((x) => { x; });",
+      "map((x) => { x; });",
+    };
+
+    assert_lint_ok! {
+      ArrayCallbackReturn,
+      options: { "checkForEach": true },
+      "foo.forEach((x) => { x; });",
+      "foo.forEach((x) => { if (x) { return; } bar(x); });",
+      "foo.forEach((x) => void bar(x));",
+      "foo.forEach(function (x) { bar(() => { return x; }); });",
+      "foo.forEach(async (x) => { return await x; });",
+    };
+  }
+
+  #[test]
+  fn array_callback_return_invalid() {
+    assert_lint_err! {
+      ArrayCallbackReturn,
+      "foo.map((x) => { x * 2; });": [
+        {
+          col: 8,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "map"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.filter(function (x) {});": [
+        {
+          col: 11,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "filter"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.filter((x) => { if (x) { return true; } });": [
+        {
+          col: 11,
+          message: variant!(ArrayCallbackReturnMessage, ExpectedAlways, "filter"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.some((x) => { if (x) { return; } return true; });": [
+        {
+          col: 9,
+          message: variant!(ArrayCallbackReturnMessage, ExpectedAlways, "some"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.every((x) => { return; });": [
+        {
+          col: 10,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "every"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.reduce((acc, x) => { acc.push(x); }, []);": [
+        {
+          col: 11,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "reduce"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.find((x) => { switch (x) { case 1: return true; } });": [
+        {
+          col: 9,
+          message: variant!(ArrayCallbackReturnMessage, ExpectedAlways, "find"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.map((x) => { const f = () => { return x; }; });": [
+        {
+          col: 8,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "map"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "Array.from(foo, (x) => { x; });": [
+        {
+          col: 16,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "from"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo?.map((x) => { x; });": [
+        {
+          col: 9,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "map"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo['flatMap']((x) => { x; });": [
+        {
+          col: 15,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "flatMap"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+      "foo.map((x) => { foo.map((y) => { y; }); return x; });": [
+        {
+          col: 25,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "map"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ArrayCallbackReturn,
+      options: { "checkForEach": true },
+      "foo.forEach((x) => bar(x));": [
+        {
+          col: 12,
+          message: ArrayCallbackReturnMessage::Unexpected,
+          hint: ArrayCallbackReturnHint::NoReturn,
+        }
+      ],
+      "foo.forEach(function (x) { if (x) { return x; } });": [
+        {
+          col: 12,
+          message: ArrayCallbackReturnMessage::Unexpected,
+          hint: ArrayCallbackReturnHint::NoReturn,
+        }
+      ],
+      "foo.map((x) => { x; });": [
+        {
+          col: 8,
+          message: variant!(ArrayCallbackReturnMessage, Expected, "map"),
+          hint: ArrayCallbackReturnHint::Return,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,335 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+
+#[derive(Default)]
+pub struct ConsistentTypeDefinitions {
+  mode: Mode,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+  /// Object types have to be defined with `interface`.
+  Interface,
+  /// Object types have to be defined with `type`.
+  Type,
+}
+
+impl Default for Mode {
+  fn default() -> Self {
+    Mode::Interface
+  }
+}
+
+const CODE: &str = "consistent-type-definitions";
+
+#[derive(Display)]
+enum ConsistentTypeDefinitionsMessage {
+  #[display(fmt = "Use an `interface` instead of a `type`")]
+  UseInterface,
+  #[display(fmt = "Use a `type` instead of an `interface`")]
+  UseType,
+}
+
+#[derive(Display)]
+enum ConsistentTypeDefinitionsHint {
+  #[display(fmt = "Convert it to an `interface` declaration")]
+  UseInterface,
+  #[display(fmt = "Convert it to a `type` alias")]
+  UseType,
+}
+
+const USE_INTERFACE_FIX_DESC: &str = "Convert to `interface`";
+const USE_TYPE_FIX_DESC: &str = "Convert to `type`";
+
+impl LintRule for ConsistentTypeDefinitions {
+  fn new() -> Box<Self> {
+    Box::new(ConsistentTypeDefinitions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    ConsistentTypeDefinitionsHandler { mode: self.mode }
+      .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces using either `interface` or `type` for object type definitions
+
+Object types can be defined both as an `interface` and as a `type` alias of a
+type literal. This rule enforces one of them, `interface` by default or `type`
+with the `"type"` option.
+
+Only type aliases of plain type literals are reported in `interface` mode,
+since other types like unions can't be written as interfaces. Interfaces
+inside `declare global` are never reported, because augmenting global types
+requires interfaces. Interfaces extending other types are reported but not
+fixed automatically.
+
+### Invalid:
+```typescript
+type Foo = { bar: string };
+
+// with "type"
+interface Foo {
+  bar: string;
+}
+```
+
+### Valid:
+```typescript
+interface Foo {
+  bar: string;
+}
+type Bar = string | number;
+
+// with "type"
+type Foo = { bar: string };
+```
+"#
+  }
+}
+
+/// Returns the span of the `type` or `interface` keyword, which is the token
+/// right before the name.
+fn keyword_span(id: &AstView::Ident) -> Span {
+  id.previous_token()
+    .map_or_else(|| id.span(), |keyword| keyword.span)
+}
+
+/// Returns the name along with the type parameters, e.g. `Foo<T>`.
+fn name_with_type_params(
+  id: &AstView::Ident,
+  type_params: Option<&AstView::TsTypeParamDecl>,
+) -> String {
+  match type_params {
+    Some(type_params) => format!("{}{}", id.text(), type_params.text()),
+    None => id.text().to_string(),
+  }
+}
+
+fn is_in_declare_global(node: AstView::Node) -> bool {
+  node.ancestors().any(|ancestor| {
+    matches!(
+      ancestor,
+      AstView::Node::TsModuleDecl(module_decl) if module_decl.global()
+    )
+  })
+}
+
+struct ConsistentTypeDefinitionsHandler {
+  mode: Mode,
+}
+
+impl Handler for ConsistentTypeDefinitionsHandler {
+  fn ts_type_alias_decl(
+    &mut self,
+    ts_type_alias_decl: &AstView::TsTypeAliasDecl,
+    ctx: &mut Context,
+  ) {
+    if self.mode != Mode::Interface {
+      return;
+    }
+
+    let type_lit = match &ts_type_alias_decl.type_ann {
+      AstView::TsType::TsTypeLit(type_lit) => type_lit,
+      _ => return,
+    };
+
+    let keyword = keyword_span(ts_type_alias_decl.id);
+    let fixed = format!(
+      "interface {} {}",
+      name_with_type_params(
+        ts_type_alias_decl.id,
+        ts_type_alias_decl.type_params
+      ),
+      type_lit.text()
+    );
+    ctx.add_diagnostic_with_fix(
+      keyword,
+      CODE,
+      ConsistentTypeDefinitionsMessage::UseInterface,
+      ConsistentTypeDefinitionsHint::UseInterface,
+      USE_INTERFACE_FIX_DESC,
+      vec![(keyword.with_hi(ts_type_alias_decl.span().hi), fixed)],
+    );
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    ts_interface_decl: &AstView::TsInterfaceDecl,
+    ctx: &mut Context,
+  ) {
+    if self.mode != Mode::Type || is_in_declare_global(ts_interface_decl.into())
+    {
+      return;
+    }
+
+    let keyword = keyword_span(ts_interface_decl.id);
+    // A type alias can't be exported as default, and extends clauses would
+    // have to be rewritten as intersections.
+    let is_default_export = matches!(
+      ts_interface_decl.parent(),
+      Some(AstView::Node::ExportDefaultDecl(_))
+    );
+    if is_default_export || !ts_interface_decl.extends.is_empty() {
+      ctx.add_diagnostic_with_hint(
+        keyword,
+        CODE,
+        ConsistentTypeDefinitionsMessage::UseType,
+        ConsistentTypeDefinitionsHint::UseType,
+      );
+      return;
+    }
+
+    let fixed = format!(
+      "type {} = {};",
+      name_with_type_params(
+        ts_interface_decl.id,
+        ts_interface_decl.type_params
+      ),
+      ts_interface_decl.body.text()
+    );
+    ctx.add_diagnostic_with_fix(
+      keyword,
+      CODE,
+      ConsistentTypeDefinitionsMessage::UseType,
+      ConsistentTypeDefinitionsHint::UseType,
+      USE_TYPE_FIX_DESC,
+      vec![(keyword.with_hi(ts_interface_decl.span().hi), fixed)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn consistent_type_definitions_valid() {
+    assert_lint_ok! {
+      ConsistentTypeDefinitions,
+      "interface Foo { bar: string }",
+      "type Foo = string;",
+      "type Foo = string | { bar: string };",
+      "type Foo = { bar: string } & Baz;",
+      "type Foo = ({ bar: string });",
+      "let foo: { bar: string };",
+    };
+
+    assert_lint_ok! {
+      ConsistentTypeDefinitions,
+      options: "type",
+      "type Foo = { bar: string };",
+      "declare global { interface Window { foo: string } }",
+    };
+  }
+
+  #[test]
+  fn consistent_type_definitions_invalid() {
+    assert_lint_err! {
+      ConsistentTypeDefinitions,
+      "type Foo = { bar: string };": [
+        {
+          col: 0,
+          message: ConsistentTypeDefinitionsMessage::UseInterface,
+          hint: ConsistentTypeDefinitionsHint::UseInterface,
+          fix: (USE_INTERFACE_FIX_DESC, "interface Foo { bar: string }"),
+        }
+      ],
+      "export type Foo<T> = { bar: T; baz(): void };": [
+        {
+          col: 7,
+          message: ConsistentTypeDefinitionsMessage::UseInterface,
+          hint: ConsistentTypeDefinitionsHint::UseInterface,
+          fix: (
+            USE_INTERFACE_FIX_DESC,
+            "export interface Foo<T> { bar: T; baz(): void }"
+          ),
+        }
+      ],
+      "declare type Foo = {};": [
+        {
+          col: 8,
+          message: ConsistentTypeDefinitionsMessage::UseInterface,
+          hint: ConsistentTypeDefinitionsHint::UseInterface,
+          fix: (USE_INTERFACE_FIX_DESC, "declare interface Foo {}"),
+        }
+      ],
+      "namespace Foo { type Bar = { baz: string } }": [
+        {
+          col: 16,
+          message: ConsistentTypeDefinitionsMessage::UseInterface,
+          hint: ConsistentTypeDefinitionsHint::UseInterface,
+          fix: (
+            USE_INTERFACE_FIX_DESC,
+            "namespace Foo { interface Bar { baz: string } }"
+          ),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ConsistentTypeDefinitions,
+      options: "type",
+      "interface Foo { bar: string }": [
+        {
+          col: 0,
+          message: ConsistentTypeDefinitionsMessage::UseType,
+          hint: ConsistentTypeDefinitionsHint::UseType,
+          fix: (USE_TYPE_FIX_DESC, "type Foo = { bar: string };"),
+        }
+      ],
+      "export interface Foo<T> { bar: T; baz(): void; }": [
+        {
+          col: 7,
+          message: ConsistentTypeDefinitionsMessage::UseType,
+          hint: ConsistentTypeDefinitionsHint::UseType,
+          fix: (
+            USE_TYPE_FIX_DESC,
+            "export type Foo<T> = { bar: T; baz(): void; };"
+          ),
+        }
+      ],
+      "interface Foo extends Bar { baz: string }": [
+        {
+          col: 0,
+          message: ConsistentTypeDefinitionsMessage::UseType,
+          hint: ConsistentTypeDefinitionsHint::UseType,
+        }
+      ],
+      "export default interface Foo { bar: string }": [
+        {
+          col: 15,
+          message: ConsistentTypeDefinitionsMessage::UseType,
+          hint: ConsistentTypeDefinitionsHint::UseType,
+        }
+      ],
+    };
+  }
+}
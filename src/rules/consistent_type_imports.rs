@@ -0,0 +1,494 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{import_type_fix, specifier_local, UsageCollector};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::visit::VisitWith;
+
+#[derive(Default)]
+pub struct ConsistentTypeImports {
+  options: ConsistentTypeImportsOptions,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum Prefer {
+  /// Imports only used as types have to use `import type`.
+  TypeImports,
+  /// `import type` is not allowed.
+  NoTypeImports,
+}
+
+impl Default for Prefer {
+  fn default() -> Self {
+    Prefer::TypeImports
+  }
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct ConsistentTypeImportsOptions {
+  prefer: Prefer,
+  /// The function JSX is compiled to, like `"React.createElement"` or `"h"`.
+  jsx_factory: Option<String>,
+}
+
+const CODE: &str = "consistent-type-imports";
+
+#[derive(Display)]
+enum ConsistentTypeImportsMessage {
+  #[display(fmt = "All imports in the declaration are only used as types")]
+  AllTypeOnly,
+  #[display(fmt = "Imports {} are only used as types", _0)]
+  SomeTypeOnly(String),
+  #[display(fmt = "Use `import` instead of `import type`")]
+  TypeImport,
+}
+
+#[derive(Display)]
+enum ConsistentTypeImportsHint {
+  #[display(
+    fmt = "Use `import type` so that the module isn't loaded at runtime just for types"
+  )]
+  UseTypeImport,
+  #[display(fmt = "Move them into a separate `import type` declaration")]
+  SplitTypeImport,
+  #[display(fmt = "Remove the `type` keyword")]
+  RemoveType,
+}
+
+const USE_TYPE_IMPORT_FIX_DESC: &str = "Use `import type`";
+const REMOVE_TYPE_FIX_DESC: &str = "Remove `type`";
+
+impl LintRule for ConsistentTypeImports {
+  fn new() -> Box<Self> {
+    Box::new(ConsistentTypeImports::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    // Scripts can't contain imports.
+    let module = match program {
+      AstView::Program::Module(module) => module,
+      AstView::Program::Script(_) => return,
+    };
+
+    let mut usages =
+      UsageCollector::new(context, self.options.jsx_factory.as_deref());
+    if self.options.prefer == Prefer::TypeImports {
+      module.inner.visit_with(&DUMMY_NODE, &mut usages);
+    }
+
+    ConsistentTypeImportsHandler {
+      prefer: self.options.prefer,
+      usages,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces `import type` for imports that are only used as types
+
+Imports written with `import type` are erased entirely when the code is
+transpiled, while regular imports make Deno load and evaluate the imported
+module at runtime even if the imported bindings are only used in type
+annotations. Using `import type` for such imports avoids that, and makes it
+clear which imports exist only for the type checker.
+
+An import is considered type-only if it's used at least once and only ever in
+type positions, including `typeof` type queries and `implements` clauses.
+Imports that aren't used at all are left to `no-unused-vars`, and declarations
+that only combine them with type-only imports to `verbatim-module-syntax`.
+
+JSX elements and fragments use the JSX factory implicitly. It's taken from a
+`/** @jsx h */` pragma in the file, or from the `jsxFactory` option, like
+`{ "jsxFactory": "React.createElement" }`. Imports in files with JSX aren't
+reported without either, since any of them may be the factory.
+
+With `{ "prefer": "no-type-imports" }` the opposite style is enforced and
+`import type` isn't allowed at all.
+
+### Invalid:
+```typescript
+import { Foo } from "./foo.ts";
+let foo: Foo;
+
+import { Bar, createBar } from "./bar.ts";
+const bar: Bar = createBar();
+
+// with { "prefer": "no-type-imports" }
+import type { Baz } from "./baz.ts";
+```
+
+### Valid:
+```typescript
+import type { Foo } from "./foo.ts";
+let foo: Foo;
+
+import type { Bar } from "./bar.ts";
+import { createBar } from "./bar.ts";
+const bar: Bar = createBar();
+
+import { Baz } from "./baz.ts";
+const baz = new Baz();
+```
+"#
+  }
+}
+
+struct ConsistentTypeImportsHandler {
+  prefer: Prefer,
+  usages: UsageCollector,
+}
+
+impl ConsistentTypeImportsHandler {
+  fn is_type_only(&self, local: &AstView::Ident) -> bool {
+    self.usages.is_used_as_type(local.inner)
+      && !self.usages.is_used_as_value(local.inner)
+  }
+
+  fn check_type_imports(
+    &self,
+    import_decl: &AstView::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    if import_decl.specifiers.is_empty() {
+      return;
+    }
+
+    let type_only = import_decl
+      .specifiers
+      .iter()
+      .map(|specifier| specifier_local(specifier))
+      .filter(|local| self.is_type_only(local))
+      .collect::<Vec<_>>();
+
+    if type_only.is_empty() {
+      return;
+    }
+
+    let has_value_imports = import_decl.specifiers.iter().any(|specifier| {
+      self
+        .usages
+        .is_used_as_value(specifier_local(specifier).inner)
+    });
+    if has_value_imports {
+      let names = type_only
+        .iter()
+        .map(|local| format!("`{}`", local.sym()))
+        .collect::<Vec<_>>()
+        .join(", ");
+      ctx.add_diagnostic_with_hint(
+        import_decl.span(),
+        CODE,
+        ConsistentTypeImportsMessage::SomeTypeOnly(names),
+        ConsistentTypeImportsHint::SplitTypeImport,
+      );
+      return;
+    }
+    if type_only.len() < import_decl.specifiers.len() {
+      return;
+    }
+
+    match import_type_fix(import_decl) {
+      Some(change) => ctx.add_diagnostic_with_fix(
+        import_decl.span(),
+        CODE,
+        ConsistentTypeImportsMessage::AllTypeOnly,
+        ConsistentTypeImportsHint::UseTypeImport,
+        USE_TYPE_IMPORT_FIX_DESC,
+        vec![change],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        import_decl.span(),
+        CODE,
+        ConsistentTypeImportsMessage::AllTypeOnly,
+        ConsistentTypeImportsHint::UseTypeImport,
+      ),
+    }
+  }
+
+  fn check_no_type_imports(
+    &self,
+    import_decl: &AstView::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    let tokens = import_decl.tokens();
+    // `import` is followed by the `type` keyword.
+    let type_keyword = tokens[1].span;
+    ctx.add_diagnostic_with_fix(
+      import_decl.span(),
+      CODE,
+      ConsistentTypeImportsMessage::TypeImport,
+      ConsistentTypeImportsHint::RemoveType,
+      REMOVE_TYPE_FIX_DESC,
+      vec![(
+        Span::new(tokens[0].span.hi, type_keyword.hi, Default::default()),
+        "".to_string(),
+      )],
+    );
+  }
+}
+
+impl Handler for ConsistentTypeImportsHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &AstView::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    match (self.prefer, import_decl.type_only()) {
+      (Prefer::TypeImports, false) => self.check_type_imports(import_decl, ctx),
+      (Prefer::NoTypeImports, true) => {
+        self.check_no_type_imports(import_decl, ctx)
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn consistent_type_imports_valid() {
+    assert_lint_ok! {
+      ConsistentTypeImports,
+      "import type { Foo } from './foo.ts'; let foo: Foo;",
+      "import type Foo from './foo.ts'; let foo: Foo;",
+      "import { Foo } from './foo.ts'; const foo = new Foo();",
+      "import { Foo } from './foo.ts'; let foo: Foo = new Foo();",
+      "import { Foo } from './foo.ts';",
+      "import './foo.ts';",
+      "import { Foo } from './foo.ts'; export { Foo };",
+      "import { Foo } from './foo.ts'; export default Foo;",
+      "import { Foo } from './foo.ts'; class Bar extends Foo {}",
+      "import { Foo } from './foo.ts'; const bar = { Foo };",
+      "import { Foo } from './foo.ts'; foo(Foo.bar);",
+      "import * as foo from './foo.ts'; foo.bar();",
+      "import * as foo from './foo.ts'; let bar: foo.Bar = foo.bar();",
+      "import { Foo } from './foo.ts'; import Bar = Foo.Bar;",
+      "import { key } from './foo.ts'; let foo: { [key]: string };",
+      "import { Foo } from './foo.ts'; bar(Foo); let foo: Foo;",
+      "import { Foo, unused } from './foo.ts'; let foo: Foo;",
+      {
+        src: "import { Foo } from './foo.tsx'; const foo = <Foo />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "import * as foo from './foo.tsx'; const bar = <foo.Bar />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "import React from 'react'; const Foo: React.FC = () => <div />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "/** @jsx h */ import { h } from 'preact'; import type { FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;",
+        filename: "foo.tsx",
+      },
+    };
+
+    assert_lint_ok! {
+      ConsistentTypeImports,
+      options: { "jsxFactory": "React.createElement" },
+      {
+        src: "import React from 'react'; const Foo: React.FC = () => <div />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "import React from 'react'; const foo = <></>;",
+        filename: "foo.tsx",
+      },
+    };
+
+    assert_lint_ok! {
+      ConsistentTypeImports,
+      options: { "prefer": "no-type-imports" },
+      "import { Foo } from './foo.ts'; let foo: Foo;",
+      "import Foo from './foo.ts'; let foo: Foo;",
+    };
+  }
+
+  #[test]
+  fn consistent_type_imports_invalid() {
+    assert_lint_err! {
+      ConsistentTypeImports,
+      "import { Foo } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type { Foo } from './foo.ts'; let foo: Foo;"
+          ),
+        }
+      ],
+      "import Foo from './foo.ts'; function foo(): Foo {}": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type Foo from './foo.ts'; function foo(): Foo {}"
+          ),
+        }
+      ],
+      "import * as foo from './foo.ts'; let bar: foo.Bar;": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type * as foo from './foo.ts'; let bar: foo.Bar;"
+          ),
+        }
+      ],
+      "import { Foo, Bar } from './foo.ts'; class Baz implements Foo { bar: typeof Bar; }": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type { Foo, Bar } from './foo.ts'; class Baz implements Foo { bar: typeof Bar; }"
+          ),
+        }
+      ],
+      "import { Foo } from './foo.ts'; interface Bar extends Foo {}": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type { Foo } from './foo.ts'; interface Bar extends Foo {}"
+          ),
+        }
+      ],
+      "import { Foo } from './foo.ts'; bar.Foo(); let foo: Foo;": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+          fix: (
+            USE_TYPE_IMPORT_FIX_DESC,
+            "import type { Foo } from './foo.ts'; bar.Foo(); let foo: Foo;"
+          ),
+        }
+      ],
+      "import Foo, { Bar } from './foo.ts'; let foo: Foo<Bar>;": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::AllTypeOnly,
+          hint: ConsistentTypeImportsHint::UseTypeImport,
+        }
+      ],
+      "import { Foo, Bar, createFoo } from './foo.ts'; const foo: Foo<Bar> = createFoo();": [
+        {
+          col: 0,
+          message: variant!(
+            ConsistentTypeImportsMessage,
+            SomeTypeOnly,
+            "`Foo`, `Bar`"
+          ),
+          hint: ConsistentTypeImportsHint::SplitTypeImport,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ConsistentTypeImports,
+      "/** @jsx h */ import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 14,
+            message: variant!(ConsistentTypeImportsMessage, SomeTypeOnly, "`FunctionComponent`"),
+            hint: ConsistentTypeImportsHint::SplitTypeImport,
+          }
+        ],
+      },
+    };
+
+    assert_lint_err! {
+      ConsistentTypeImports,
+      options: { "jsxFactory": "h" },
+      "import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 0,
+            message: variant!(ConsistentTypeImportsMessage, SomeTypeOnly, "`FunctionComponent`"),
+            hint: ConsistentTypeImportsHint::SplitTypeImport,
+          }
+        ],
+      },
+      "import { FC } from 'react'; const Foo: FC = () => <div />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 0,
+            message: ConsistentTypeImportsMessage::AllTypeOnly,
+            hint: ConsistentTypeImportsHint::UseTypeImport,
+            fix: (
+              USE_TYPE_IMPORT_FIX_DESC,
+              "import type { FC } from 'react'; const Foo: FC = () => <div />;"
+            ),
+          }
+        ],
+      },
+    };
+
+    assert_lint_err! {
+      ConsistentTypeImports,
+      options: { "prefer": "no-type-imports" },
+      "import type { Foo } from './foo.ts';": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::TypeImport,
+          hint: ConsistentTypeImportsHint::RemoveType,
+          fix: (REMOVE_TYPE_FIX_DESC, "import { Foo } from './foo.ts';"),
+        }
+      ],
+      "import type Foo from './foo.ts';": [
+        {
+          col: 0,
+          message: ConsistentTypeImportsMessage::TypeImport,
+          hint: ConsistentTypeImportsHint::RemoveType,
+          fix: (REMOVE_TYPE_FIX_DESC, "import Foo from './foo.ts';"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,226 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view as AstView;
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+pub struct DefaultCase {
+  comment_pattern: Regex,
+}
+
+impl Default for DefaultCase {
+  fn default() -> Self {
+    Self {
+      comment_pattern: build_comment_pattern(DEFAULT_COMMENT_PATTERN).unwrap(),
+    }
+  }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct DefaultCaseOptions {
+  /// Regex a comment after the last case has to match to allow a missing
+  /// `default` case.
+  comment_pattern: Option<String>,
+}
+
+const CODE: &str = "default-case";
+const MESSAGE: &str = "Expected a `default` case";
+const HINT: &str = "Add a `default` case, or a `// no default` comment after the last case if it's left out on purpose";
+const DEFAULT_COMMENT_PATTERN: &str = "^no default$";
+
+fn build_comment_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+  RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+impl LintRule for DefaultCase {
+  fn new() -> Box<Self> {
+    Box::new(DefaultCase::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    let options: DefaultCaseOptions = serde_json::from_value(options.clone())?;
+    let pattern = options
+      .comment_pattern
+      .as_deref()
+      .unwrap_or(DEFAULT_COMMENT_PATTERN);
+    self.comment_pattern = build_comment_pattern(pattern)?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    DefaultCaseHandler {
+      comment_pattern: &self.comment_pattern,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `switch` statements to have a `default` case
+
+Always having a `default` case makes it explicit what happens when none of the
+cases match, and avoids silently ignoring unexpected values.
+
+If leaving out the `default` case is intentional, put a `// no default`
+comment after the last case. The `commentPattern` option takes a regex to
+change which comment is recognized; it's matched case-insensitively against
+the comment text with surrounding whitespace trimmed.
+
+### Invalid:
+```typescript
+switch (foo) {
+  case 1:
+    bar();
+    break;
+}
+```
+
+### Valid:
+```typescript
+switch (foo) {
+  case 1:
+    bar();
+    break;
+  default:
+    baz();
+}
+
+switch (foo) {
+  case 1:
+    bar();
+    break;
+  // no default
+}
+
+// with { "commentPattern": "^skip default" }
+switch (foo) {
+  case 1:
+    bar();
+    break;
+  // skip default because foo is always 1
+}
+```
+"#
+  }
+}
+
+struct DefaultCaseHandler<'a> {
+  comment_pattern: &'a Regex,
+}
+
+impl<'a> Handler for DefaultCaseHandler<'a> {
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &AstView::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let last_case = match switch_stmt.cases.last() {
+      Some(last_case) => last_case,
+      None => return,
+    };
+
+    if switch_stmt.cases.iter().any(|case| case.test.is_none()) {
+      return;
+    }
+
+    let after_last_case = last_case.span().hi..switch_stmt.span().hi;
+    let has_escape_comment = ctx
+      .all_comments()
+      .filter(|comment| after_last_case.contains(&comment.span.lo))
+      .max_by_key(|comment| comment.span.lo)
+      .map_or(false, |comment| {
+        self.comment_pattern.is_match(comment.text.trim())
+      });
+
+    if !has_escape_comment {
+      ctx.add_diagnostic_with_hint(switch_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_case_valid() {
+    assert_lint_ok! {
+      DefaultCase,
+      "switch (a) {}",
+      "switch (a) { default: break; }",
+      "switch (a) { case 1: break; default: break; }",
+      "switch (a) { case 1: break; default: case 2: break; }",
+      "switch (a) { case 1: break; // no default\n}",
+      "switch (a) { case 1: break; /* no default */ }",
+      "switch (a) { case 1: break; // No Default\n}",
+      "switch (a) { case 1: break; //   no default   \n}",
+      "switch (a) { case 1: break; // foo\n // no default\n}",
+    };
+
+    assert_lint_ok! {
+      DefaultCase,
+      options: { "commentPattern": "^skip default" },
+      "switch (a) { case 1: break; // skip default because a is always 1\n}",
+      "switch (a) { case 1: break; // Skip Default\n}",
+    };
+  }
+
+  #[test]
+  fn default_case_invalid() {
+    assert_lint_err! {
+      DefaultCase,
+      "switch (a) { case 1: break; }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "switch (a) { case 1: break; case 2: break; }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "switch (a) { case 1: break; // no default yet\n}": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "switch (a) { // no default\n case 1: break; }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "switch (a) { case 1: break; // no default\n // foo\n}": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { switch (a) { case 1: return; } }": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+    };
+
+    assert_lint_err! {
+      DefaultCase,
+      options: { "commentPattern": "^skip default" },
+      "switch (a) { case 1: break; // no default\n}": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+
+  #[test]
+  fn default_case_invalid_options() {
+    let mut rule = DefaultCase::default();
+    assert!(rule
+      .set_options(&serde_json::json!({ "commentPattern": "(" }))
+      .is_err());
+  }
+}
@@ -0,0 +1,144 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct DefaultCaseLast;
+
+const CODE: &str = "default-case-last";
+const MESSAGE: &str = "The `default` clause should be the last clause";
+const HINT: &str =
+  "Move the `default` clause to the end of the `switch` statement";
+
+impl LintRule for DefaultCaseLast {
+  fn new() -> Box<Self> {
+    Box::new(DefaultCaseLast)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    DefaultCaseLastHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the `default` clause to be the last clause of a `switch` statement
+
+A `default` clause in the middle of a `switch` is still only taken when no
+other case matches, but it's easy to misread, especially when it falls through
+into the following cases.
+
+### Invalid:
+```typescript
+switch (foo) {
+  default:
+    bar();
+    break;
+  case "a":
+    baz();
+    break;
+}
+
+switch (foo) {
+  case "a":
+    baz();
+  // falls through
+  default:
+    bar();
+  // falls through
+  case "b":
+    qux();
+}
+```
+
+### Valid:
+```typescript
+switch (foo) {
+  case "a":
+    baz();
+    break;
+  default:
+    bar();
+    break;
+}
+
+switch (foo) {
+  case "a":
+    baz();
+    break;
+}
+```
+"#
+  }
+}
+
+struct DefaultCaseLastHandler;
+
+impl Handler for DefaultCaseLastHandler {
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &AstView::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let (last, rest) = match switch_stmt.cases.split_last() {
+      Some(cases) => cases,
+      None => return,
+    };
+
+    if last.test.is_none() {
+      return;
+    }
+
+    if let Some(default) = rest.iter().find(|case| case.test.is_none()) {
+      ctx.add_diagnostic_with_hint(default.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_case_last_valid() {
+    assert_lint_ok! {
+      DefaultCaseLast,
+      "switch (foo) {}",
+      "switch (foo) { case 1: bar(); break; }",
+      "switch (foo) { default: bar(); }",
+      "switch (foo) { case 1: bar(); break; default: baz(); }",
+      "switch (foo) { case 1: case 2: default: baz(); }",
+      "switch (foo) { case 1: break; default: switch (bar) { case 1: break; default: break; } }",
+    };
+  }
+
+  #[test]
+  fn default_case_last_invalid() {
+    assert_lint_err! {
+      DefaultCaseLast,
+      "switch (foo) { default: bar(); break; case 1: baz(); }": [
+        { col: 15, message: MESSAGE, hint: HINT }
+      ],
+      "switch (foo) { case 1: baz(); default: bar(); case 2: qux(); }": [
+        { col: 30, message: MESSAGE, hint: HINT }
+      ],
+      "switch (foo) { case 1: default: case 2: }": [
+        { col: 23, message: MESSAGE, hint: HINT }
+      ],
+      "switch (foo) { case 1: break; default: switch (bar) { default: break; case 1: break; } }": [
+        { col: 54, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
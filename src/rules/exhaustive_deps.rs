@@ -0,0 +1,519 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::enclosing_function;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use std::collections::{BTreeSet, HashSet};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  AssignPatProp, BindingIdent, CallExpr, ClassDecl, Expr, ExprOrSuper, FnDecl,
+  MemberExpr, Pat, Prop, VarDeclarator,
+};
+use swc_ecmascript::utils::{ident::IdentLike, Id};
+use swc_ecmascript::visit::{Node, Visit, VisitWith};
+
+pub struct ExhaustiveDeps;
+
+const CODE: &str = "exhaustive-deps";
+const FIX_DESC: &str = "Update the dependency array";
+
+#[derive(Display)]
+enum ExhaustiveDepsMessage {
+  #[display(fmt = "React Hook `{}` has missing dependencies: {}", _0, _1)]
+  Missing(String, String),
+  #[display(fmt = "React Hook `{}` has unnecessary dependencies: {}", _0, _1)]
+  Unnecessary(String, String),
+  #[display(
+    fmt = "React Hook `{}` has missing dependencies: {} and unnecessary dependencies: {}",
+    _0,
+    _1,
+    _2
+  )]
+  MissingAndUnnecessary(String, String, String),
+  #[display(
+    fmt = "React Hook `{}` was passed a dependency list that is not an array literal",
+    _0
+  )]
+  NotArrayLiteral(String),
+}
+
+#[derive(Display)]
+enum ExhaustiveDepsHint {
+  #[display(fmt = "Update the dependency array to `{}`", _0)]
+  Update(String),
+  #[display(
+    fmt = "Pass the dependencies as an array literal so that they can be checked"
+  )]
+  NotArrayLiteral,
+}
+
+impl LintRule for ExhaustiveDeps {
+  fn new() -> Box<Self> {
+    Box::new(ExhaustiveDeps)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    ExhaustiveDepsHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Checks the dependency arrays of React and Preact hooks
+
+`useEffect`, `useLayoutEffect`, `useInsertionEffect`, `useMemo`,
+`useCallback` and `useImperativeHandle` only rerun their callback when one of
+the listed dependencies changes. A value from the component that's used in the
+callback but missing from the list leads to a stale value being used.
+
+Values declared in the component, including props, that the callback uses have
+to be listed. The setters returned by `useState` and `useReducer` and refs
+returned by `useRef` never change, so they don't have to be listed. Values
+from outside the component are reported as unnecessary dependencies, since
+changing them doesn't rerender the component, and so are unused dependencies
+of `useMemo` and `useCallback`.
+
+### Invalid:
+```typescript
+function Foo({ id }) {
+  const [data, setData] = useState();
+  useEffect(() => {
+    fetchData(id).then(setData);
+  }, []);
+
+  const label = useMemo(() => format(data), [data, id]);
+}
+```
+
+### Valid:
+```typescript
+function Foo({ id }) {
+  const [data, setData] = useState();
+  useEffect(() => {
+    fetchData(id).then(setData);
+  }, [id]);
+
+  const label = useMemo(() => format(data), [data]);
+}
+```
+"#
+  }
+}
+
+/// Returns the name of the hook being called, e.g. `useEffect` for both
+/// `useEffect()` and `React.useEffect()`.
+fn hook_name(callee: &ExprOrSuper) -> Option<&str> {
+  match callee {
+    ExprOrSuper::Expr(expr) => match &**expr {
+      Expr::Ident(ident) => Some(&ident.sym),
+      Expr::Member(MemberExpr {
+        obj: ExprOrSuper::Expr(obj),
+        prop,
+        computed: false,
+        ..
+      }) => match (&**obj, &**prop) {
+        (Expr::Ident(_), Expr::Ident(prop)) => Some(&prop.sym),
+        _ => None,
+      },
+      _ => None,
+    },
+    ExprOrSuper::Super(_) => None,
+  }
+}
+
+/// Returns the index of the callback argument of hooks that take a
+/// dependency array right after it.
+fn callback_index(hook: &str) -> Option<usize> {
+  match hook {
+    "useEffect" | "useLayoutEffect" | "useInsertionEffect" | "useMemo"
+    | "useCallback" => Some(0),
+    "useImperativeHandle" => Some(1),
+    _ => None,
+  }
+}
+
+/// Returns the identifier an expression like `foo` or `foo.bar.baz` starts
+/// with, along with its path as written.
+fn member_path(expr: &Expr) -> Option<(Id, String)> {
+  match expr {
+    Expr::Ident(ident) => Some((ident.to_id(), ident.sym.to_string())),
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) => match &**prop {
+      Expr::Ident(prop) => member_path(obj)
+        .map(|(id, path)| (id, format!("{}.{}", path, prop.sym))),
+      _ => None,
+    },
+    Expr::Paren(paren) => member_path(&paren.expr),
+    _ => None,
+  }
+}
+
+/// Collects the bindings declared in a function, and which of them are
+/// known to never change between renders.
+#[derive(Default)]
+struct BindingCollector {
+  declared: HashSet<Id>,
+  stable: HashSet<Id>,
+}
+
+impl Visit for BindingCollector {
+  fn visit_binding_ident(&mut self, binding: &BindingIdent, _: &dyn Node) {
+    self.declared.insert(binding.id.to_id());
+  }
+
+  fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp, _: &dyn Node) {
+    self.declared.insert(prop.key.to_id());
+    prop.visit_children_with(self);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _: &dyn Node) {
+    self.declared.insert(fn_decl.ident.to_id());
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl, _: &dyn Node) {
+    self.declared.insert(class_decl.ident.to_id());
+    class_decl.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
+    let hook = match declarator.init.as_deref() {
+      Some(Expr::Call(CallExpr { callee, .. })) => hook_name(callee),
+      _ => None,
+    };
+    match (hook, &declarator.name) {
+      (Some("useState"), Pat::Array(array))
+      | (Some("useReducer"), Pat::Array(array)) => {
+        if let Some(Some(Pat::Ident(setter))) = array.elems.get(1) {
+          self.stable.insert(setter.id.to_id());
+        }
+      }
+      (Some("useRef"), Pat::Ident(binding)) => {
+        self.stable.insert(binding.id.to_id());
+      }
+      _ => {}
+    }
+    declarator.visit_children_with(self);
+  }
+}
+
+/// Collects the values a callback reads, like `foo` and `bar.baz` in
+/// `foo(bar.baz)`. For method calls like `foo.bar()` the object `foo` is
+/// collected, since the method may depend on it.
+#[derive(Default)]
+struct ReferenceCollector {
+  references: Vec<(Id, String)>,
+}
+
+impl Visit for ReferenceCollector {
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    match member_path(expr) {
+      Some(reference) => self.references.push(reference),
+      None => expr.visit_children_with(self),
+    }
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member_expr) if !member_expr.computed => {
+          member_expr.visit_with(call_expr, self)
+        }
+        _ => callee.visit_with(call_expr, self),
+      },
+      ExprOrSuper::Super(_) => {}
+    }
+    call_expr.args.visit_with(call_expr, self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _: &dyn Node) {
+    match prop {
+      Prop::Shorthand(ident) => {
+        self.references.push((ident.to_id(), ident.sym.to_string()))
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+}
+
+fn is_covered_by(path: &str, dependency: &str) -> bool {
+  path == dependency
+    || path
+      .strip_prefix(dependency)
+      .map_or(false, |rest| rest.starts_with('.'))
+}
+
+fn format_list(paths: &[String]) -> String {
+  paths
+    .iter()
+    .map(|path| format!("`{}`", path))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+struct ExhaustiveDepsHandler;
+
+impl Handler for ExhaustiveDepsHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let hook = match hook_name(&call_expr.inner.callee) {
+      Some(hook) => hook,
+      None => return,
+    };
+    let index = match callback_index(hook) {
+      Some(index) => index,
+      None => return,
+    };
+    let (callback, deps) =
+      match (call_expr.args.get(index), call_expr.args.get(index + 1)) {
+        (Some(callback), Some(deps)) => (callback, deps),
+        _ => return,
+      };
+    if !matches!(
+      callback.expr,
+      AstView::Expr::Arrow(_) | AstView::Expr::Fn(_)
+    ) {
+      return;
+    }
+    let deps_array = match &deps.expr {
+      AstView::Expr::Array(deps_array) => deps_array,
+      _ => {
+        ctx.add_diagnostic_with_hint(
+          deps.span(),
+          CODE,
+          ExhaustiveDepsMessage::NotArrayLiteral(hook.to_string()),
+          ExhaustiveDepsHint::NotArrayLiteral,
+        );
+        return;
+      }
+    };
+    let function = match enclosing_function(call_expr.into()) {
+      Some(function) => function,
+      None => return,
+    };
+
+    let mut bindings = BindingCollector::default();
+    match function {
+      AstView::Node::Function(function) => {
+        function.inner.visit_with(&DUMMY_NODE, &mut bindings)
+      }
+      AstView::Node::ArrowExpr(arrow) => {
+        arrow.inner.visit_with(&DUMMY_NODE, &mut bindings)
+      }
+      _ => unreachable!(),
+    }
+    let mut callback_bindings = BindingCollector::default();
+    callback
+      .inner
+      .expr
+      .visit_with(&DUMMY_NODE, &mut callback_bindings);
+    let mut references = ReferenceCollector::default();
+    callback.inner.expr.visit_with(&DUMMY_NODE, &mut references);
+
+    let is_component_value = |id: &Id| {
+      bindings.declared.contains(id) && !callback_bindings.declared.contains(id)
+    };
+    let required: BTreeSet<String> = references
+      .references
+      .into_iter()
+      .filter(|(id, _)| is_component_value(id) && !bindings.stable.contains(id))
+      .map(|(_, path)| path)
+      .collect();
+
+    // Only entries like `a` or `b.c` can be checked, others like `a[0]` are
+    // kept as they are.
+    let is_memo = hook == "useMemo" || hook == "useCallback";
+    let mut declared = Vec::new();
+    let mut kept = Vec::new();
+    let mut unnecessary = Vec::new();
+    for elem in deps_array.elems.iter().flatten() {
+      let (id, path) = match member_path(&elem.inner.expr) {
+        Some(dependency) if elem.spread().is_none() => dependency,
+        _ => {
+          kept.push(elem.text().to_string());
+          continue;
+        }
+      };
+      let is_used = required.iter().any(|p| is_covered_by(p, &path));
+      let is_unnecessary = declared.contains(&path)
+        || !is_component_value(&id)
+        || (is_memo && !is_used && !bindings.stable.contains(&id));
+      if is_unnecessary {
+        unnecessary.push(path);
+      } else {
+        kept.push(elem.text().to_string());
+        declared.push(path);
+      }
+    }
+
+    let missing: Vec<String> = required
+      .iter()
+      .filter(|path| {
+        !required
+          .iter()
+          .any(|other| other != *path && is_covered_by(path, other))
+      })
+      .filter(|path| !declared.iter().any(|dep| is_covered_by(path, dep)))
+      .cloned()
+      .collect();
+
+    let message = match (missing.is_empty(), unnecessary.is_empty()) {
+      (true, true) => return,
+      (false, true) => {
+        ExhaustiveDepsMessage::Missing(hook.to_string(), format_list(&missing))
+      }
+      (true, false) => ExhaustiveDepsMessage::Unnecessary(
+        hook.to_string(),
+        format_list(&unnecessary),
+      ),
+      (false, false) => ExhaustiveDepsMessage::MissingAndUnnecessary(
+        hook.to_string(),
+        format_list(&missing),
+        format_list(&unnecessary),
+      ),
+    };
+    kept.extend(missing);
+    let fixed = format!("[{}]", kept.join(", "));
+    ctx.add_diagnostic_with_fix(
+      deps_array.span(),
+      CODE,
+      message,
+      ExhaustiveDepsHint::Update(fixed.clone()),
+      FIX_DESC,
+      vec![(deps_array.span(), fixed)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn exhaustive_deps_valid() {
+    assert_lint_ok! {
+      ExhaustiveDeps,
+      "function Foo() { useEffect(() => {}, []); }",
+      "function Foo({ id }) { useEffect(() => { fetch(id); }, [id]); }",
+      "function Foo(props) { useEffect(() => { fetch(props.id); }, [props.id]); }",
+      "function Foo(props) { useEffect(() => { fetch(props.id); }, [props]); }",
+      "function Foo(props) { useEffect(() => { props.onChange(); }, [props]); }",
+      "function Foo() { const [a, setA] = useState(); useEffect(() => { setA(1); }, []); }",
+      "function Foo() { const [a, dispatch] = useReducer(r); useEffect(() => { dispatch(a); }, [a]); }",
+      "function Foo() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }",
+      "function Foo() { const ref = useRef(); useCallback(() => { ref.current = 1; }, [ref]); }",
+      "function Foo() { useEffect(() => { console.log(window.location); }, []); }",
+      "const a = 1; function Foo() { useEffect(() => { console.log(a); }, []); }",
+      "function Foo() { useEffect(() => { const a = 1; console.log(a); }, []); }",
+      "function Foo({ a }) { useEffect(() => { console.log(a); }); }",
+      "function Foo({ a, b }) { useEffect(() => { console.log(a); }, [a, b]); }",
+      "function Foo({ a }) { const b = useMemo(() => ({ a }), [a]); }",
+      "function Foo({ a }) { const b = React.useMemo(() => a * 2, [a]); }",
+      "function Foo({ a }) { const b = useCallback(function () { return a; }, [a]); }",
+      "function Foo({ a }) { useImperativeHandle(ref, () => ({ a }), [a]); }",
+      "function Foo({ a }) { useEffect(handler, []); }",
+      "function Foo({ items }) { useEffect(() => { items[0].x; }, [items]); }",
+      "function Foo({ a }) { useEffect(() => { a.b.c; }, [a.b]); }",
+      "const Foo = ({ a }) => { useEffect(() => { console.log(a); }, [a]); };",
+    };
+  }
+
+  #[test]
+  fn exhaustive_deps_invalid() {
+    assert_lint_err! {
+      ExhaustiveDeps,
+      "function Foo({ id }) { useEffect(() => { fetch(id); }, []); }": [
+        {
+          col: 55,
+          message: variant!(ExhaustiveDepsMessage, Missing, "useEffect", "`id`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[id]"),
+          fix: (FIX_DESC, "function Foo({ id }) { useEffect(() => { fetch(id); }, [id]); }"),
+        }
+      ],
+      "function Foo(props) { useEffect(() => { fetch(props.id, props.name); }, [props.id]); }": [
+        {
+          col: 72,
+          message: variant!(ExhaustiveDepsMessage, Missing, "useEffect", "`props.name`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[props.id, props.name]"),
+          fix: (FIX_DESC, "function Foo(props) { useEffect(() => { fetch(props.id, props.name); }, [props.id, props.name]); }"),
+        }
+      ],
+      "function Foo(props) { useEffect(() => { props.onChange(props.value); }, []); }": [
+        {
+          col: 72,
+          message: variant!(ExhaustiveDepsMessage, Missing, "useEffect", "`props`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[props]"),
+          fix: (FIX_DESC, "function Foo(props) { useEffect(() => { props.onChange(props.value); }, [props]); }"),
+        }
+      ],
+      "function Foo() { const [a, setA] = useState(); const b = a + 1; useEffect(() => { setA(b); }, [a]); }": [
+        {
+          col: 94,
+          message: variant!(ExhaustiveDepsMessage, Missing, "useEffect", "`b`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[a, b]"),
+          fix: (FIX_DESC, "function Foo() { const [a, setA] = useState(); const b = a + 1; useEffect(() => { setA(b); }, [a, b]); }"),
+        }
+      ],
+      "function Foo({ a, b }) { const c = useMemo(() => a + b, [c, a]); }": [
+        {
+          col: 56,
+          message: variant!(ExhaustiveDepsMessage, MissingAndUnnecessary, "useMemo", "`b`", "`c`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[a, b]"),
+          fix: (FIX_DESC, "function Foo({ a, b }) { const c = useMemo(() => a + b, [a, b]); }"),
+        }
+      ],
+      "function Foo({ a }) { const b = useCallback(() => a, [a, a]); }": [
+        {
+          col: 53,
+          message: variant!(ExhaustiveDepsMessage, Unnecessary, "useCallback", "`a`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[a]"),
+          fix: (FIX_DESC, "function Foo({ a }) { const b = useCallback(() => a, [a]); }"),
+        }
+      ],
+      "const a = 1; function Foo() { useEffect(() => { console.log(a); }, [a]); }": [
+        {
+          col: 67,
+          message: variant!(ExhaustiveDepsMessage, Unnecessary, "useEffect", "`a`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[]"),
+          fix: (FIX_DESC, "const a = 1; function Foo() { useEffect(() => { console.log(a); }, []); }"),
+        }
+      ],
+      "function Foo({ a }) { useEffect(() => { const b = { a }; }, []); }": [
+        {
+          col: 60,
+          message: variant!(ExhaustiveDepsMessage, Missing, "useEffect", "`a`"),
+          hint: variant!(ExhaustiveDepsHint, Update, "[a]"),
+          fix: (FIX_DESC, "function Foo({ a }) { useEffect(() => { const b = { a }; }, [a]); }"),
+        }
+      ],
+      "function Foo({ a }) { useEffect(() => { console.log(a); }, deps); }": [
+        {
+          col: 59,
+          message: variant!(ExhaustiveDepsMessage, NotArrayLiteral, "useEffect"),
+          hint: ExhaustiveDepsHint::NotArrayLiteral,
+        }
+      ],
+    };
+  }
+}
@@ -90,7 +90,7 @@ impl<'c, 'view> Visit for ExplicitFunctionReturnTypeVisitor<'c, 'view> {
         ExplicitFunctionReturnTypeHint::AddRetType,
       );
     }
-    for stmt in &function.body {
+    if let Some(stmt) = &function.body {
       self.visit_block_stmt(stmt, _parent);
     }
   }
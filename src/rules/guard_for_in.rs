@@ -0,0 +1,232 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::skip_wrappers;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, UnaryOp};
+use swc_common::Spanned;
+
+pub struct GuardForIn;
+
+const CODE: &str = "guard-for-in";
+const MESSAGE: &str =
+  "The body of a for-in should be wrapped in an if statement to filter unwanted properties from the prototype";
+const HINT: &str = "Check the key with `Object.hasOwn(obj, key)`, e.g. `if (Object.hasOwn(obj, key)) { ... }`, or skip it with `if (!Object.hasOwn(obj, key)) continue;`";
+
+impl LintRule for GuardForIn {
+  fn new() -> Box<Self> {
+    Box::new(GuardForIn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    GuardForInHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `for...in` loops to filter out inherited properties
+
+A `for...in` loop iterates over inherited enumerable properties as well as
+the object's own ones. The body of the loop is required to either be wrapped
+in an `if` statement checking `Object.hasOwn()` or `hasOwnProperty()`, or to
+start with such a check that `continue`s otherwise.
+
+### Invalid:
+```typescript
+for (const key in obj) {
+  foo(obj, key);
+}
+
+for (const key in obj) {
+  if (key !== "bar") {
+    foo(obj, key);
+  }
+}
+```
+
+### Valid:
+```typescript
+for (const key in obj) {
+  if (Object.hasOwn(obj, key)) {
+    foo(obj, key);
+  }
+}
+
+for (const key in obj) {
+  if (!Object.prototype.hasOwnProperty.call(obj, key)) {
+    continue;
+  }
+  foo(obj, key);
+}
+```
+"#
+  }
+}
+
+/// Checks if the expression is a call like `Object.hasOwn(obj, key)`,
+/// `obj.hasOwnProperty(key)` or
+/// `Object.prototype.hasOwnProperty.call(obj, key)`.
+fn is_has_own_call(expr: &AstView::Expr) -> bool {
+  let callee = match skip_wrappers(expr) {
+    AstView::Expr::Call(AstView::CallExpr {
+      callee: AstView::ExprOrSuper::Expr(AstView::Expr::Member(callee)),
+      ..
+    }) => callee,
+    _ => return false,
+  };
+
+  let prop_name = |member_expr: &AstView::MemberExpr| match member_expr.prop {
+    AstView::Expr::Ident(ident) if !member_expr.computed() => {
+      Some(ident.sym().to_string())
+    }
+    _ => None,
+  };
+
+  match prop_name(callee).as_deref() {
+    Some("hasOwn") | Some("hasOwnProperty") => true,
+    Some("call") | Some("apply") => matches!(
+      &callee.obj,
+      AstView::ExprOrSuper::Expr(AstView::Expr::Member(obj))
+        if prop_name(obj).as_deref() == Some("hasOwnProperty")
+    ),
+    _ => false,
+  }
+}
+
+/// Checks if the test of an `if` statement ensures that the key is an own
+/// property when it's truthy.
+fn is_guard(test: &AstView::Expr) -> bool {
+  match skip_wrappers(test) {
+    AstView::Expr::Bin(bin_expr) if bin_expr.op() == BinaryOp::LogicalAnd => {
+      is_guard(&bin_expr.left) || is_guard(&bin_expr.right)
+    }
+    expr => is_has_own_call(&expr),
+  }
+}
+
+/// Checks if the test of an `if` statement is truthy whenever the key is not
+/// an own property.
+fn is_negated_guard(test: &AstView::Expr) -> bool {
+  match skip_wrappers(test) {
+    AstView::Expr::Bin(bin_expr) if bin_expr.op() == BinaryOp::LogicalOr => {
+      is_negated_guard(&bin_expr.left) || is_negated_guard(&bin_expr.right)
+    }
+    AstView::Expr::Unary(unary_expr) if unary_expr.op() == UnaryOp::Bang => {
+      is_has_own_call(&unary_expr.arg)
+    }
+    _ => false,
+  }
+}
+
+/// Checks if the statement is `continue;` or `{ continue; }`.
+fn is_continue(stmt: &AstView::Stmt) -> bool {
+  match stmt {
+    AstView::Stmt::Continue(_) => true,
+    AstView::Stmt::Block(block) => {
+      matches!(block.stmts.as_slice(), [AstView::Stmt::Continue(_)])
+    }
+    _ => false,
+  }
+}
+
+fn is_guarded(body: &AstView::Stmt) -> bool {
+  match body {
+    AstView::Stmt::Empty(_) => true,
+    AstView::Stmt::If(if_stmt) => is_guard(&if_stmt.test),
+    AstView::Stmt::Block(block) => match block.stmts.as_slice() {
+      [] => true,
+      [AstView::Stmt::If(if_stmt)] if is_guard(&if_stmt.test) => true,
+      [AstView::Stmt::If(if_stmt), ..] => {
+        if_stmt.alt.is_none()
+          && is_negated_guard(&if_stmt.test)
+          && is_continue(&if_stmt.cons)
+      }
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+struct GuardForInHandler;
+
+impl Handler for GuardForInHandler {
+  fn for_in_stmt(
+    &mut self,
+    for_in_stmt: &AstView::ForInStmt,
+    ctx: &mut Context,
+  ) {
+    if !is_guarded(&for_in_stmt.body) {
+      ctx.add_diagnostic_with_hint(for_in_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn guard_for_in_valid() {
+    assert_lint_ok! {
+      GuardForIn,
+      "for (const x in o);",
+      "for (const x in o) {}",
+      "for (const x in o) if (Object.hasOwn(o, x)) foo();",
+      "for (const x in o) { if (Object.hasOwn(o, x)) { foo(); } }",
+      "for (const x in o) { if (o.hasOwnProperty(x)) { foo(); } }",
+      "for (const x in o) { if (Object.prototype.hasOwnProperty.call(o, x)) { foo(); } }",
+      "for (const x in o) { if ({}.hasOwnProperty.call(o, x)) { foo(); } }",
+      "for (const x in o) { if (x !== 'a' && Object.hasOwn(o, x)) { foo(); } }",
+      "for (const x in o) { if (!Object.hasOwn(o, x)) continue; foo(); }",
+      "for (const x in o) { if (!Object.hasOwn(o, x)) { continue; } foo(); }",
+      "for (const x in o) { if (x === 'a' || !o.hasOwnProperty(x)) continue; foo(); }",
+      "for (const x of o) { foo(); }",
+    };
+  }
+
+  #[test]
+  fn guard_for_in_invalid() {
+    assert_lint_err! {
+      GuardForIn,
+      "for (const x in o) foo();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "for (const x in o) { foo(); }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (x) { foo(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (Object.hasOwn(o, x)) { foo(); } bar(); }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (x || Object.hasOwn(o, x)) { foo(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (!Object.hasOwn(o, x)) { foo(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (!Object.hasOwn(o, x)) { foo(); continue; } bar(); }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { if (Object.hasOwn(o, x)) continue; foo(); }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { foo(); if (!Object.hasOwn(o, x)) continue; }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x in o) { for (const y in o[x]) { foo(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 21, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,329 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::enclosing_function;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::Spanned;
+
+pub struct JsxKey;
+
+const CODE: &str = "jsx-key";
+
+#[derive(Display)]
+enum JsxKeyMessage {
+  #[display(fmt = "Missing `key` prop for element in iterator")]
+  MissingKey,
+  #[display(fmt = "Missing `key` prop for fragment shorthand in iterator")]
+  FragmentShorthand,
+}
+
+#[derive(Display)]
+enum JsxKeyHint {
+  #[display(fmt = "Add a `key` prop that uniquely identifies the element")]
+  MissingKey,
+  #[display(
+    fmt = "Use `<Fragment key={{...}}>` instead, since `<>` can't have a `key` prop"
+  )]
+  FragmentShorthand,
+}
+
+impl LintRule for JsxKey {
+  fn new() -> Box<Self> {
+    Box::new(JsxKey)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    JsxKeyHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires a `key` prop for JSX elements in arrays and iterators
+
+Frameworks like React and Preact use the `key` prop to tell apart the elements
+of a list between renders. Without it, state can end up attached to the wrong
+element when the list changes.
+
+This rule reports elements without a `key` prop that are array literal
+elements or are returned from a callback of `.map()` or `Array.from()`.
+Fragment shorthands (`<></>`) can't have a `key` prop at all, so they are
+reported in those positions too.
+
+### Invalid:
+```typescript
+[<li>foo</li>, <li>bar</li>];
+items.map((item) => <li>{item.name}</li>);
+items.map(function (item) {
+  return <li>{item.name}</li>;
+});
+Array.from(items, (item) => <li>{item.name}</li>);
+items.map((item) => <>{item.name}</>);
+```
+
+### Valid:
+```typescript
+[<li key="foo">foo</li>, <li key="bar">bar</li>];
+items.map((item) => <li key={item.id}>{item.name}</li>);
+items.map((item) => <Fragment key={item.id}>{item.name}</Fragment>);
+```
+"#
+  }
+}
+
+/// Walks up from the node through parentheses and conditional branches,
+/// returning the outermost node that still evaluates to the node's value and
+/// its parent. Like `skip_paren_ancestors`, but also skips conditionals.
+fn outermost_wrapper(
+  mut node: AstView::Node,
+) -> (AstView::Node, Option<AstView::Node>) {
+  loop {
+    match node.parent() {
+      Some(parent @ AstView::Node::ParenExpr(_)) => node = parent,
+      Some(parent @ AstView::Node::CondExpr(cond_expr))
+        if cond_expr.test.span() != node.span() =>
+      {
+        node = parent
+      }
+      parent => return (node, parent),
+    }
+  }
+}
+
+/// Checks if the given function or arrow function is passed as the callback
+/// of `.map()` or `Array.from()`.
+fn is_iterator_callback(callback: AstView::Node) -> bool {
+  // The argument of a function expression is the `FnExpr` wrapping the
+  // function.
+  let callback = match callback {
+    AstView::Node::Function(function) => function.parent,
+    _ => callback,
+  };
+  let arg = match outermost_wrapper(callback) {
+    (_, Some(AstView::Node::ExprOrSpread(arg))) => arg,
+    _ => return false,
+  };
+  let call_expr = match arg.parent {
+    AstView::Node::CallExpr(call_expr) => call_expr,
+    _ => return false,
+  };
+  let index = call_expr.args.iter().position(|a| a.span() == arg.span());
+
+  let callee = match &call_expr.callee {
+    AstView::ExprOrSuper::Expr(AstView::Expr::OptChain(opt_chain)) => {
+      &opt_chain.expr
+    }
+    AstView::ExprOrSuper::Expr(callee) => callee,
+    AstView::ExprOrSuper::Super(_) => return false,
+  };
+  let member_expr = match callee {
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      member_expr
+    }
+    _ => return false,
+  };
+  match (&member_expr.obj, &member_expr.prop) {
+    (
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+      AstView::Expr::Ident(prop),
+    ) if obj.sym() == "Array" && prop.sym() == "from" => index == Some(1),
+    (_, AstView::Expr::Ident(prop)) if prop.sym() == "map" => index == Some(0),
+    _ => false,
+  }
+}
+
+/// Checks if the given JSX element or fragment is an element of an array
+/// literal or is returned from an iterator callback.
+fn is_in_iterator(node: AstView::Node) -> bool {
+  match outermost_wrapper(node) {
+    (_, Some(AstView::Node::ExprOrSpread(arg))) => {
+      matches!(arg.parent, AstView::Node::ArrayLit(_))
+    }
+    (_, Some(arrow @ AstView::Node::ArrowExpr(_))) => {
+      is_iterator_callback(arrow)
+    }
+    (_, Some(return_stmt @ AstView::Node::ReturnStmt(_))) => {
+      enclosing_function(return_stmt).map_or(false, is_iterator_callback)
+    }
+    _ => false,
+  }
+}
+
+fn has_key_prop(opening: &AstView::JSXOpeningElement) -> bool {
+  opening.attrs.iter().any(|attr| {
+    matches!(
+      attr,
+      AstView::JSXAttrOrSpread::JSXAttr(AstView::JSXAttr {
+        name: AstView::JSXAttrName::Ident(name),
+        ..
+      }) if name.sym() == "key"
+    )
+  })
+}
+
+struct JsxKeyHandler;
+
+impl Handler for JsxKeyHandler {
+  fn jsx_element(
+    &mut self,
+    jsx_element: &AstView::JSXElement,
+    ctx: &mut Context,
+  ) {
+    if !has_key_prop(jsx_element.opening) && is_in_iterator(jsx_element.into())
+    {
+      ctx.add_diagnostic_with_hint(
+        jsx_element.opening.span(),
+        CODE,
+        JsxKeyMessage::MissingKey,
+        JsxKeyHint::MissingKey,
+      );
+    }
+  }
+
+  fn jsx_fragment(
+    &mut self,
+    jsx_fragment: &AstView::JSXFragment,
+    ctx: &mut Context,
+  ) {
+    if is_in_iterator(jsx_fragment.into()) {
+      ctx.add_diagnostic_with_hint(
+        jsx_fragment.opening.span(),
+        CODE,
+        JsxKeyMessage::FragmentShorthand,
+        JsxKeyHint::FragmentShorthand,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_key_valid() {
+    assert_lint_ok! {
+      JsxKey,
+      { src: r#"[<li key="a">a</li>, <li key="b">b</li>];"#, filename: "foo.tsx" },
+      { src: "items.map((item) => <li key={item.id}>{item.name}</li>);", filename: "foo.tsx" },
+      { src: "items.map(function (item) { return <li key={item.id} />; });", filename: "foo.tsx" },
+      { src: "Array.from(items, (item) => <li key={item.id} />);", filename: "foo.tsx" },
+      { src: "items.map((item) => <Fragment key={item.id}>{item.name}</Fragment>);", filename: "foo.tsx" },
+      { src: "const foo = <div><li /></div>;", filename: "foo.tsx" },
+      { src: "const foo = <><li /></>;", filename: "foo.tsx" },
+      { src: "foo(<li />);", filename: "foo.tsx" },
+      { src: "items.forEach((item) => <li />);", filename: "foo.tsx" },
+      { src: "items.map((item) => <li key={item.id}><span /></li>);", filename: "foo.tsx" },
+      { src: "items.map((item) => { const foo = () => <li />; return null; });", filename: "foo.tsx" },
+      { src: "Array.from(<li />);", filename: "foo.tsx" },
+    };
+  }
+
+  #[test]
+  fn jsx_key_invalid() {
+    assert_lint_err! {
+      JsxKey,
+      "[<li>a</li>, <li key=\"b\">b</li>];": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 1,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items.map((item) => <li>{item.name}</li>);": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 20,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items.map((item) => (<li {...item} />));": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 21,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items.map(function (item) { return <li />; });": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 35,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items?.map((item) => { if (item) { return <li />; } return null; });": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 42,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items.map((item) => item ? <li key={item.id} /> : <li />);": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 50,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "Array.from(items, (item) => <li />);": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 28,
+            message: JsxKeyMessage::MissingKey,
+            hint: JsxKeyHint::MissingKey,
+          }
+        ],
+      },
+      "items.map((item) => <>{item.name}</>);": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 20,
+            message: JsxKeyMessage::FragmentShorthand,
+            hint: JsxKeyHint::FragmentShorthand,
+          }
+        ],
+      },
+      "[<></>];": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 1,
+            message: JsxKeyMessage::FragmentShorthand,
+            hint: JsxKeyHint::FragmentShorthand,
+          }
+        ],
+      },
+    };
+  }
+}
@@ -0,0 +1,212 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use std::collections::HashMap;
+use swc_common::{Span, Spanned};
+
+#[derive(Default)]
+pub struct JsxNoDuplicateProps {
+  options: JsxNoDuplicatePropsOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct JsxNoDuplicatePropsOptions {
+  /// Treats props whose names only differ in case as duplicates.
+  ignore_case: bool,
+}
+
+const CODE: &str = "jsx-no-duplicate-props";
+const HINT: &str =
+  "Remove the duplicate prop, only the last one takes effect anyway";
+
+#[derive(Display)]
+enum JsxNoDuplicatePropsMessage {
+  #[display(fmt = "Duplicate prop `{}`", _0)]
+  Duplicate(String),
+}
+
+impl LintRule for JsxNoDuplicateProps {
+  fn new() -> Box<Self> {
+    Box::new(JsxNoDuplicateProps::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    JsxNoDuplicatePropsHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows passing the same prop more than once to a JSX element
+
+When a prop appears more than once, only the last one takes effect, which is
+almost always a mistake. With `ignoreCase` (`false` by default), props whose
+names only differ in case, like `onClick` and `onclick`, are also reported.
+
+### Invalid:
+```typescript
+<div id="foo" id="bar" />;
+
+// with { "ignoreCase": true }
+<button onClick={foo} onclick={bar} />;
+```
+
+### Valid:
+```typescript
+<div id="foo" className="bar" />;
+<button onClick={foo} onclick={bar} />;
+```
+"#
+  }
+}
+
+struct JsxNoDuplicatePropsHandler {
+  options: JsxNoDuplicatePropsOptions,
+}
+
+impl Handler for JsxNoDuplicatePropsHandler {
+  fn jsx_opening_element(
+    &mut self,
+    jsx_opening_element: &AstView::JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    let mut seen: HashMap<String, (Span, &str)> = HashMap::new();
+    for attr in &jsx_opening_element.attrs {
+      let attr = match attr {
+        AstView::JSXAttrOrSpread::JSXAttr(attr) => attr,
+        AstView::JSXAttrOrSpread::SpreadElement(_) => continue,
+      };
+
+      let name = attr.name.text();
+      let normalized = if self.options.ignore_case {
+        name.to_lowercase()
+      } else {
+        name.to_string()
+      };
+
+      match seen.get(&normalized) {
+        Some(&(first_span, first_name)) => ctx.add_diagnostic_with_related(
+          attr.span(),
+          CODE,
+          JsxNoDuplicatePropsMessage::Duplicate(name.to_string()),
+          HINT,
+          vec![(first_span, format!("`{}` is first passed here", first_name))],
+        ),
+        None => {
+          seen.insert(normalized, (attr.span(), name));
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_duplicate_props_valid() {
+    assert_lint_ok! {
+      JsxNoDuplicateProps,
+      { src: r#"<div id="foo" className="bar" />;"#, filename: "foo.tsx" },
+      { src: "<button onClick={foo} onclick={bar} />;", filename: "foo.tsx" },
+      { src: "<div {...foo} {...foo} />;", filename: "foo.tsx" },
+      { src: r#"<div id="foo"><span id="foo" /></div>;"#, filename: "foo.tsx" },
+      { src: r#"<svg xlink:href="foo" href="bar" />;"#, filename: "foo.tsx" },
+    };
+
+    assert_lint_ok! {
+      JsxNoDuplicateProps,
+      options: { "ignoreCase": true },
+      { src: r#"<div id="foo" className="bar" />;"#, filename: "foo.tsx" },
+    };
+  }
+
+  #[test]
+  fn jsx_no_duplicate_props_invalid() {
+    assert_lint_err! {
+      JsxNoDuplicateProps,
+      r#"<div id="foo" id="bar" />;"#: {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 14,
+            message: variant!(JsxNoDuplicatePropsMessage, Duplicate, "id"),
+            hint: HINT,
+            related: (1, 5, "`id` is first passed here"),
+          }
+        ],
+      },
+      r#"<div id="foo" {...bar} id="baz" id />;"#: {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 23,
+            message: variant!(JsxNoDuplicatePropsMessage, Duplicate, "id"),
+            hint: HINT,
+            related: (1, 5, "`id` is first passed here"),
+          },
+          {
+            col: 32,
+            message: variant!(JsxNoDuplicatePropsMessage, Duplicate, "id"),
+            hint: HINT,
+            related: (1, 5, "`id` is first passed here"),
+          }
+        ],
+      },
+      r#"<svg xlink:href="foo" xlink:href="bar" />;"#: {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 22,
+            message: variant!(JsxNoDuplicatePropsMessage, Duplicate, "xlink:href"),
+            hint: HINT,
+            related: (1, 5, "`xlink:href` is first passed here"),
+          }
+        ],
+      },
+    };
+
+    assert_lint_err! {
+      JsxNoDuplicateProps,
+      options: { "ignoreCase": true },
+      "<button onClick={foo} onclick={bar} />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 22,
+            message: variant!(JsxNoDuplicatePropsMessage, Duplicate, "onclick"),
+            hint: HINT,
+            related: (1, 8, "`onClick` is first passed here"),
+          }
+        ],
+      },
+    };
+  }
+}
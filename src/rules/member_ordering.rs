@@ -0,0 +1,815 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, Accessibility, NodeTrait};
+use serde::Deserialize;
+use std::convert::TryFrom;
+use swc_common::{Span, Spanned};
+
+#[derive(Default)]
+pub struct MemberOrdering {
+  options: MemberOrderingOptions,
+}
+
+const CODE: &str = "member-ordering";
+const HINT: &str =
+  "Move the member so that the members follow the configured order";
+
+#[derive(Display)]
+enum MemberOrderingMessage {
+  #[display(fmt = "Member `{}` should be declared before `{}`", _0, _1)]
+  WrongOrder(String, String),
+}
+
+/// Kind of a member, regardless of its modifiers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MemberKind {
+  Signature,
+  Field,
+  Constructor,
+  Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Scope {
+  Static,
+  Instance,
+}
+
+/// A member type of the configured order, e.g. `public-static-field` or just
+/// `method`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
+struct MemberType {
+  accessibility: Option<Accessibility>,
+  scope: Option<Scope>,
+  kind: MemberKind,
+}
+
+impl TryFrom<String> for MemberType {
+  type Error = String;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    let mut parts = value.rsplit('-');
+    let kind = match parts.next() {
+      Some("signature") => MemberKind::Signature,
+      Some("field") => MemberKind::Field,
+      Some("constructor") => MemberKind::Constructor,
+      Some("method") => MemberKind::Method,
+      _ => return Err(format!("unknown member type `{}`", value)),
+    };
+
+    let mut scope = None;
+    let mut accessibility = None;
+    for part in parts {
+      match part {
+        "static" if scope.is_none() && accessibility.is_none() => {
+          scope = Some(Scope::Static);
+        }
+        "instance" if scope.is_none() && accessibility.is_none() => {
+          scope = Some(Scope::Instance);
+        }
+        "public" if accessibility.is_none() => {
+          accessibility = Some(Accessibility::Public);
+        }
+        "protected" if accessibility.is_none() => {
+          accessibility = Some(Accessibility::Protected);
+        }
+        "private" if accessibility.is_none() => {
+          accessibility = Some(Accessibility::Private);
+        }
+        _ => return Err(format!("unknown member type `{}`", value)),
+      }
+    }
+
+    let has_invalid_modifiers = match kind {
+      MemberKind::Signature => scope.is_some() || accessibility.is_some(),
+      MemberKind::Constructor => scope.is_some(),
+      MemberKind::Field | MemberKind::Method => false,
+    };
+    if has_invalid_modifiers {
+      return Err(format!("unknown member type `{}`", value));
+    }
+
+    Ok(Self {
+      accessibility,
+      scope,
+      kind,
+    })
+  }
+}
+
+/// An entry of the configured order. Member types grouped in an array share
+/// the same rank and can be mixed freely.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Rank {
+  One(MemberType),
+  Many(Vec<MemberType>),
+}
+
+impl Rank {
+  fn contains(&self, member_type: &MemberType) -> bool {
+    match self {
+      Rank::One(t) => t == member_type,
+      Rank::Many(types) => types.contains(member_type),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Never {
+  Never,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Order {
+  Never(Never),
+  MemberTypes(Vec<Rank>),
+}
+
+impl Order {
+  fn ranks(&self) -> Option<&[Rank]> {
+    match self {
+      Order::Never(_) => None,
+      Order::MemberTypes(ranks) => Some(ranks),
+    }
+  }
+}
+
+impl Default for Order {
+  fn default() -> Self {
+    let member_types = [
+      "signature",
+      "public-static-field",
+      "protected-static-field",
+      "private-static-field",
+      "public-instance-field",
+      "protected-instance-field",
+      "private-instance-field",
+      "public-field",
+      "protected-field",
+      "private-field",
+      "static-field",
+      "instance-field",
+      "field",
+      "public-constructor",
+      "protected-constructor",
+      "private-constructor",
+      "constructor",
+      "public-static-method",
+      "protected-static-method",
+      "private-static-method",
+      "public-instance-method",
+      "protected-instance-method",
+      "private-instance-method",
+      "public-method",
+      "protected-method",
+      "private-method",
+      "static-method",
+      "instance-method",
+      "method",
+    ];
+    Order::MemberTypes(
+      member_types
+        .iter()
+        .map(|t| Rank::One(MemberType::try_from(t.to_string()).unwrap()))
+        .collect(),
+    )
+  }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct MemberOrderingOptions {
+  /// Used for every kind of body that doesn't have its own order configured.
+  default: Order,
+  classes: Option<Order>,
+  interfaces: Option<Order>,
+  type_literals: Option<Order>,
+}
+
+impl LintRule for MemberOrdering {
+  fn new() -> Box<Self> {
+    Box::new(MemberOrdering::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    MemberOrderingHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent order of members in classes, interfaces and type literals
+
+Keeping members in a predictable order, e.g. fields first, then the
+constructor, then methods, makes it easy to find things in a class.
+
+The order is an array of member types, each of the form
+`[public|protected|private-][static|instance-]<kind>` where the kind is one of
+`signature` (index signatures), `field`, `constructor` and `method`. Getters,
+setters and fields initialized with a function count as methods, `#private`
+members as `private`, and members without an accessibility modifier as
+`public`. Each member is ranked by the most specific member type that matches
+it, and members matching none of them can go anywhere. Member types grouped in
+an array share the same rank.
+
+The order is set with the `default` option, and it can be overridden for
+`classes`, `interfaces` and `typeLiterals`. `"never"` disables the check. By
+default index signatures come first, then fields, the constructor and
+methods, each sorted by static before instance and by public, protected and
+private accessibility.
+
+Only the first out-of-order member of each body is reported, along with the
+member it should be declared before.
+
+### Invalid:
+```typescript
+class Foo {
+  constructor() {}
+  bar = 1;
+}
+
+class Foo {
+  private bar = 1;
+  public baz = 2;
+}
+
+interface Foo {
+  bar(): void;
+  baz: string;
+}
+
+// with { "default": ["method", "field"] }
+class Foo {
+  bar = 1;
+  baz() {}
+}
+```
+
+### Valid:
+```typescript
+class Foo {
+  static foo = 1;
+  public bar = 1;
+  private baz = 2;
+  constructor() {}
+  qux() {}
+  #quux() {}
+}
+
+interface Foo {
+  [key: string]: unknown;
+  baz: string;
+  bar(): void;
+}
+
+// with { "default": "never" }
+class Foo {
+  qux() {}
+  bar = 1;
+}
+```
+"#
+  }
+}
+
+/// Info about a member needed to rank it.
+struct Member {
+  span: Span,
+  name: String,
+  member_type: MemberType,
+}
+
+impl Member {
+  fn new(
+    span: Span,
+    name: String,
+    kind: MemberKind,
+    scope: Scope,
+    accessibility: Option<Accessibility>,
+  ) -> Self {
+    Self {
+      span,
+      name,
+      member_type: MemberType {
+        accessibility: Some(accessibility.unwrap_or(Accessibility::Public)),
+        scope: Some(scope),
+        kind,
+      },
+    }
+  }
+
+  /// Returns the index of the most specific member type in the order that
+  /// matches this member.
+  fn rank(&self, ranks: &[Rank]) -> Option<usize> {
+    let MemberType {
+      accessibility,
+      scope,
+      kind,
+    } = self.member_type;
+    let candidates = match kind {
+      MemberKind::Signature => vec![(None, None)],
+      MemberKind::Constructor => vec![(accessibility, None), (None, None)],
+      MemberKind::Field | MemberKind::Method => vec![
+        (accessibility, scope),
+        (accessibility, None),
+        (None, scope),
+        (None, None),
+      ],
+    };
+
+    candidates.into_iter().find_map(|(accessibility, scope)| {
+      let member_type = MemberType {
+        accessibility,
+        scope,
+        kind,
+      };
+      ranks.iter().position(|rank| rank.contains(&member_type))
+    })
+  }
+}
+
+fn scope(is_static: bool) -> Scope {
+  if is_static {
+    Scope::Static
+  } else {
+    Scope::Instance
+  }
+}
+
+fn class_member(member: &AstView::ClassMember) -> Option<Member> {
+  use AstView::ClassMember::*;
+  let member = match member {
+    Constructor(constructor) => Member::new(
+      constructor.span(),
+      constructor.key.text().to_string(),
+      MemberKind::Constructor,
+      Scope::Instance,
+      constructor.accessibility(),
+    ),
+    Method(method) => Member::new(
+      method.span(),
+      method.key.text().to_string(),
+      MemberKind::Method,
+      scope(method.is_static()),
+      method.accessibility(),
+    ),
+    PrivateMethod(method) => Member::new(
+      method.span(),
+      method.key.text().to_string(),
+      MemberKind::Method,
+      scope(method.is_static()),
+      Some(Accessibility::Private),
+    ),
+    ClassProp(prop) => {
+      let kind = match prop.value {
+        Some(AstView::Expr::Fn(_)) | Some(AstView::Expr::Arrow(_)) => {
+          MemberKind::Method
+        }
+        _ => MemberKind::Field,
+      };
+      let name = if prop.computed() {
+        format!("[{}]", prop.key.text())
+      } else {
+        prop.key.text().to_string()
+      };
+      Member::new(
+        prop.span(),
+        name,
+        kind,
+        scope(prop.is_static()),
+        prop.accessibility(),
+      )
+    }
+    PrivateProp(prop) => {
+      let kind = match prop.value {
+        Some(AstView::Expr::Fn(_)) | Some(AstView::Expr::Arrow(_)) => {
+          MemberKind::Method
+        }
+        _ => MemberKind::Field,
+      };
+      Member::new(
+        prop.span(),
+        prop.key.text().to_string(),
+        kind,
+        scope(prop.is_static()),
+        Some(Accessibility::Private),
+      )
+    }
+    TsIndexSignature(signature) => Member::new(
+      signature.span(),
+      signature.text().to_string(),
+      MemberKind::Signature,
+      scope(signature.is_static()),
+      None,
+    ),
+    Empty(_) => return None,
+  };
+  Some(member)
+}
+
+fn type_element(element: &AstView::TsTypeElement) -> Option<Member> {
+  use AstView::TsTypeElement::*;
+  let (name, kind) = match element {
+    TsPropertySignature(signature) => {
+      (signature.key.text().to_string(), MemberKind::Field)
+    }
+    TsMethodSignature(signature) => {
+      (signature.key.text().to_string(), MemberKind::Method)
+    }
+    TsGetterSignature(signature) => {
+      (signature.key.text().to_string(), MemberKind::Method)
+    }
+    TsSetterSignature(signature) => {
+      (signature.key.text().to_string(), MemberKind::Method)
+    }
+    TsConstructSignatureDecl(_) => ("new".to_string(), MemberKind::Constructor),
+    TsIndexSignature(signature) => {
+      (signature.text().to_string(), MemberKind::Signature)
+    }
+    TsCallSignatureDecl(_) => return None,
+  };
+  Some(Member::new(
+    element.span(),
+    name,
+    kind,
+    Scope::Instance,
+    None,
+  ))
+}
+
+struct MemberOrderingHandler<'a> {
+  options: &'a MemberOrderingOptions,
+}
+
+impl<'a> MemberOrderingHandler<'a> {
+  fn check_order(
+    &self,
+    order: Option<&Order>,
+    members: impl Iterator<Item = Member>,
+    ctx: &mut Context,
+  ) {
+    let ranks = match order.unwrap_or(&self.options.default).ranks() {
+      Some(ranks) => ranks,
+      None => return,
+    };
+
+    let mut previous: Vec<(usize, Member)> = Vec::new();
+    for member in members {
+      let rank = match member.rank(ranks) {
+        Some(rank) => rank,
+        None => continue,
+      };
+
+      if let Some((_, before)) = previous.iter().find(|(r, _)| *r > rank) {
+        ctx.add_diagnostic_with_related(
+          member.span,
+          CODE,
+          MemberOrderingMessage::WrongOrder(
+            member.name.clone(),
+            before.name.clone(),
+          ),
+          HINT,
+          vec![(
+            before.span,
+            format!("`{}` should be declared before this member", member.name),
+          )],
+        );
+        return;
+      }
+
+      previous.push((rank, member));
+    }
+  }
+}
+
+impl<'a> Handler for MemberOrderingHandler<'a> {
+  fn class(&mut self, class: &AstView::Class, ctx: &mut Context) {
+    self.check_order(
+      self.options.classes.as_ref(),
+      class.body.iter().filter_map(class_member),
+      ctx,
+    );
+  }
+
+  fn ts_interface_body(
+    &mut self,
+    ts_interface_body: &AstView::TsInterfaceBody,
+    ctx: &mut Context,
+  ) {
+    self.check_order(
+      self.options.interfaces.as_ref(),
+      ts_interface_body.body.iter().filter_map(type_element),
+      ctx,
+    );
+  }
+
+  fn ts_type_lit(
+    &mut self,
+    ts_type_lit: &AstView::TsTypeLit,
+    ctx: &mut Context,
+  ) {
+    self.check_order(
+      self.options.type_literals.as_ref(),
+      ts_type_lit.members.iter().filter_map(type_element),
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn member_ordering_valid() {
+    assert_lint_ok! {
+      MemberOrdering,
+      "class Foo {}",
+      r#"
+class Foo {
+  [key: string]: unknown;
+  static a = 1;
+  protected static b = 1;
+  private static c = 1;
+  d = 1;
+  protected e = 1;
+  private f = 1;
+  #g = 1;
+  constructor() {}
+  static h() {}
+  i() {}
+  get j() { return 1; }
+  k = () => {};
+  protected l() {}
+  private m() {}
+  #n() {}
+}
+"#,
+      r#"
+class Foo {
+  a = 1;
+  ;
+  b() {}
+}
+"#,
+      r#"
+interface Foo {
+  [key: string]: unknown;
+  a: string;
+  new (): Foo;
+  b(): void;
+  (): void;
+}
+"#,
+      r#"
+type Foo = {
+  a: string;
+  b(): void;
+};
+"#,
+      r#"
+const Foo = class {
+  a = 1;
+  constructor() {}
+};
+"#,
+    };
+
+    assert_lint_ok! {
+      MemberOrdering,
+      options: { "default": "never" },
+      "class Foo { a() {} b = 1; }",
+      "interface Foo { a(): void; b: string; }",
+    };
+
+    assert_lint_ok! {
+      MemberOrdering,
+      options: { "default": ["method", "field"], "interfaces": "never" },
+      "class Foo { a() {} b = 1; }",
+      "class Foo { a() {} constructor() {} b = 1; }",
+      "interface Foo { b: string; a(): void; }",
+    };
+
+    assert_lint_ok! {
+      MemberOrdering,
+      options: { "default": [["field", "method"], "constructor"] },
+      "class Foo { a() {} b = 1; c() {} constructor() {} }",
+    };
+
+    assert_lint_ok! {
+      MemberOrdering,
+      options: { "default": ["private-method", "public-field", "method"] },
+      "class Foo { #a() {} private b() {} c = 1; d() {} protected e() {} }",
+    };
+  }
+
+  #[test]
+  fn member_ordering_invalid() {
+    assert_lint_err! {
+      MemberOrdering,
+      r#"
+class Foo {
+  constructor() {}
+  bar = 1;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(
+            MemberOrderingMessage,
+            WrongOrder,
+            "bar",
+            "constructor"
+          ),
+          hint: HINT,
+          related: (3, 2, "`bar` should be declared before this member"),
+        }
+      ],
+      r#"
+class Foo {
+  a = 1;
+  b() {}
+  private c = 1;
+  d = 1;
+}
+"#: [
+        {
+          line: 5,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "c", "b"),
+          hint: HINT,
+          related: (4, 2, "`c` should be declared before this member"),
+        }
+      ],
+      r#"
+class Foo {
+  private a = 1;
+  b = 1;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+      r#"
+class Foo {
+  a = 1;
+  static b = 1;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+      r#"
+class Foo {
+  #a() {}
+  b() {}
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "#a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+      r#"
+class Foo {
+  a = () => {};
+  b = 1;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+      r#"
+interface Foo {
+  a(): void;
+  b: string;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+      r#"
+type Foo = {
+  a: string;
+  [key: string]: unknown;
+};
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(
+            MemberOrderingMessage,
+            WrongOrder,
+            "[key: string]: unknown;",
+            "a"
+          ),
+          hint: HINT,
+          related: (
+            3,
+            2,
+            "`[key: string]: unknown;` should be declared before this member"
+          ),
+        }
+      ],
+      r#"
+class Foo {
+  a() {}
+  b = 1;
+  c = 1;
+  static d = 1;
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (3, 2, "`b` should be declared before this member"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MemberOrdering,
+      options: { "default": ["method", "field"], "classes": ["field", "method"] },
+      "class Foo { a() {} b = 1; }": [
+        {
+          col: 19,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (1, 12, "`b` should be declared before this member"),
+        }
+      ],
+      "interface Foo { a: string; b(): void; }": [
+        {
+          col: 27,
+          message: variant!(MemberOrderingMessage, WrongOrder, "b", "a"),
+          hint: HINT,
+          related: (1, 16, "`b` should be declared before this member"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn member_ordering_invalid_options() {
+    for member_type in &["foo", "static-signature", "static-constructor"] {
+      let mut rule = MemberOrdering::default();
+      assert!(rule
+        .set_options(&serde_json::json!({ "default": [member_type] }))
+        .is_err());
+    }
+  }
+}
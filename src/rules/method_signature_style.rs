@@ -0,0 +1,447 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait, SpannedExt};
+use serde::Deserialize;
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecmascript::parser::token::Token;
+
+#[derive(Default)]
+pub struct MethodSignatureStyle {
+  mode: Mode,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+  /// Function members have to be properties, e.g. `foo: () => void`.
+  Property,
+  /// Function members have to be methods, e.g. `foo(): void`.
+  Method,
+}
+
+impl Default for Mode {
+  fn default() -> Self {
+    Mode::Property
+  }
+}
+
+const CODE: &str = "method-signature-style";
+
+#[derive(Display)]
+enum MethodSignatureStyleMessage {
+  #[display(fmt = "Use a property signature instead of a method signature")]
+  UseProperty,
+  #[display(fmt = "Use a method signature instead of a property signature")]
+  UseMethod,
+}
+
+#[derive(Display)]
+enum MethodSignatureStyleHint {
+  #[display(
+    fmt = "Declare it as a property with a function type, e.g. `foo: () => void`"
+  )]
+  UseProperty,
+  #[display(fmt = "Declare it as a method, e.g. `foo(): void`")]
+  UseMethod,
+}
+
+const USE_PROPERTY_FIX_DESC: &str = "Convert to a property signature";
+const USE_METHOD_FIX_DESC: &str = "Convert to a method signature";
+
+impl LintRule for MethodSignatureStyle {
+  fn new() -> Box<Self> {
+    Box::new(MethodSignatureStyle::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    MethodSignatureStyleHandler { mode: self.mode }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent style for function members of interfaces and type literals
+
+A function member can be declared as a method, `foo(): void`, or as a property
+with a function type, `foo: () => void`. With `strictFunctionTypes`,
+TypeScript checks the parameters of properties contravariantly but still
+checks the parameters of methods bivariantly, so the property style is safer.
+
+This rule enforces the property style by default, or the method style with
+the `"method"` option. Overloaded methods are reported but not fixed
+automatically, and `readonly` properties are never reported in `"method"`
+mode since a method can't be `readonly`.
+
+### Invalid:
+```typescript
+interface Foo {
+  bar(baz: string): number;
+}
+type Qux = { quux?(): void };
+
+// with "method"
+interface Foo {
+  bar: (baz: string) => number;
+}
+```
+
+### Valid:
+```typescript
+interface Foo {
+  bar: (baz: string) => number;
+}
+type Qux = { quux?: () => void };
+
+// with "method"
+interface Foo {
+  bar(baz: string): number;
+}
+```
+"#
+  }
+}
+
+/// Returns the key of a type member the way it's written before the
+/// parameters or the type annotation, e.g. `[foo]?`.
+fn key_text(key: &AstView::Expr, computed: bool, optional: bool) -> String {
+  format!(
+    "{}{}",
+    if computed {
+      format!("[{}]", key.text())
+    } else {
+      key.text().to_string()
+    },
+    if optional { "?" } else { "" }
+  )
+}
+
+/// Returns the part of a node's source text from `lo` up to `hi`, with
+/// trailing whitespace removed.
+fn source_between<'a>(
+  node: &AstView::Node<'a>,
+  lo: BytePos,
+  hi: BytePos,
+) -> &'a str {
+  let start = (lo - node.lo()).0 as usize;
+  let end = (hi - node.lo()).0 as usize;
+  node.text()[start..end].trim_end()
+}
+
+fn is_overloaded(ts_method_signature: &AstView::TsMethodSignature) -> bool {
+  let is_same_key = |other: &AstView::TsMethodSignature| {
+    other.span() != ts_method_signature.span()
+      && other.computed() == ts_method_signature.computed()
+      && other.key.text() == ts_method_signature.key.text()
+  };
+  ts_method_signature.parent.children().iter().any(|sibling| {
+    matches!(sibling, AstView::Node::TsMethodSignature(other) if is_same_key(other))
+  })
+}
+
+/// Builds a property signature equivalent to the given method signature,
+/// e.g. `foo: (a: string) => void` for `foo(a: string): void`. The returned
+/// span covers the part of the method signature that's replaced.
+fn build_property(
+  ts_method_signature: &AstView::TsMethodSignature,
+) -> Option<(Span, String)> {
+  let tokens = ts_method_signature.tokens();
+  let key_hi = ts_method_signature.key.hi();
+  let params_lo = match ts_method_signature.type_params {
+    Some(type_params) => type_params.lo(),
+    None => tokens
+      .iter()
+      .find(|t| t.token == Token::LParen && t.span.lo() >= key_hi)?
+      .span
+      .lo(),
+  };
+  let params_hi = match ts_method_signature.type_ann {
+    Some(type_ann) => tokens
+      .iter()
+      .rev()
+      .find(|t| t.token == Token::RParen && t.span.hi() <= type_ann.lo())?
+      .span
+      .hi(),
+    None => tokens
+      .iter()
+      .rev()
+      .find(|t| t.token == Token::RParen)?
+      .span
+      .hi(),
+  };
+
+  let return_type = ts_method_signature
+    .type_ann
+    .map_or("any", |type_ann| type_ann.type_ann.text());
+  let replaced_hi = ts_method_signature
+    .type_ann
+    .map_or(params_hi, |type_ann| type_ann.hi());
+
+  Some((
+    ts_method_signature.span().with_hi(replaced_hi),
+    format!(
+      "{}: {} => {}",
+      key_text(
+        &ts_method_signature.key,
+        ts_method_signature.computed(),
+        ts_method_signature.optional()
+      ),
+      source_between(&ts_method_signature.into(), params_lo, params_hi),
+      return_type
+    ),
+  ))
+}
+
+/// Builds a method signature equivalent to the given property signature with
+/// a function type, e.g. `foo(a: string): void` for
+/// `foo: (a: string) => void`. The returned span covers the part of the
+/// property signature that's replaced.
+fn build_method(
+  ts_property_signature: &AstView::TsPropertySignature,
+  fn_type: &AstView::TsFnType,
+) -> Option<(Span, String)> {
+  let tokens = fn_type.tokens();
+  let params_lo = match fn_type.type_params {
+    Some(type_params) => type_params.lo(),
+    None => tokens.iter().find(|t| t.token == Token::LParen)?.span.lo(),
+  };
+  let return_type = &fn_type.type_ann.type_ann;
+  let arrow_lo = tokens
+    .iter()
+    .rev()
+    .find(|t| t.token == Token::Arrow && t.span.hi() <= return_type.lo())?
+    .span
+    .lo();
+
+  Some((
+    ts_property_signature.span().with_hi(fn_type.hi()),
+    format!(
+      "{}{}: {}",
+      key_text(
+        &ts_property_signature.key,
+        ts_property_signature.computed(),
+        ts_property_signature.optional()
+      ),
+      source_between(&fn_type.into(), params_lo, arrow_lo),
+      return_type.text()
+    ),
+  ))
+}
+
+struct MethodSignatureStyleHandler {
+  mode: Mode,
+}
+
+impl Handler for MethodSignatureStyleHandler {
+  fn ts_method_signature(
+    &mut self,
+    ts_method_signature: &AstView::TsMethodSignature,
+    ctx: &mut Context,
+  ) {
+    if self.mode != Mode::Property {
+      return;
+    }
+
+    let fix = if is_overloaded(ts_method_signature) {
+      None
+    } else {
+      build_property(ts_method_signature)
+    };
+    match fix {
+      Some(fix) => ctx.add_diagnostic_with_fix(
+        ts_method_signature.span(),
+        CODE,
+        MethodSignatureStyleMessage::UseProperty,
+        MethodSignatureStyleHint::UseProperty,
+        USE_PROPERTY_FIX_DESC,
+        vec![fix],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        ts_method_signature.span(),
+        CODE,
+        MethodSignatureStyleMessage::UseProperty,
+        MethodSignatureStyleHint::UseProperty,
+      ),
+    }
+  }
+
+  fn ts_property_signature(
+    &mut self,
+    ts_property_signature: &AstView::TsPropertySignature,
+    ctx: &mut Context,
+  ) {
+    if self.mode != Mode::Method || ts_property_signature.readonly() {
+      return;
+    }
+
+    let fn_type = match ts_property_signature
+      .type_ann
+      .map(|type_ann| &type_ann.type_ann)
+    {
+      Some(AstView::TsType::TsFnOrConstructorType(
+        AstView::TsFnOrConstructorType::TsFnType(fn_type),
+      )) => fn_type,
+      _ => return,
+    };
+
+    match build_method(ts_property_signature, fn_type) {
+      Some(fix) => ctx.add_diagnostic_with_fix(
+        ts_property_signature.span(),
+        CODE,
+        MethodSignatureStyleMessage::UseMethod,
+        MethodSignatureStyleHint::UseMethod,
+        USE_METHOD_FIX_DESC,
+        vec![fix],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        ts_property_signature.span(),
+        CODE,
+        MethodSignatureStyleMessage::UseMethod,
+        MethodSignatureStyleHint::UseMethod,
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn method_signature_style_valid() {
+    assert_lint_ok! {
+      MethodSignatureStyle,
+      "interface Foo { bar: () => void; }",
+      "interface Foo { bar?: (baz: string) => number; }",
+      "type Foo = { bar: <T>(baz: T) => T };",
+      "interface Foo { (): void; new (): Foo; }",
+      "interface Foo { get bar(): string; }",
+      "class Foo { bar(): void {} }",
+      "let foo = { bar() {} };",
+    };
+
+    assert_lint_ok! {
+      MethodSignatureStyle,
+      options: "method",
+      "interface Foo { bar(): void; }",
+      "interface Foo { bar: string; }",
+      "interface Foo { readonly bar: () => void; }",
+      "interface Foo { bar: (() => void) | string; }",
+      "type Foo = { bar(baz: string): number };",
+    };
+  }
+
+  #[test]
+  fn method_signature_style_invalid() {
+    assert_lint_err! {
+      MethodSignatureStyle,
+      "interface Foo { bar(): void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (USE_PROPERTY_FIX_DESC, "interface Foo { bar: () => void; }"),
+        }
+      ],
+      "interface Foo { bar?(baz: string, ...qux: number[]): Promise<void>; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (
+            USE_PROPERTY_FIX_DESC,
+            "interface Foo { bar?: (baz: string, ...qux: number[]) => Promise<void>; }"
+          ),
+        }
+      ],
+      "type Foo = { bar<T>(baz: T): T };": [
+        {
+          col: 13,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (USE_PROPERTY_FIX_DESC, "type Foo = { bar: <T>(baz: T) => T };"),
+        }
+      ],
+      "interface Foo { [bar](baz: (a: string) => void): void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (
+            USE_PROPERTY_FIX_DESC,
+            "interface Foo { [bar]: (baz: (a: string) => void) => void; }"
+          ),
+        }
+      ],
+      "interface Foo { bar(); }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (USE_PROPERTY_FIX_DESC, "interface Foo { bar: () => any; }"),
+        }
+      ],
+      "interface Foo { bar(): void; bar(baz: string): void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+        },
+        {
+          col: 29,
+          message: MethodSignatureStyleMessage::UseProperty,
+          hint: MethodSignatureStyleHint::UseProperty,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MethodSignatureStyle,
+      options: "method",
+      "interface Foo { bar: () => void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseMethod,
+          hint: MethodSignatureStyleHint::UseMethod,
+          fix: (USE_METHOD_FIX_DESC, "interface Foo { bar(): void; }"),
+        }
+      ],
+      "type Foo = { bar?: <T>(baz: T) => () => T };": [
+        {
+          col: 13,
+          message: MethodSignatureStyleMessage::UseMethod,
+          hint: MethodSignatureStyleHint::UseMethod,
+          fix: (USE_METHOD_FIX_DESC, "type Foo = { bar?<T>(baz: T): () => T };"),
+        }
+      ],
+      "interface Foo { [bar]: (baz: string) => number; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::UseMethod,
+          hint: MethodSignatureStyleHint::UseMethod,
+          fix: (USE_METHOD_FIX_DESC, "interface Foo { [bar](baz: string): number; }"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,1312 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, MethodKind};
+use regex::Regex;
+use serde::Deserialize;
+use std::convert::TryFrom;
+use swc_common::{Span, Spanned};
+
+pub struct NamingConvention {
+  configs: Vec<NamingConfig>,
+}
+
+impl Default for NamingConvention {
+  fn default() -> Self {
+    Self {
+      configs: vec![
+        NamingConfig::new(Selector::Default, &[Format::CamelCase]),
+        NamingConfig::new(
+          Selector::Variable,
+          &[Format::CamelCase, Format::UpperCase],
+        ),
+        NamingConfig::new(Selector::TypeLike, &[Format::PascalCase]),
+      ],
+    }
+  }
+}
+
+const CODE: &str = "naming-convention";
+const HINT: &str =
+  "Rename it to follow the naming convention configured for this kind of name";
+
+/// The kinds of names a config applies to. Group selectors like `typeLike`
+/// cover several individual ones.
+#[derive(Clone, Copy, Debug, Deserialize, Display, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum Selector {
+  #[display(fmt = "Name")]
+  Default,
+  #[display(fmt = "Variable-like")]
+  VariableLike,
+  #[display(fmt = "Variable")]
+  Variable,
+  #[display(fmt = "Function")]
+  Function,
+  #[display(fmt = "Parameter")]
+  Parameter,
+  #[display(fmt = "Member")]
+  MemberLike,
+  #[display(fmt = "Property")]
+  Property,
+  #[display(fmt = "Class property")]
+  ClassProperty,
+  #[display(fmt = "Object literal property")]
+  ObjectLiteralProperty,
+  #[display(fmt = "Type property")]
+  TypeProperty,
+  #[display(fmt = "Method")]
+  Method,
+  #[display(fmt = "Class method")]
+  ClassMethod,
+  #[display(fmt = "Object literal method")]
+  ObjectLiteralMethod,
+  #[display(fmt = "Type method")]
+  TypeMethod,
+  #[display(fmt = "Accessor")]
+  Accessor,
+  #[display(fmt = "Enum member")]
+  EnumMember,
+  #[display(fmt = "Type-like")]
+  TypeLike,
+  #[display(fmt = "Class")]
+  Class,
+  #[display(fmt = "Interface")]
+  Interface,
+  #[display(fmt = "Type alias")]
+  TypeAlias,
+  #[display(fmt = "Enum")]
+  Enum,
+  #[display(fmt = "Type parameter")]
+  TypeParameter,
+}
+
+impl Selector {
+  /// Configs for more specific selectors take precedence over less specific
+  /// ones.
+  fn specificity(self) -> u8 {
+    use Selector::*;
+    match self {
+      Default => 0,
+      VariableLike | MemberLike | TypeLike => 1,
+      Property | Method => 2,
+      _ => 3,
+    }
+  }
+
+  /// Checks if the selector covers names of the given individual kind.
+  fn matches(self, kind: Selector) -> bool {
+    use Selector::*;
+    match self {
+      Default => true,
+      VariableLike => matches!(kind, Variable | Function | Parameter),
+      MemberLike => matches!(
+        kind,
+        ClassProperty
+          | ObjectLiteralProperty
+          | TypeProperty
+          | ClassMethod
+          | ObjectLiteralMethod
+          | TypeMethod
+          | Accessor
+          | EnumMember
+      ),
+      Property => {
+        matches!(kind, ClassProperty | ObjectLiteralProperty | TypeProperty)
+      }
+      Method => matches!(kind, ClassMethod | ObjectLiteralMethod | TypeMethod),
+      TypeLike => {
+        matches!(kind, Class | Interface | TypeAlias | Enum | TypeParameter)
+      }
+      _ => self == kind,
+    }
+  }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, PartialEq)]
+enum Format {
+  #[serde(rename = "camelCase")]
+  #[display(fmt = "camelCase")]
+  CamelCase,
+  #[serde(rename = "strictCamelCase")]
+  #[display(fmt = "strictCamelCase")]
+  StrictCamelCase,
+  #[serde(rename = "PascalCase")]
+  #[display(fmt = "PascalCase")]
+  PascalCase,
+  #[serde(rename = "StrictPascalCase")]
+  #[display(fmt = "StrictPascalCase")]
+  StrictPascalCase,
+  #[serde(rename = "snake_case")]
+  #[display(fmt = "snake_case")]
+  SnakeCase,
+  #[serde(rename = "UPPER_CASE")]
+  #[display(fmt = "UPPER_CASE")]
+  UpperCase,
+}
+
+impl Format {
+  fn check(self, name: &str) -> bool {
+    let first = match name.chars().next() {
+      Some(first) => first,
+      None => return true,
+    };
+
+    match self {
+      Format::CamelCase => !first.is_uppercase() && !name.contains('_'),
+      Format::StrictCamelCase => {
+        !first.is_uppercase() && has_strict_camel_humps(name, false)
+      }
+      Format::PascalCase => !first.is_lowercase() && !name.contains('_'),
+      Format::StrictPascalCase => {
+        !first.is_lowercase() && has_strict_camel_humps(name, true)
+      }
+      Format::SnakeCase => {
+        name.to_lowercase() == name && has_valid_underscores(name)
+      }
+      Format::UpperCase => {
+        name.to_uppercase() == name && has_valid_underscores(name)
+      }
+    }
+  }
+}
+
+/// Checks that upper and lower case letters alternate, i.e. that there are no
+/// consecutive upper case letters like in `fooBAR`.
+fn has_strict_camel_humps(name: &str, mut is_upper: bool) -> bool {
+  for c in name.chars().skip(1) {
+    if c == '_' {
+      return false;
+    }
+    if is_upper == c.is_uppercase() {
+      if is_upper {
+        return false;
+      }
+    } else {
+      is_upper = !is_upper;
+    }
+  }
+  true
+}
+
+/// Checks that underscores only appear as single separators between words.
+fn has_valid_underscores(name: &str) -> bool {
+  !name.starts_with('_') && !name.ends_with('_') && !name.contains("__")
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum UnderscoreOption {
+  /// Disallows the underscore.
+  Forbid,
+  /// Requires a single underscore.
+  Require,
+  /// Allows a single underscore.
+  Allow,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+  One(T),
+  Many(Vec<T>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "RawMatchRegex")]
+struct MatchRegex {
+  regex: Regex,
+  matches: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawMatchRegex {
+  regex: String,
+  #[serde(rename = "match")]
+  matches: bool,
+}
+
+impl TryFrom<RawMatchRegex> for MatchRegex {
+  type Error = regex::Error;
+
+  fn try_from(raw: RawMatchRegex) -> Result<Self, Self::Error> {
+    Ok(Self {
+      regex: Regex::new(&raw.regex)?,
+      matches: raw.matches,
+    })
+  }
+}
+
+impl MatchRegex {
+  fn test(&self, name: &str) -> bool {
+    self.regex.is_match(name) == self.matches
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct NamingConfig {
+  selector: OneOrMany<Selector>,
+  /// The name has to satisfy one of these formats. `None` skips the check.
+  #[serde(default)]
+  format: Option<Vec<Format>>,
+  #[serde(default)]
+  leading_underscore: Option<UnderscoreOption>,
+  #[serde(default)]
+  trailing_underscore: Option<UnderscoreOption>,
+  /// The name has to start with one of these, which is trimmed before the
+  /// format is checked.
+  #[serde(default)]
+  prefix: Vec<String>,
+  /// The name has to end with one of these, which is trimmed before the
+  /// format is checked.
+  #[serde(default)]
+  suffix: Vec<String>,
+  /// A regex the whole name has to match, or not match.
+  #[serde(default)]
+  custom: Option<MatchRegex>,
+  /// Restricts the config to names that match, or don't match, the regex.
+  #[serde(default)]
+  filter: Option<MatchRegex>,
+}
+
+impl NamingConfig {
+  fn new(selector: Selector, format: &[Format]) -> Self {
+    Self {
+      selector: OneOrMany::One(selector),
+      format: Some(format.to_vec()),
+      leading_underscore: Some(UnderscoreOption::Allow),
+      trailing_underscore: Some(UnderscoreOption::Allow),
+      prefix: vec![],
+      suffix: vec![],
+      custom: None,
+      filter: None,
+    }
+  }
+
+  /// Returns the specificity of the most specific selector of this config
+  /// that covers the given kind, if any.
+  fn specificity_for(&self, kind: Selector) -> Option<u8> {
+    let selectors = match &self.selector {
+      OneOrMany::One(selector) => std::slice::from_ref(selector),
+      OneOrMany::Many(selectors) => selectors.as_slice(),
+    };
+    selectors
+      .iter()
+      .filter(|selector| selector.matches(kind))
+      .map(|selector| selector.specificity())
+      .max()
+  }
+
+  fn check(
+    &self,
+    kind: Selector,
+    name: &str,
+  ) -> Option<NamingConventionMessage> {
+    let kind = kind.to_string();
+    let mut rest = name;
+
+    match self.leading_underscore {
+      Some(UnderscoreOption::Forbid) if rest.starts_with('_') => {
+        return Some(NamingConventionMessage::UnexpectedUnderscore(
+          kind,
+          name.to_string(),
+          "leading".to_string(),
+        ));
+      }
+      Some(UnderscoreOption::Require) if !rest.starts_with('_') => {
+        return Some(NamingConventionMessage::MissingUnderscore(
+          kind,
+          name.to_string(),
+          "leading".to_string(),
+        ));
+      }
+      Some(UnderscoreOption::Require) | Some(UnderscoreOption::Allow) => {
+        rest = rest.strip_prefix('_').unwrap_or(rest);
+      }
+      _ => {}
+    }
+
+    match self.trailing_underscore {
+      Some(UnderscoreOption::Forbid) if rest.ends_with('_') => {
+        return Some(NamingConventionMessage::UnexpectedUnderscore(
+          kind,
+          name.to_string(),
+          "trailing".to_string(),
+        ));
+      }
+      Some(UnderscoreOption::Require) if !rest.ends_with('_') => {
+        return Some(NamingConventionMessage::MissingUnderscore(
+          kind,
+          name.to_string(),
+          "trailing".to_string(),
+        ));
+      }
+      Some(UnderscoreOption::Require) | Some(UnderscoreOption::Allow) => {
+        rest = rest.strip_suffix('_').unwrap_or(rest);
+      }
+      _ => {}
+    }
+
+    if !self.prefix.is_empty() {
+      match self.prefix.iter().find(|prefix| rest.starts_with(*prefix)) {
+        Some(prefix) => rest = &rest[prefix.len()..],
+        None => {
+          return Some(NamingConventionMessage::MissingPrefix(
+            kind,
+            name.to_string(),
+            self.prefix.join(", "),
+          ));
+        }
+      }
+    }
+
+    if !self.suffix.is_empty() {
+      match self.suffix.iter().find(|suffix| rest.ends_with(*suffix)) {
+        Some(suffix) => rest = &rest[..rest.len() - suffix.len()],
+        None => {
+          return Some(NamingConventionMessage::MissingSuffix(
+            kind,
+            name.to_string(),
+            self.suffix.join(", "),
+          ));
+        }
+      }
+    }
+
+    if let Some(custom) = &self.custom {
+      if !custom.test(name) {
+        let regex = custom.regex.to_string();
+        return Some(if custom.matches {
+          NamingConventionMessage::NotMatchingCustom(
+            kind,
+            name.to_string(),
+            regex,
+          )
+        } else {
+          NamingConventionMessage::MatchingCustom(kind, name.to_string(), regex)
+        });
+      }
+    }
+
+    if let Some(format) = &self.format {
+      if !format.iter().any(|format| format.check(rest)) {
+        let formats = format
+          .iter()
+          .map(|format| format.to_string())
+          .collect::<Vec<_>>()
+          .join(", ");
+        return Some(NamingConventionMessage::Format(
+          kind,
+          name.to_string(),
+          formats,
+        ));
+      }
+    }
+
+    None
+  }
+}
+
+#[derive(Display)]
+enum NamingConventionMessage {
+  #[display(
+    fmt = "{} name `{}` must match one of the following formats: {}",
+    _0,
+    _1,
+    _2
+  )]
+  Format(String, String, String),
+  #[display(fmt = "{} name `{}` must not have a {} underscore", _0, _1, _2)]
+  UnexpectedUnderscore(String, String, String),
+  #[display(fmt = "{} name `{}` must have a {} underscore", _0, _1, _2)]
+  MissingUnderscore(String, String, String),
+  #[display(
+    fmt = "{} name `{}` must have one of the following prefixes: {}",
+    _0,
+    _1,
+    _2
+  )]
+  MissingPrefix(String, String, String),
+  #[display(
+    fmt = "{} name `{}` must have one of the following suffixes: {}",
+    _0,
+    _1,
+    _2
+  )]
+  MissingSuffix(String, String, String),
+  #[display(fmt = "{} name `{}` must match the regex `{}`", _0, _1, _2)]
+  NotMatchingCustom(String, String, String),
+  #[display(fmt = "{} name `{}` must not match the regex `{}`", _0, _1, _2)]
+  MatchingCustom(String, String, String),
+}
+
+impl LintRule for NamingConvention {
+  fn new() -> Box<Self> {
+    Box::new(NamingConvention::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.configs = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NamingConventionHandler {
+      configs: &self.configs,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces naming conventions for everything across a codebase
+
+This rule is a more flexible take on `camelcase`, especially for TypeScript
+code. It's configured with an array of configs, each applying to one or more
+kinds of names via `selector`:
+
+- `variable`, `function`, `parameter`, grouped as `variableLike`
+- `classProperty`, `objectLiteralProperty`, `typeProperty`, grouped as
+  `property`
+- `classMethod`, `objectLiteralMethod`, `typeMethod`, grouped as `method`
+- `accessor`, `enumMember`, grouped with properties and methods as
+  `memberLike`
+- `class`, `interface`, `typeAlias`, `enum`, `typeParameter`, grouped as
+  `typeLike`
+- `default`, which covers all of the above
+
+Each name is checked against a single config: the one with the most specific
+selector. Among equally specific ones, a config whose `filter` matches the name
+takes precedence, and otherwise the first one listed. A config can contain the
+following options, checked in this order:
+
+- `leadingUnderscore` / `trailingUnderscore`: `"forbid"`, `"require"` or
+  `"allow"` a single underscore, which is trimmed before the format is checked
+- `prefix` / `suffix`: an array of strings, one of which the name has to start
+  or end with, also trimmed before the format is checked
+- `custom`: `{ "regex": "...", "match": true }` requires the name to match (or
+  with `false`, not to match) the regex
+- `format`: an array of formats, one of which the name has to satisfy:
+  `camelCase`, `strictCamelCase`, `PascalCase`, `StrictPascalCase`,
+  `snake_case` and `UPPER_CASE`. The strict variants disallow consecutive
+  capitals, e.g. `HTMLElement`. `null` skips the check.
+
+`filter` uses the same format as `custom` and restricts a config to names
+matching it, e.g. to exempt some names from the convention.
+
+Only names that are written as identifiers are checked, so quoted property
+names are never reported. By default variables have to be camelCase or
+UPPER_CASE, types PascalCase and everything else camelCase.
+
+### Invalid:
+```typescript
+// with the default options
+const my_variable = 1;
+function DoSomething() {}
+class my_class {}
+
+// with
+// [{ "selector": "interface", "format": ["PascalCase"],
+//    "custom": { "regex": "^I[A-Z]", "match": false } },
+//  { "selector": "enumMember", "format": ["UPPER_CASE"] }]
+interface IFoo {}
+enum Direction { up, down }
+```
+
+### Valid:
+```typescript
+// with the default options
+const myVariable = 1;
+const MAX_COUNT = 10;
+function doSomething() {}
+class MyClass {}
+const headers = { "Content-Type": "text/plain" };
+
+// with
+// [{ "selector": "interface", "format": ["PascalCase"],
+//    "custom": { "regex": "^I[A-Z]", "match": false } },
+//  { "selector": "enumMember", "format": ["UPPER_CASE"] }]
+interface Foo {}
+enum Direction { UP, DOWN }
+```
+"#
+  }
+}
+
+/// Collects the identifiers bound by a pattern, e.g. `a`, `b` and `c` in
+/// `{ a, b: [b], ...c }`.
+fn collect_bindings<'a>(
+  pat: &AstView::Pat<'a>,
+  out: &mut Vec<&'a AstView::Ident<'a>>,
+) {
+  match pat {
+    AstView::Pat::Ident(binding_ident) => out.push(binding_ident.id),
+    AstView::Pat::Array(array_pat) => {
+      for elem in array_pat.elems.iter().flatten() {
+        collect_bindings(elem, out);
+      }
+    }
+    AstView::Pat::Object(object_pat) => {
+      for prop in &object_pat.props {
+        match prop {
+          AstView::ObjectPatProp::KeyValue(key_value) => {
+            collect_bindings(&key_value.value, out);
+          }
+          AstView::ObjectPatProp::Assign(assign) => out.push(assign.key),
+          AstView::ObjectPatProp::Rest(rest) => {
+            collect_bindings(&rest.arg, out);
+          }
+        }
+      }
+    }
+    AstView::Pat::Rest(rest_pat) => collect_bindings(&rest_pat.arg, out),
+    AstView::Pat::Assign(assign_pat) => collect_bindings(&assign_pat.left, out),
+    AstView::Pat::Invalid(_) | AstView::Pat::Expr(_) => {}
+  }
+}
+
+fn ident_prop_name<'a>(
+  prop_name: &AstView::PropName<'a>,
+) -> Option<&'a AstView::Ident<'a>> {
+  match prop_name {
+    AstView::PropName::Ident(ident) => Some(ident),
+    _ => None,
+  }
+}
+
+struct NamingConventionHandler<'c> {
+  configs: &'c [NamingConfig],
+}
+
+impl<'c> NamingConventionHandler<'c> {
+  fn check(&self, kind: Selector, span: Span, name: &str, ctx: &mut Context) {
+    let config = self
+      .configs
+      .iter()
+      .enumerate()
+      .filter(|(_, config)| {
+        config
+          .filter
+          .as_ref()
+          .map_or(true, |filter| filter.test(name))
+      })
+      .filter_map(|(i, config)| {
+        let specificity = config.specificity_for(kind)?;
+        Some(((specificity, config.filter.is_some()), i, config))
+      })
+      // The most specific config wins, then one with a matching filter, and
+      // then the first one listed.
+      .max_by(|(s1, i1, _), (s2, i2, _)| s1.cmp(s2).then(i2.cmp(i1)));
+
+    if let Some((_, _, config)) = config {
+      if let Some(message) = config.check(kind, name) {
+        ctx.add_diagnostic_with_hint(span, CODE, message, HINT);
+      }
+    }
+  }
+
+  fn check_ident(
+    &self,
+    kind: Selector,
+    ident: &AstView::Ident,
+    ctx: &mut Context,
+  ) {
+    self.check(kind, ident.span(), ident.sym(), ctx);
+  }
+
+  fn check_pat(&self, kind: Selector, pat: &AstView::Pat, ctx: &mut Context) {
+    let mut bindings = Vec::new();
+    collect_bindings(pat, &mut bindings);
+    for ident in bindings {
+      self.check_ident(kind, ident, ctx);
+    }
+  }
+
+  fn check_prop_name(
+    &self,
+    kind: Selector,
+    prop_name: &AstView::PropName,
+    ctx: &mut Context,
+  ) {
+    if let Some(ident) = ident_prop_name(prop_name) {
+      self.check_ident(kind, ident, ctx);
+    }
+  }
+}
+
+impl<'c> Handler for NamingConventionHandler<'c> {
+  fn var_declarator(
+    &mut self,
+    var_declarator: &AstView::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    self.check_pat(Selector::Variable, &var_declarator.name, ctx);
+  }
+
+  fn fn_decl(&mut self, fn_decl: &AstView::FnDecl, ctx: &mut Context) {
+    self.check_ident(Selector::Function, fn_decl.ident, ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &AstView::FnExpr, ctx: &mut Context) {
+    if let Some(ident) = fn_expr.ident {
+      self.check_ident(Selector::Function, ident, ctx);
+    }
+  }
+
+  fn param(&mut self, param: &AstView::Param, ctx: &mut Context) {
+    self.check_pat(Selector::Parameter, &param.pat, ctx);
+  }
+
+  fn arrow_expr(&mut self, arrow_expr: &AstView::ArrowExpr, ctx: &mut Context) {
+    for param in &arrow_expr.params {
+      self.check_pat(Selector::Parameter, param, ctx);
+    }
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &AstView::SetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Accessor, &setter_prop.key, ctx);
+    self.check_pat(Selector::Parameter, &setter_prop.param, ctx);
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &AstView::GetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Accessor, &getter_prop.key, ctx);
+  }
+
+  fn key_value_prop(
+    &mut self,
+    key_value_prop: &AstView::KeyValueProp,
+    ctx: &mut Context,
+  ) {
+    let kind = match key_value_prop.value {
+      AstView::Expr::Fn(_) | AstView::Expr::Arrow(_) => {
+        Selector::ObjectLiteralMethod
+      }
+      _ => Selector::ObjectLiteralProperty,
+    };
+    self.check_prop_name(kind, &key_value_prop.key, ctx);
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &AstView::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::ObjectLiteralMethod, &method_prop.key, ctx);
+  }
+
+  fn class_decl(&mut self, class_decl: &AstView::ClassDecl, ctx: &mut Context) {
+    self.check_ident(Selector::Class, class_decl.ident, ctx);
+  }
+
+  fn class_expr(&mut self, class_expr: &AstView::ClassExpr, ctx: &mut Context) {
+    if let Some(ident) = class_expr.ident {
+      self.check_ident(Selector::Class, ident, ctx);
+    }
+  }
+
+  fn class_prop(&mut self, class_prop: &AstView::ClassProp, ctx: &mut Context) {
+    if let AstView::Expr::Ident(ident) = class_prop.key {
+      if !class_prop.computed() {
+        self.check_ident(Selector::ClassProperty, ident, ctx);
+      }
+    }
+  }
+
+  fn private_prop(
+    &mut self,
+    private_prop: &AstView::PrivateProp,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(Selector::ClassProperty, private_prop.key.id, ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &AstView::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    let kind = match class_method.method_kind() {
+      MethodKind::Method => Selector::ClassMethod,
+      MethodKind::Getter | MethodKind::Setter => Selector::Accessor,
+    };
+    self.check_prop_name(kind, &class_method.key, ctx);
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &AstView::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    let kind = match private_method.method_kind() {
+      MethodKind::Method => Selector::ClassMethod,
+      MethodKind::Getter | MethodKind::Setter => Selector::Accessor,
+    };
+    self.check_ident(kind, private_method.key.id, ctx);
+  }
+
+  fn ts_property_signature(
+    &mut self,
+    ts_property_signature: &AstView::TsPropertySignature,
+    ctx: &mut Context,
+  ) {
+    if let AstView::Expr::Ident(ident) = ts_property_signature.key {
+      if !ts_property_signature.computed() {
+        self.check_ident(Selector::TypeProperty, ident, ctx);
+      }
+    }
+  }
+
+  fn ts_method_signature(
+    &mut self,
+    ts_method_signature: &AstView::TsMethodSignature,
+    ctx: &mut Context,
+  ) {
+    if let AstView::Expr::Ident(ident) = ts_method_signature.key {
+      if !ts_method_signature.computed() {
+        self.check_ident(Selector::TypeMethod, ident, ctx);
+      }
+    }
+  }
+
+  fn ts_enum_member(
+    &mut self,
+    ts_enum_member: &AstView::TsEnumMember,
+    ctx: &mut Context,
+  ) {
+    if let AstView::TsEnumMemberId::Ident(ident) = ts_enum_member.id {
+      self.check_ident(Selector::EnumMember, ident, ctx);
+    }
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    ts_interface_decl: &AstView::TsInterfaceDecl,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(Selector::Interface, ts_interface_decl.id, ctx);
+  }
+
+  fn ts_type_alias_decl(
+    &mut self,
+    ts_type_alias_decl: &AstView::TsTypeAliasDecl,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(Selector::TypeAlias, ts_type_alias_decl.id, ctx);
+  }
+
+  fn ts_enum_decl(
+    &mut self,
+    ts_enum_decl: &AstView::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(Selector::Enum, ts_enum_decl.id, ctx);
+  }
+
+  fn ts_type_param(
+    &mut self,
+    ts_type_param: &AstView::TsTypeParam,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(Selector::TypeParameter, ts_type_param.name, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn naming_convention_valid() {
+    assert_lint_ok! {
+      NamingConvention,
+      "const fooBar = 1;",
+      "const FOO_BAR = 1;",
+      "const _fooBar = 1;",
+      "const fooBar_ = 1;",
+      "let { fooBar, baz: [quxQuux] } = obj;",
+      "const { foo_bar: fooBar } = obj;",
+      "function fooBar(bazQux, ...rest) {}",
+      "const f = (fooBar) => fooBar;",
+      "const f = function fooBar() {};",
+      "class FooBar { fooBar = 1; #bazQux = 2; fooBar() {} get bazQux() { return 1; } }",
+      "const obj = { fooBar: 1, 'foo_bar': 2, [foo_bar]: 3, bazQux() {} };",
+      "foo_bar();",
+      "foo_bar.baz_qux = 1;",
+      "interface FooBar { fooBar: string; bazQux(): void; 'baz-qux': number }",
+      "type FooBar<TFoo> = { fooBar: TFoo };",
+      "enum FooBar { fooBar, 'foo_bar' }",
+      "import { foo_bar } from './foo.ts';",
+    };
+
+    assert_lint_ok! {
+      NamingConvention,
+      options: [
+        { "selector": "default", "format": ["camelCase"] },
+        { "selector": "enumMember", "format": ["UPPER_CASE"] },
+        {
+          "selector": "interface",
+          "format": ["PascalCase"],
+          "custom": { "regex": "^I[A-Z]", "match": false }
+        },
+        { "selector": ["class", "typeAlias"], "format": ["PascalCase"] }
+      ],
+      "enum foo { FOO_BAR, BAZ }",
+      "interface Foo {}",
+      "interface Ifoo {}",
+      "class Foo {}",
+      "type Foo = string;",
+    };
+
+    assert_lint_ok! {
+      NamingConvention,
+      options: [
+        { "selector": "variable", "format": ["camelCase"] },
+        { "selector": "variable", "format": null, "filter": { "regex": "^__", "match": true } },
+        { "selector": "parameter", "format": ["camelCase"], "leadingUnderscore": "allow" },
+        { "selector": "classProperty", "format": ["camelCase"], "leadingUnderscore": "require" },
+        { "selector": "typeParameter", "format": ["PascalCase"], "prefix": ["T"] },
+        { "selector": "function", "format": ["camelCase"], "suffix": ["Async"] }
+      ],
+      "const __dirname__ = 1;",
+      "function fooAsync(_bar, baz) {}",
+      "class A { _foo = 1; }",
+      "type A<TFoo> = TFoo;",
+    };
+
+    assert_lint_ok! {
+      NamingConvention,
+      options: [
+        { "selector": "variable", "format": ["strictCamelCase"] },
+        { "selector": "class", "format": ["StrictPascalCase"] },
+        { "selector": "function", "format": ["snake_case"] }
+      ],
+      "const fooBarBaz = 1;",
+      "class HtmlElement {}",
+      "function foo_bar() {}",
+    };
+  }
+
+  #[test]
+  fn naming_convention_invalid() {
+    assert_lint_err! {
+      NamingConvention,
+      "const foo_bar = 1;": [
+        {
+          col: 6,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Variable,
+            "foo_bar",
+            "camelCase, UPPER_CASE"
+          ),
+          hint: HINT,
+        }
+      ],
+      "const { a: [foo_bar] } = obj;": [
+        {
+          col: 12,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Variable,
+            "foo_bar",
+            "camelCase, UPPER_CASE"
+          ),
+          hint: HINT,
+        }
+      ],
+      "function FooBar(baz_qux) {}": [
+        {
+          col: 9,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Function,
+            "FooBar",
+            "camelCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 16,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Parameter,
+            "baz_qux",
+            "camelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "class fooBar { foo_bar = 1; BazQux() {} }": [
+        {
+          col: 6,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Class,
+            "fooBar",
+            "PascalCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 15,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::ClassProperty,
+            "foo_bar",
+            "camelCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 28,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::ClassMethod,
+            "BazQux",
+            "camelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "const obj = { foo_bar: 1, baz_qux: () => {} };": [
+        {
+          col: 14,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::ObjectLiteralProperty,
+            "foo_bar",
+            "camelCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 26,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::ObjectLiteralMethod,
+            "baz_qux",
+            "camelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "interface foo { Bar: string }": [
+        {
+          col: 10,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Interface,
+            "foo",
+            "PascalCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 16,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::TypeProperty,
+            "Bar",
+            "camelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "type foo<t> = t;": [
+        {
+          col: 5,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::TypeAlias,
+            "foo",
+            "PascalCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 9,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::TypeParameter,
+            "t",
+            "PascalCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "enum foo { Bar }": [
+        {
+          col: 5,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Enum,
+            "foo",
+            "PascalCase"
+          ),
+          hint: HINT,
+        },
+        {
+          col: 11,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::EnumMember,
+            "Bar",
+            "camelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention,
+      options: [
+        { "selector": "default", "format": ["camelCase"] },
+        { "selector": "enumMember", "format": ["UPPER_CASE"] },
+        {
+          "selector": "interface",
+          "format": ["PascalCase"],
+          "custom": { "regex": "^I[A-Z]", "match": false }
+        },
+        { "selector": ["class", "typeAlias"], "format": ["PascalCase"] }
+      ],
+      "enum foo { fooBar }": [
+        {
+          col: 11,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::EnumMember,
+            "fooBar",
+            "UPPER_CASE"
+          ),
+          hint: HINT,
+        }
+      ],
+      "interface IFoo {}": [
+        {
+          col: 10,
+          message: variant!(
+            NamingConventionMessage,
+            MatchingCustom,
+            Selector::Interface,
+            "IFoo",
+            "^I[A-Z]"
+          ),
+          hint: HINT,
+        }
+      ],
+      "type foo = string;": [
+        {
+          col: 5,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::TypeAlias,
+            "foo",
+            "PascalCase"
+          ),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention,
+      options: [
+        { "selector": "variable", "format": ["camelCase"], "leadingUnderscore": "forbid" },
+        { "selector": "variable", "format": null, "filter": { "regex": "^__", "match": true } },
+        { "selector": "classProperty", "format": ["camelCase"], "leadingUnderscore": "require" },
+        { "selector": "typeParameter", "format": ["PascalCase"], "prefix": ["T"] },
+        { "selector": "function", "format": ["camelCase"], "suffix": ["Async"] },
+        {
+          "selector": "parameter",
+          "format": ["camelCase"],
+          "custom": { "regex": "^[a-z]{2,}", "match": true }
+        }
+      ],
+      "const _foo = 1;": [
+        {
+          col: 6,
+          message: variant!(
+            NamingConventionMessage,
+            UnexpectedUnderscore,
+            Selector::Variable,
+            "_foo",
+            "leading"
+          ),
+          hint: HINT,
+        }
+      ],
+      "class A { foo = 1; }": [
+        {
+          col: 10,
+          message: variant!(
+            NamingConventionMessage,
+            MissingUnderscore,
+            Selector::ClassProperty,
+            "foo",
+            "leading"
+          ),
+          hint: HINT,
+        }
+      ],
+      "type A<Foo> = Foo;": [
+        {
+          col: 7,
+          message: variant!(
+            NamingConventionMessage,
+            MissingPrefix,
+            Selector::TypeParameter,
+            "Foo",
+            "T"
+          ),
+          hint: HINT,
+        }
+      ],
+      "type A<Tfoo> = Tfoo;": [
+        {
+          col: 7,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::TypeParameter,
+            "Tfoo",
+            "PascalCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "function foo() {}": [
+        {
+          col: 9,
+          message: variant!(
+            NamingConventionMessage,
+            MissingSuffix,
+            Selector::Function,
+            "foo",
+            "Async"
+          ),
+          hint: HINT,
+        }
+      ],
+      "function fooAsync(a) {}": [
+        {
+          col: 18,
+          message: variant!(
+            NamingConventionMessage,
+            NotMatchingCustom,
+            Selector::Parameter,
+            "a",
+            "^[a-z]{2,}"
+          ),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention,
+      options: [
+        { "selector": "variable", "format": ["strictCamelCase"] },
+        { "selector": "class", "format": ["StrictPascalCase"] },
+        { "selector": "function", "format": ["snake_case"] }
+      ],
+      "const fooBAR = 1;": [
+        {
+          col: 6,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Variable,
+            "fooBAR",
+            "strictCamelCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "class HTMLElement {}": [
+        {
+          col: 6,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Class,
+            "HTMLElement",
+            "StrictPascalCase"
+          ),
+          hint: HINT,
+        }
+      ],
+      "function foo__bar() {}": [
+        {
+          col: 9,
+          message: variant!(
+            NamingConventionMessage,
+            Format,
+            Selector::Function,
+            "foo__bar",
+            "snake_case"
+          ),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn naming_convention_invalid_options() {
+    let mut rule = NamingConvention::default();
+    assert!(rule
+      .set_options(&serde_json::json!([{ "selector": "unknown" }]))
+      .is_err());
+    assert!(rule
+      .set_options(&serde_json::json!([{
+        "selector": "variable",
+        "custom": { "regex": "(", "match": true }
+      }]))
+      .is_err());
+  }
+}
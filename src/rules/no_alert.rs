@@ -0,0 +1,204 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoAlert;
+
+const CODE: &str = "no-alert";
+const HINT: &str =
+  "Write to the console or use a dedicated UI component instead; these functions block execution and are unavailable on servers";
+
+#[derive(Display)]
+enum NoAlertMessage {
+  #[display(fmt = "Unexpected use of `{}`", _0)]
+  Unexpected(String),
+}
+
+const DIALOG_FNS: [&str; 3] = ["alert", "confirm", "prompt"];
+const GLOBAL_OBJS: [&str; 3] = ["globalThis", "window", "self"];
+
+impl LintRule for NoAlert {
+  fn new() -> Box<Self> {
+    Box::new(NoAlert)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoAlertHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of `alert`, `confirm` and `prompt`
+
+These functions show a blocking dialog, which is a poor user experience and
+is often left over from debugging. They aren't available at all in server
+environments such as Deno Deploy.
+
+Only the global functions are reported, including when accessed through
+`globalThis`, `window` or `self`.
+
+### Invalid:
+```typescript
+alert("here!");
+const proceed = confirm("Are you sure?");
+const name = prompt("What's your name?", "Deno");
+globalThis.alert("here!");
+```
+
+### Valid:
+```typescript
+console.log("here!");
+customAlert("here!");
+
+function foo(alert) {
+  alert("here!"); // this is a parameter
+}
+```
+"#
+  }
+}
+
+/// Returns the name of the dialog function being called, if any.
+fn dialog_fn_name(callee: &AstView::Expr, ctx: &Context) -> Option<String> {
+  let ident = match callee {
+    AstView::Expr::Ident(ident) if is_global(ident, ctx.scope()) => ident,
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      let is_global_obj = |obj: &AstView::Ident| {
+        GLOBAL_OBJS.contains(&&**obj.sym()) && is_global(obj, ctx.scope())
+      };
+      match (&member_expr.obj, &member_expr.prop) {
+        (
+          AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+          AstView::Expr::Ident(prop),
+        ) if is_global_obj(obj) => prop,
+        _ => return None,
+      }
+    }
+    _ => return None,
+  };
+
+  let name = ident.sym().to_string();
+  if DIALOG_FNS.contains(&name.as_str()) {
+    Some(name)
+  } else {
+    None
+  }
+}
+
+struct NoAlertHandler;
+
+impl Handler for NoAlertHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if let AstView::ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Some(name) = dialog_fn_name(callee, ctx) {
+        ctx.add_diagnostic_with_hint(
+          call_expr.span(),
+          CODE,
+          NoAlertMessage::Unexpected(name),
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_alert_valid() {
+    assert_lint_ok! {
+      NoAlert,
+      "console.log('foo');",
+      "foo.alert('foo');",
+      "foo.window.alert('foo');",
+      "globalThis['alert']('foo');",
+      "customAlert('foo');",
+      "const alert = () => {}; alert('foo');",
+      "function f(confirm) { confirm('foo'); }",
+      "function f() { const prompt = foo; prompt('foo'); }",
+      "function f(window) { window.alert('foo'); }",
+      "import { alert } from './alert.ts'; alert('foo');",
+      "alert;",
+    };
+  }
+
+  #[test]
+  fn no_alert_invalid() {
+    assert_lint_err! {
+      NoAlert,
+      "alert('foo');": [
+        {
+          col: 0,
+          message: variant!(NoAlertMessage, Unexpected, "alert"),
+          hint: HINT,
+        }
+      ],
+      "const a = confirm('foo');": [
+        {
+          col: 10,
+          message: variant!(NoAlertMessage, Unexpected, "confirm"),
+          hint: HINT,
+        }
+      ],
+      "const a = prompt('foo', 'bar');": [
+        {
+          col: 10,
+          message: variant!(NoAlertMessage, Unexpected, "prompt"),
+          hint: HINT,
+        }
+      ],
+      "globalThis.alert('foo');": [
+        {
+          col: 0,
+          message: variant!(NoAlertMessage, Unexpected, "alert"),
+          hint: HINT,
+        }
+      ],
+      "window.confirm('foo');": [
+        {
+          col: 0,
+          message: variant!(NoAlertMessage, Unexpected, "confirm"),
+          hint: HINT,
+        }
+      ],
+      "self.prompt('foo');": [
+        {
+          col: 0,
+          message: variant!(NoAlertMessage, Unexpected, "prompt"),
+          hint: HINT,
+        }
+      ],
+      "function f() { alert('foo'); }": [
+        {
+          col: 15,
+          message: variant!(NoAlertMessage, Unexpected, "alert"),
+          hint: HINT,
+        }
+      ],
+      "function f() { const alert = foo; } alert('foo');": [
+        {
+          col: 36,
+          message: variant!(NoAlertMessage, Unexpected, "alert"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
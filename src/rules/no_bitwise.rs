@@ -0,0 +1,355 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, AssignOp, BinaryOp, UnaryOp};
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+
+#[derive(Default)]
+pub struct NoBitwise {
+  options: NoBitwiseOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoBitwiseOptions {
+  /// Operators that are allowed, e.g. `["|", "<<="]`
+  allow: Vec<String>,
+  /// Allows `| 0`, which is commonly used to truncate a number to an int32.
+  int32_hint: bool,
+}
+
+const CODE: &str = "no-bitwise";
+const HINT: &str = "Bitwise operators are often typos of logical ones, e.g. `&` for `&&`. Use a logical operator if that was intended";
+
+#[derive(Display)]
+enum NoBitwiseMessage {
+  #[display(fmt = "Unexpected use of `{}`", _0)]
+  Unexpected(String),
+}
+
+impl LintRule for NoBitwise {
+  fn new() -> Box<Self> {
+    Box::new(NoBitwise::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoBitwiseHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows bitwise operators
+
+Bitwise operators are rarely needed in application code, and when they appear
+they are often typos of logical operators, e.g. `&` instead of `&&`.
+
+Specific operators can be permitted with the `allow` option, e.g.
+`{ "allow": ["|", "<<"] }`. Setting `int32Hint` to `true` permits `| 0`, a
+common idiom to truncate a number to a 32-bit integer in performance-sensitive
+code.
+
+### Invalid:
+```typescript
+const a = b | c;
+const d = e & f;
+const g = ~h;
+i <<= 1;
+```
+
+### Valid:
+```typescript
+const a = b || c;
+const d = e && f;
+const g = !h;
+
+// allowed with `int32Hint`
+const i = j | 0;
+```
+"#
+  }
+}
+
+struct NoBitwiseHandler<'a> {
+  options: &'a NoBitwiseOptions,
+}
+
+impl<'a> NoBitwiseHandler<'a> {
+  fn check(&self, span: Span, op: &str, ctx: &mut Context) {
+    if self.options.allow.iter().any(|allowed| allowed == op) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoBitwiseMessage::Unexpected(op.to_string()),
+      HINT,
+    );
+  }
+}
+
+impl<'a> Handler for NoBitwiseHandler<'a> {
+  fn bin_expr(&mut self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    use BinaryOp::*;
+    let op = bin_expr.op();
+    if !matches!(
+      op,
+      BitOr | BitXor | BitAnd | LShift | RShift | ZeroFillRShift
+    ) {
+      return;
+    }
+
+    if self.options.int32_hint
+      && op == BitOr
+      && matches!(
+        bin_expr.right,
+        AstView::Expr::Lit(AstView::Lit::Num(num)) if num.value() == 0.0
+      )
+    {
+      return;
+    }
+
+    self.check(bin_expr.span(), &op.to_string(), ctx);
+  }
+
+  fn assign_expr(
+    &mut self,
+    assign_expr: &AstView::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    use AssignOp::*;
+    let op = assign_expr.op();
+    if matches!(
+      op,
+      LShiftAssign
+        | RShiftAssign
+        | ZeroFillRShiftAssign
+        | BitOrAssign
+        | BitXorAssign
+        | BitAndAssign
+    ) {
+      self.check(assign_expr.span(), &op.to_string(), ctx);
+    }
+  }
+
+  fn unary_expr(&mut self, unary_expr: &AstView::UnaryExpr, ctx: &mut Context) {
+    let op = unary_expr.op();
+    if op == UnaryOp::Tilde {
+      self.check(unary_expr.span(), &op.to_string(), ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_bitwise_valid() {
+    assert_lint_ok! {
+      NoBitwise,
+      "a + b;",
+      "a || b;",
+      "a && b;",
+      "!a;",
+      "a += b;",
+      "a ||= b;",
+      "a &&= b;",
+    };
+
+    assert_lint_ok! {
+      NoBitwise,
+      options: { "allow": ["|", "~", "<<="] },
+      "a | b;",
+      "~a;",
+      "a <<= b;",
+    };
+
+    assert_lint_ok! {
+      NoBitwise,
+      options: { "int32Hint": true },
+      "a | 0;",
+      "const b = (c + d) | 0;",
+    };
+  }
+
+  #[test]
+  fn no_bitwise_invalid() {
+    assert_lint_err! {
+      NoBitwise,
+      "a ^ b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "^"),
+          hint: HINT,
+        }
+      ],
+      "a | b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "|"),
+          hint: HINT,
+        }
+      ],
+      "a & b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "&"),
+          hint: HINT,
+        }
+      ],
+      "a << b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "<<"),
+          hint: HINT,
+        }
+      ],
+      "a >> b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, ">>"),
+          hint: HINT,
+        }
+      ],
+      "a >>> b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, ">>>"),
+          hint: HINT,
+        }
+      ],
+      "~a;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "~"),
+          hint: HINT,
+        }
+      ],
+      "a ^= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "^="),
+          hint: HINT,
+        }
+      ],
+      "a |= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "|="),
+          hint: HINT,
+        }
+      ],
+      "a &= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "&="),
+          hint: HINT,
+        }
+      ],
+      "a <<= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "<<="),
+          hint: HINT,
+        }
+      ],
+      "a >>= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, ">>="),
+          hint: HINT,
+        }
+      ],
+      "a >>>= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, ">>>="),
+          hint: HINT,
+        }
+      ],
+      "a | 0;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "|"),
+          hint: HINT,
+        }
+      ],
+      "const c = a & ~b;": [
+        {
+          col: 10,
+          message: variant!(NoBitwiseMessage, Unexpected, "&"),
+          hint: HINT,
+        },
+        {
+          col: 14,
+          message: variant!(NoBitwiseMessage, Unexpected, "~"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoBitwise,
+      options: { "allow": ["&"] },
+      "a | b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "|"),
+          hint: HINT,
+        }
+      ],
+      "a &= b;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "&="),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoBitwise,
+      options: { "int32Hint": true },
+      "a | 1;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "|"),
+          hint: HINT,
+        }
+      ],
+      "a & 0;": [
+        {
+          col: 0,
+          message: variant!(NoBitwiseMessage, Unexpected, "&"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,225 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::static_member_prop_name;
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_ecmascript::ast::{Expr, ExprOrSpread, ExprOrSuper, Lit};
+
+#[derive(Default)]
+pub struct NoBooleanLiteralForArguments {
+  options: NoBooleanLiteralForArgumentsOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoBooleanLiteralForArgumentsOptions {
+  /// Functions that may be called with boolean literals, e.g.
+  /// `["setEnabled", "Deno.exit"]`
+  allow: Vec<String>,
+}
+
+const CODE: &str = "no-boolean-literal-for-arguments";
+const MESSAGE: &str = "Boolean literal passed as an argument";
+const HINT: &str = "Use a self-documenting constant, e.g. `const recursive = true;`, or an options object like `{ recursive: true }` instead";
+
+impl LintRule for NoBooleanLiteralForArguments {
+  fn new() -> Box<Self> {
+    Box::new(NoBooleanLiteralForArguments::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoBooleanLiteralForArgumentsHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows passing boolean literals as function arguments
+
+At a call site like `copy(src, dest, true, false)` there's no telling what
+`true` and `false` mean without looking up the function. A named constant or
+an options object documents itself.
+
+Constructor calls like `new Foo(true)` are checked as well.
+
+Calls of APIs that can't be changed can be permitted with the `allow` option.
+Entries without a dot, like `"setEnabled"`, match functions, methods and classes
+of that name, while entries like `"Deno.exit"` only match that exact callee.
+
+### Invalid:
+```typescript
+copy(src, dest, true, false);
+element.toggle("hidden", true);
+new Worker(url, false);
+```
+
+### Valid:
+```typescript
+const overwrite = true;
+copy(src, dest, overwrite);
+copy(src, dest, { overwrite: true, recursive: false });
+
+// with { "allow": ["toggle"] }
+element.toggle("hidden", true);
+```
+"#
+  }
+}
+
+/// Returns the callee as a dotted path like `foo.bar.baz`, if it consists of
+/// identifiers and static member accesses only.
+fn callee_path(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::This(_) => Some("this".to_string()),
+    Expr::Member(member_expr) => {
+      let obj = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => callee_path(obj)?,
+        ExprOrSuper::Super(_) => "super".to_string(),
+      };
+      let prop = static_member_prop_name(member_expr)?;
+      Some(format!("{}.{}", obj, prop))
+    }
+    _ => None,
+  }
+}
+
+struct NoBooleanLiteralForArgumentsHandler<'a> {
+  options: &'a NoBooleanLiteralForArgumentsOptions,
+}
+
+impl<'a> NoBooleanLiteralForArgumentsHandler<'a> {
+  fn is_allowed(&self, callee: &Expr) -> bool {
+    let path = match callee_path(callee) {
+      Some(path) => path,
+      None => return false,
+    };
+    let name = path.rsplit('.').next().unwrap_or_default();
+    self.options.allow.iter().any(|allowed| {
+      if allowed.contains('.') {
+        *allowed == path
+      } else {
+        allowed == name
+      }
+    })
+  }
+
+  fn check_args(&self, args: &[ExprOrSpread], ctx: &mut Context) {
+    for arg in args.iter().filter(|arg| arg.spread.is_none()) {
+      if let Expr::Lit(Lit::Bool(boolean)) = &*arg.expr {
+        ctx.add_diagnostic_with_hint(boolean.span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+impl<'a> Handler for NoBooleanLiteralForArgumentsHandler<'a> {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let is_allowed = match &call_expr.inner.callee {
+      ExprOrSuper::Expr(callee) => self.is_allowed(callee),
+      ExprOrSuper::Super(_) => false,
+    };
+    if !is_allowed {
+      self.check_args(&call_expr.inner.args, ctx);
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &AstView::NewExpr, ctx: &mut Context) {
+    if let Some(args) = &new_expr.inner.args {
+      if !self.is_allowed(&new_expr.inner.callee) {
+        self.check_args(args, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_boolean_literal_for_arguments_valid() {
+    assert_lint_ok! {
+      NoBooleanLiteralForArguments,
+      "foo();",
+      "foo(a, b);",
+      "const recursive = true; foo(recursive);",
+      "foo({ recursive: true });",
+      "foo([true, false]);",
+      "foo(!a);",
+      "foo('true');",
+      "const a = true;",
+      "if (foo(a) === true) {}",
+      "new Foo;",
+      "new Foo(a);",
+    };
+
+    assert_lint_ok! {
+      NoBooleanLiteralForArguments,
+      options: { "allow": ["toggle", "Deno.exit", "this.setEnabled", "Worker"] },
+      "element.toggle('hidden', true);",
+      "toggle(true);",
+      "a.b.toggle(false);",
+      "Deno.exit(true);",
+      "this.setEnabled(false);",
+      "new Worker(url, true);",
+    };
+  }
+
+  #[test]
+  fn no_boolean_literal_for_arguments_invalid() {
+    assert_lint_err! {
+      NoBooleanLiteralForArguments,
+      "foo(true);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "copy(src, dest, true, false);": [
+        { col: 16, message: MESSAGE, hint: HINT },
+        { col: 22, message: MESSAGE, hint: HINT }
+      ],
+      "obj.method(a, false);": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "foo?.(true);": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "const a = foo(bar(false));": [{ col: 18, message: MESSAGE, hint: HINT }],
+      "foo(true, bar(false));": [
+        { col: 4, message: MESSAGE, hint: HINT },
+        { col: 14, message: MESSAGE, hint: HINT }
+      ],
+      "class A extends B { constructor() { super(true); } }": [
+        { col: 42, message: MESSAGE, hint: HINT }
+      ],
+      "new Foo(true);": [{ col: 8, message: MESSAGE, hint: HINT }],
+      "new foo.Bar(a, false);": [{ col: 15, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      NoBooleanLiteralForArguments,
+      options: { "allow": ["Deno.exit", "toggle"] },
+      "exit(true);": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "foo.exit(true);": [{ col: 9, message: MESSAGE, hint: HINT }],
+      "toggler(true);": [{ col: 8, message: MESSAGE, hint: HINT }],
+      "foo[toggle](true);": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "new Toggle(true);": [{ col: 11, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
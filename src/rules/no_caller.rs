@@ -0,0 +1,146 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::static_member_prop_name;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoCaller;
+
+const CODE: &str = "no-caller";
+const HINT: &str = "Give the function a name and refer to it directly instead; accessing this property throws a `TypeError` in strict mode, which includes all ES modules";
+
+#[derive(Display)]
+enum NoCallerMessage {
+  #[display(fmt = "Do not use `arguments.{}`", _0)]
+  Unexpected(String),
+}
+
+impl LintRule for NoCaller {
+  fn new() -> Box<Self> {
+    Box::new(NoCaller)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoCallerHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of `arguments.callee` and `arguments.caller`
+
+Both properties are forbidden in strict mode, and since ES modules are always
+strict, accessing them throws a `TypeError` at runtime in Deno. A function
+that needs to refer to itself can simply be given a name.
+
+### Invalid:
+```typescript
+[1, 2, 3].map(function (n) {
+  return n <= 1 ? 1 : n * arguments.callee(n - 1);
+});
+```
+
+### Valid:
+```typescript
+[1, 2, 3].map(function factorial(n) {
+  return n <= 1 ? 1 : n * factorial(n - 1);
+});
+```
+"#
+  }
+}
+
+struct NoCallerHandler;
+
+impl Handler for NoCallerHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &AstView::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    let is_arguments = matches!(
+      &member_expr.obj,
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj))
+        if *obj.sym() == *"arguments"
+    );
+    if !is_arguments {
+      return;
+    }
+
+    if let Some(prop) = static_member_prop_name(member_expr.inner) {
+      if prop == "callee" || prop == "caller" {
+        ctx.add_diagnostic_with_hint(
+          member_expr.span(),
+          CODE,
+          NoCallerMessage::Unexpected(prop),
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_caller_valid() {
+    assert_lint_ok! {
+      NoCaller,
+      "function foo() { return arguments.length; }",
+      "function foo() { return arguments[0]; }",
+      "function foo() { return arguments[callee]; }",
+      "foo.callee;",
+      "foo.arguments.caller;",
+      "const callee = arguments;",
+    };
+  }
+
+  #[test]
+  fn no_caller_invalid() {
+    assert_lint_err! {
+      NoCaller,
+      "function foo() { return arguments.callee; }": [
+        {
+          col: 24,
+          message: variant!(NoCallerMessage, Unexpected, "callee"),
+          hint: HINT,
+        }
+      ],
+      "function foo() { return arguments.caller; }": [
+        {
+          col: 24,
+          message: variant!(NoCallerMessage, Unexpected, "caller"),
+          hint: HINT,
+        }
+      ],
+      "function foo() { arguments['callee'](); }": [
+        {
+          col: 17,
+          message: variant!(NoCallerMessage, Unexpected, "callee"),
+          hint: HINT,
+        }
+      ],
+      "[1].map(function (n) { return n * arguments.callee(n - 1); });": [
+        {
+          col: 34,
+          message: variant!(NoCallerMessage, Unexpected, "callee"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
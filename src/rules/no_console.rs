@@ -0,0 +1,219 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{is_global, skip_wrappers, static_member_prop_name};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoConsole {
+  options: NoConsoleOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoConsoleOptions {
+  /// Methods of `console` that may be called, e.g. `["error", "warn"]`
+  allow: Vec<String>,
+}
+
+const CODE: &str = "no-console";
+const HINT: &str =
+  "Remove the call, or report through a logger or the return value instead";
+
+#[derive(Display)]
+enum NoConsoleMessage {
+  #[display(fmt = "Unexpected call of `console.{}()`", _0)]
+  Unexpected(String),
+}
+
+impl LintRule for NoConsole {
+  fn new() -> Box<Self> {
+    Box::new(NoConsole::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoConsoleHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calls of `console` methods
+
+Logging left over from debugging clutters the output of whoever uses the code,
+which matters especially for published modules. Methods that are meant to stay,
+like `console.error`, can be permitted with the `allow` option, e.g.
+`{ "allow": ["error", "warn"] }`.
+
+A locally defined `console` isn't the global one and isn't reported.
+
+### Invalid:
+```typescript
+console.log("here");
+console.debug(value);
+```
+
+### Valid:
+```typescript
+logger.info("started");
+
+// with { "allow": ["error", "warn"] }
+console.error("failed");
+console.warn("deprecated");
+```
+"#
+  }
+}
+
+struct NoConsoleHandler<'a> {
+  options: &'a NoConsoleOptions,
+}
+
+impl<'a> Handler for NoConsoleHandler<'a> {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let callee = match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(callee) => skip_wrappers(callee),
+      AstView::ExprOrSuper::Super(_) => return,
+    };
+    let member_expr = match callee {
+      AstView::Expr::Member(member_expr) => member_expr,
+      _ => return,
+    };
+    let is_global_console = matches!(
+      &member_expr.obj,
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj))
+        if *obj.sym() == *"console"
+          && is_global(obj, ctx.scope())
+    );
+    if !is_global_console {
+      return;
+    }
+    let method = match static_member_prop_name(member_expr.inner) {
+      Some(method) => method,
+      None => return,
+    };
+    if self.options.allow.contains(&method) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      call_expr.span(),
+      CODE,
+      NoConsoleMessage::Unexpected(method),
+      HINT,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_console_valid() {
+    assert_lint_ok! {
+      NoConsole,
+      "logger.log('foo');",
+      "foo.console.log('foo');",
+      "const console = { log() {} }; console.log('foo');",
+      "function f(console) { console.log('foo'); }",
+      "import { console } from './logger.ts'; console.log('foo');",
+      "console[method]('foo');",
+      "const log = console;",
+    };
+
+    assert_lint_ok! {
+      NoConsole,
+      options: { "allow": ["error", "warn"] },
+      "console.error('foo');",
+      "console.warn('foo');",
+      "console['error']('foo');",
+    };
+  }
+
+  #[test]
+  fn no_console_invalid() {
+    assert_lint_err! {
+      NoConsole,
+      "console.log('foo');": [
+        {
+          col: 0,
+          message: variant!(NoConsoleMessage, Unexpected, "log"),
+          hint: HINT,
+        }
+      ],
+      "console.error('foo');": [
+        {
+          col: 0,
+          message: variant!(NoConsoleMessage, Unexpected, "error"),
+          hint: HINT,
+        }
+      ],
+      "console['debug'](value);": [
+        {
+          col: 0,
+          message: variant!(NoConsoleMessage, Unexpected, "debug"),
+          hint: HINT,
+        }
+      ],
+      "console.log?.('foo');": [
+        {
+          col: 0,
+          message: variant!(NoConsoleMessage, Unexpected, "log"),
+          hint: HINT,
+        }
+      ],
+      "function f() { if (a) { console.info(a); } }": [
+        {
+          col: 24,
+          message: variant!(NoConsoleMessage, Unexpected, "info"),
+          hint: HINT,
+        }
+      ],
+      "function f(console) {} console.log('foo');": [
+        {
+          col: 23,
+          message: variant!(NoConsoleMessage, Unexpected, "log"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConsole,
+      options: { "allow": ["error", "warn"] },
+      "console.log('foo');": [
+        {
+          col: 0,
+          message: variant!(NoConsoleMessage, Unexpected, "log"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
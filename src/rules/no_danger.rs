@@ -0,0 +1,214 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoDanger {
+  options: NoDangerOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoDangerOptions {
+  /// Props reported in addition to `dangerouslySetInnerHTML`, e.g.
+  /// `innerHTML`.
+  additional_props: Vec<String>,
+}
+
+const CODE: &str = "no-danger";
+const HINT: &str = "This prop bypasses escaping and can expose the page to cross-site scripting (XSS); render the content as text or make sure it's sanitized";
+const DANGEROUS_PROP: &str = "dangerouslySetInnerHTML";
+
+#[derive(Display)]
+enum NoDangerMessage {
+  #[display(fmt = "Do not use the `{}` prop", _0)]
+  Dangerous(String),
+}
+
+impl LintRule for NoDanger {
+  fn new() -> Box<Self> {
+    Box::new(NoDanger::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoDangerHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `dangerouslySetInnerHTML` on DOM elements
+
+`dangerouslySetInnerHTML` inserts a string as raw HTML, so any user-controlled
+part of it can inject scripts into the page. Rendering the content as text
+avoids the problem entirely.
+
+Only DOM elements like `<div>` are checked, not components. The
+`additionalProps` option takes a list of other prop names to report as well,
+e.g. `["innerHTML"]`.
+
+### Invalid:
+```typescript
+<div dangerouslySetInnerHTML={{ __html: content }} />;
+
+// with { "additionalProps": ["innerHTML"] }
+<div innerHTML={content} />;
+```
+
+### Valid:
+```typescript
+<div>{content}</div>;
+<Markdown dangerouslySetInnerHTML={{ __html: content }} />;
+```
+"#
+  }
+}
+
+/// Checks if the element name refers to a DOM element rather than a
+/// component, i.e. it's a lowercase name like `div` or `my-element`.
+fn is_dom_element(name: &AstView::JSXElementName) -> bool {
+  match name {
+    AstView::JSXElementName::Ident(ident) => {
+      ident.sym().starts_with(|c: char| c.is_ascii_lowercase())
+    }
+    _ => false,
+  }
+}
+
+struct NoDangerHandler<'a> {
+  options: &'a NoDangerOptions,
+}
+
+impl<'a> Handler for NoDangerHandler<'a> {
+  fn jsx_opening_element(
+    &mut self,
+    jsx_opening_element: &AstView::JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    if !is_dom_element(&jsx_opening_element.name) {
+      return;
+    }
+
+    for attr in &jsx_opening_element.attrs {
+      let name = match attr {
+        AstView::JSXAttrOrSpread::JSXAttr(attr) => attr.name.text(),
+        AstView::JSXAttrOrSpread::SpreadElement(_) => continue,
+      };
+      let is_dangerous = name == DANGEROUS_PROP
+        || self
+          .options
+          .additional_props
+          .iter()
+          .any(|prop| prop == name);
+      if is_dangerous {
+        ctx.add_diagnostic_with_hint(
+          attr.span(),
+          CODE,
+          NoDangerMessage::Dangerous(name.to_string()),
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_danger_valid() {
+    assert_lint_ok! {
+      NoDanger,
+      { src: "<div>{content}</div>;", filename: "foo.tsx" },
+      { src: "<div className={foo} />;", filename: "foo.tsx" },
+      { src: "<Markdown dangerouslySetInnerHTML={{ __html: content }} />;", filename: "foo.tsx" },
+      { src: "<ui.div dangerouslySetInnerHTML={{ __html: content }} />;", filename: "foo.tsx" },
+      { src: "<div innerHTML={content} />;", filename: "foo.tsx" },
+      { src: "<div {...props} />;", filename: "foo.tsx" },
+    };
+  }
+
+  #[test]
+  fn no_danger_invalid() {
+    assert_lint_err! {
+      NoDanger,
+      "<div dangerouslySetInnerHTML={{ __html: content }} />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 5,
+            message: variant!(NoDangerMessage, Dangerous, DANGEROUS_PROP),
+            hint: HINT,
+          }
+        ],
+      },
+      "<my-element id=\"foo\" dangerouslySetInnerHTML={foo}></my-element>;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 21,
+            message: variant!(NoDangerMessage, Dangerous, DANGEROUS_PROP),
+            hint: HINT,
+          }
+        ],
+      },
+    };
+
+    assert_lint_err! {
+      NoDanger,
+      options: { "additionalProps": ["innerHTML", "srcdoc"] },
+      "<div innerHTML={content} />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 5,
+            message: variant!(NoDangerMessage, Dangerous, "innerHTML"),
+            hint: HINT,
+          }
+        ],
+      },
+      "<iframe srcdoc={html} dangerouslySetInnerHTML={foo} />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 8,
+            message: variant!(NoDangerMessage, Dangerous, "srcdoc"),
+            hint: HINT,
+          },
+          {
+            col: 22,
+            message: variant!(NoDangerMessage, Dangerous, DANGEROUS_PROP),
+            hint: HINT,
+          }
+        ],
+      },
+    };
+  }
+}
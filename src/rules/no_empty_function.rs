@@ -0,0 +1,458 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, Accessibility, MethodKind};
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+
+#[derive(Default)]
+pub struct NoEmptyFunction {
+  options: NoEmptyFunctionOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoEmptyFunctionOptions {
+  /// Kinds of functions that are allowed to be empty, e.g. `["arrowFunctions"]`
+  allow: Vec<Allow>,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum Allow {
+  Functions,
+  ArrowFunctions,
+  Methods,
+  Getters,
+  Setters,
+  Constructors,
+  PrivateConstructors,
+  ProtectedConstructors,
+}
+
+const CODE: &str = "no-empty-function";
+const HINT: &str =
+  "Add code, or a comment explaining why the function is intentionally empty";
+
+#[derive(Clone, Copy, Display)]
+enum FunctionKind {
+  #[display(fmt = "function")]
+  Function,
+  #[display(fmt = "arrow function")]
+  ArrowFunction,
+  #[display(fmt = "method")]
+  Method,
+  #[display(fmt = "getter")]
+  Getter,
+  #[display(fmt = "setter")]
+  Setter,
+  #[display(fmt = "constructor")]
+  Constructor,
+}
+
+impl FunctionKind {
+  fn from_method_kind(method_kind: MethodKind) -> Self {
+    match method_kind {
+      MethodKind::Method => FunctionKind::Method,
+      MethodKind::Getter => FunctionKind::Getter,
+      MethodKind::Setter => FunctionKind::Setter,
+    }
+  }
+
+  fn allow(self) -> Allow {
+    match self {
+      FunctionKind::Function => Allow::Functions,
+      FunctionKind::ArrowFunction => Allow::ArrowFunctions,
+      FunctionKind::Method => Allow::Methods,
+      FunctionKind::Getter => Allow::Getters,
+      FunctionKind::Setter => Allow::Setters,
+      FunctionKind::Constructor => Allow::Constructors,
+    }
+  }
+}
+
+#[derive(Display)]
+enum NoEmptyFunctionMessage {
+  #[display(fmt = "Unexpected empty {}", _0)]
+  Empty(String),
+}
+
+impl LintRule for NoEmptyFunction {
+  fn new() -> Box<Self> {
+    Box::new(NoEmptyFunction::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoEmptyFunctionHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows functions with an empty body
+
+An empty function is often unfinished code. If it's intentional, e.g. a no-op
+callback, a comment in its body makes that clear to the reader, and such
+functions aren't reported.
+
+Constructors that declare parameter properties, like
+`constructor(private name: string) {}`, aren't empty and are never reported.
+Other kinds can be permitted with the `allow` option, which accepts
+`"functions"`, `"arrowFunctions"`, `"methods"`, `"getters"`, `"setters"`,
+`"constructors"`, `"privateConstructors"` and `"protectedConstructors"`, e.g.
+`{ "allow": ["arrowFunctions"] }`.
+
+### Invalid:
+```typescript
+function foo() {}
+
+const bar = () => {};
+
+class Baz {
+  constructor() {}
+  qux() {}
+}
+```
+
+### Valid:
+```typescript
+function foo() {
+  // intentionally empty
+}
+
+const bar = () => {
+  /* no-op */
+};
+
+class Baz {
+  constructor(private name: string) {}
+}
+
+// with { "allow": ["privateConstructors"] }
+class Singleton {
+  private constructor() {}
+}
+```
+"#
+  }
+}
+
+struct NoEmptyFunctionHandler<'a> {
+  options: &'a NoEmptyFunctionOptions,
+}
+
+impl<'a> NoEmptyFunctionHandler<'a> {
+  fn is_allowed(&self, allow: Allow) -> bool {
+    self.options.allow.contains(&allow)
+  }
+
+  fn check(
+    &self,
+    span: Span,
+    kind: FunctionKind,
+    body: Option<&AstView::BlockStmt>,
+    ctx: &mut Context,
+  ) {
+    // Functions without a body, like overload signatures, are fine.
+    let body = match body {
+      Some(body) => body,
+      None => return,
+    };
+    if !body.stmts.is_empty() || self.is_allowed(kind.allow()) {
+      return;
+    }
+    let body_span = body.span();
+    if ctx
+      .all_comments()
+      .any(|comment| body_span.contains(comment.span))
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoEmptyFunctionMessage::Empty(kind.to_string()),
+      HINT,
+    );
+  }
+}
+
+impl<'a> Handler for NoEmptyFunctionHandler<'a> {
+  fn function(&mut self, function: &AstView::Function, ctx: &mut Context) {
+    let (span, kind) = match function.parent {
+      AstView::Node::FnDecl(fn_decl) => {
+        (fn_decl.span(), FunctionKind::Function)
+      }
+      AstView::Node::FnExpr(fn_expr) => {
+        (fn_expr.span(), FunctionKind::Function)
+      }
+      AstView::Node::ClassMethod(method) => (
+        method.span(),
+        FunctionKind::from_method_kind(method.method_kind()),
+      ),
+      AstView::Node::PrivateMethod(method) => (
+        method.span(),
+        FunctionKind::from_method_kind(method.method_kind()),
+      ),
+      AstView::Node::MethodProp(method) => {
+        (method.span(), FunctionKind::Method)
+      }
+      _ => return,
+    };
+    self.check(span, kind, function.body, ctx);
+  }
+
+  fn arrow_expr(&mut self, arrow_expr: &AstView::ArrowExpr, ctx: &mut Context) {
+    if let AstView::BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      self.check(
+        arrow_expr.span(),
+        FunctionKind::ArrowFunction,
+        Some(body),
+        ctx,
+      );
+    }
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &AstView::GetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      getter_prop.span(),
+      FunctionKind::Getter,
+      getter_prop.body,
+      ctx,
+    );
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &AstView::SetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      setter_prop.span(),
+      FunctionKind::Setter,
+      setter_prop.body,
+      ctx,
+    );
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &AstView::Constructor,
+    ctx: &mut Context,
+  ) {
+    let has_param_props = constructor.params.iter().any(|param| {
+      matches!(param, AstView::ParamOrTsParamProp::TsParamProp(_))
+    });
+    if has_param_props {
+      return;
+    }
+    let allowed_by_accessibility = match constructor.accessibility() {
+      Some(Accessibility::Private) => {
+        self.is_allowed(Allow::PrivateConstructors)
+      }
+      Some(Accessibility::Protected) => {
+        self.is_allowed(Allow::ProtectedConstructors)
+      }
+      _ => false,
+    };
+    if allowed_by_accessibility {
+      return;
+    }
+
+    self.check(
+      constructor.span(),
+      FunctionKind::Constructor,
+      constructor.body,
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_empty_function_valid() {
+    assert_lint_ok! {
+      NoEmptyFunction,
+      "function foo() { bar(); }",
+      "function foo() { /* empty */ }",
+      "function foo() {\n  // empty\n}",
+      "const foo = () => {\n  // no-op\n};",
+      "const foo = () => bar;",
+      "const foo = function () { return 1; };",
+      "class A { constructor(private name: string) {} }",
+      "class A { constructor(readonly a: number, b: string) {} }",
+      "class A { foo() { /* empty */ } }",
+      "abstract class A { abstract foo(): void; }",
+      "class A { foo(): void; foo(a?: number) { bar(a); } }",
+      "declare function foo(): void;",
+      "const obj = { get foo() { return 1; }, set foo(v) { bar(v); } };",
+    };
+
+    assert_lint_ok! {
+      NoEmptyFunction,
+      options: { "allow": ["arrowFunctions"] },
+      "const foo = () => {};",
+      "foo(() => {});",
+    };
+
+    assert_lint_ok! {
+      NoEmptyFunction,
+      options: { "allow": ["privateConstructors", "protectedConstructors"] },
+      "class A { private constructor() {} }",
+      "class A { protected constructor() {} }",
+    };
+
+    assert_lint_ok! {
+      NoEmptyFunction,
+      options: { "allow": ["functions", "methods", "getters", "setters", "constructors"] },
+      "function foo() {}",
+      "const foo = function () {};",
+      "class A { foo() {} #bar() {} get baz() {} set baz(v) {} }",
+      "const obj = { foo() {}, get bar() {}, set bar(v) {} };",
+      "class A { constructor() {} }",
+      "class A { private constructor() {} }",
+    };
+  }
+
+  #[test]
+  fn no_empty_function_invalid() {
+    assert_lint_err! {
+      NoEmptyFunction,
+      "function foo() {}": [
+        {
+          col: 0,
+          message: variant!(NoEmptyFunctionMessage, Empty, "function"),
+          hint: HINT,
+        }
+      ],
+      "const foo = function () {};": [
+        {
+          col: 12,
+          message: variant!(NoEmptyFunctionMessage, Empty, "function"),
+          hint: HINT,
+        }
+      ],
+      "const foo = () => {};": [
+        {
+          col: 12,
+          message: variant!(NoEmptyFunctionMessage, Empty, "arrow function"),
+          hint: HINT,
+        }
+      ],
+      "class A { foo() {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "method"),
+          hint: HINT,
+        }
+      ],
+      "class A { #foo() {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "method"),
+          hint: HINT,
+        }
+      ],
+      "class A { get foo() {} set foo(v) {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "getter"),
+          hint: HINT,
+        },
+        {
+          col: 23,
+          message: variant!(NoEmptyFunctionMessage, Empty, "setter"),
+          hint: HINT,
+        }
+      ],
+      "class A { constructor() {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "constructor"),
+          hint: HINT,
+        }
+      ],
+      "class A { private constructor() {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "constructor"),
+          hint: HINT,
+        }
+      ],
+      "const obj = { foo() {}, get bar() {}, set bar(v) {} };": [
+        {
+          col: 14,
+          message: variant!(NoEmptyFunctionMessage, Empty, "method"),
+          hint: HINT,
+        },
+        {
+          col: 24,
+          message: variant!(NoEmptyFunctionMessage, Empty, "getter"),
+          hint: HINT,
+        },
+        {
+          col: 38,
+          message: variant!(NoEmptyFunctionMessage, Empty, "setter"),
+          hint: HINT,
+        }
+      ],
+      "function foo() {} // not inside the body": [
+        {
+          col: 0,
+          message: variant!(NoEmptyFunctionMessage, Empty, "function"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoEmptyFunction,
+      options: { "allow": ["privateConstructors"] },
+      "class A { protected constructor() {} }": [
+        {
+          col: 10,
+          message: variant!(NoEmptyFunctionMessage, Empty, "constructor"),
+          hint: HINT,
+        }
+      ],
+      "const foo = () => {};": [
+        {
+          col: 12,
+          message: variant!(NoEmptyFunctionMessage, Empty, "arrow function"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
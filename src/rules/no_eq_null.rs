@@ -0,0 +1,299 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::equal_tokens;
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, NodeTrait};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoEqNull {
+  mode: Mode,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum Mode {
+  /// `== null` and `!= null` are reported.
+  Never,
+  /// `== null` and `!= null` are the preferred nullish checks, so explicit
+  /// checks for both `null` and `undefined` are reported instead.
+  Always,
+}
+
+impl Default for Mode {
+  fn default() -> Self {
+    Mode::Never
+  }
+}
+
+const CODE: &str = "no-eq-null";
+
+#[derive(Display)]
+enum NoEqNullMessage {
+  #[display(fmt = "Use `===` to compare with `null`")]
+  StrictEq,
+  #[display(fmt = "Use `!==` to compare with `null`")]
+  StrictNotEq,
+  #[display(fmt = "Use `== null` to check for both `null` and `undefined`")]
+  EqNull,
+  #[display(fmt = "Use `!= null` to check for both `null` and `undefined`")]
+  NotEqNull,
+}
+
+#[derive(Display)]
+enum NoEqNullHint {
+  #[display(
+    fmt = "`== null` is also true for `undefined`; compare with `=== null` if only `null` is meant, or check for `undefined` explicitly as well"
+  )]
+  Strict,
+  #[display(fmt = "Replace both comparisons with `{} {} null`", _0, _1)]
+  Loose(String, String),
+}
+
+impl LintRule for NoEqNull {
+  fn new() -> Box<Self> {
+    Box::new(NoEqNull::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoEqNullHandler { mode: self.mode }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows comparing with `null` using `==` or `!=`
+
+Because of type coercion, `x == null` is true not only when `x` is `null`, but
+also when it is `undefined`. This is easy to miss when reading the code, so by
+default (`"never"`) the rule requires `===` and `!==` for comparisons with
+`null`.
+
+Many codebases rely on this behavior on purpose, as `x == null` is the
+shortest way to check for both values at once. With the `"always"` option the
+rule goes the other way: `== null` and `!= null` are allowed, and explicit
+checks like `x === null || x === undefined` are reported instead.
+
+### Invalid:
+```typescript
+if (foo == null) {}
+if (bar != null) {}
+
+// with "always"
+if (foo === null || foo === undefined) {}
+if (bar !== null && bar !== undefined) {}
+```
+
+### Valid:
+```typescript
+if (foo === null) {}
+if (bar !== null) {}
+
+// with "always"
+if (foo == null) {}
+if (bar != null) {}
+```
+"#
+  }
+}
+
+fn is_null(expr: &AstView::Expr) -> bool {
+  matches!(expr, AstView::Expr::Lit(AstView::Lit::Null(_)))
+}
+
+fn is_undefined(expr: &AstView::Expr) -> bool {
+  matches!(expr, AstView::Expr::Ident(ident) if *ident.sym() == *"undefined")
+}
+
+/// Returns the operand compared with `null` or `undefined` using the given
+/// operator, together with which of the two it's compared with (`true` for
+/// `null`).
+fn nullish_comparison<'a>(
+  expr: &AstView::Expr<'a>,
+  op: BinaryOp,
+) -> Option<(AstView::Expr<'a>, bool)> {
+  let bin_expr = match expr {
+    AstView::Expr::Bin(bin_expr) if bin_expr.op() == op => bin_expr,
+    _ => return None,
+  };
+
+  let (operand, other) =
+    if is_null(&bin_expr.right) || is_undefined(&bin_expr.right) {
+      (bin_expr.left, bin_expr.right)
+    } else {
+      (bin_expr.right, bin_expr.left)
+    };
+
+  if is_null(&other) {
+    Some((operand, true))
+  } else if is_undefined(&other) {
+    Some((operand, false))
+  } else {
+    None
+  }
+}
+
+struct NoEqNullHandler {
+  mode: Mode,
+}
+
+impl NoEqNullHandler {
+  fn check_loose(&self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    let (message, hint) = match bin_expr.op() {
+      BinaryOp::EqEq => (NoEqNullMessage::StrictEq, NoEqNullHint::Strict),
+      BinaryOp::NotEq => (NoEqNullMessage::StrictNotEq, NoEqNullHint::Strict),
+      _ => return,
+    };
+
+    if is_null(&bin_expr.left) || is_null(&bin_expr.right) {
+      ctx.add_diagnostic_with_hint(bin_expr.span(), CODE, message, hint);
+    }
+  }
+
+  fn check_explicit(&self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    let (message, op, loose_op) = match bin_expr.op() {
+      BinaryOp::LogicalOr => (NoEqNullMessage::EqNull, BinaryOp::EqEqEq, "=="),
+      BinaryOp::LogicalAnd => {
+        (NoEqNullMessage::NotEqNull, BinaryOp::NotEqEq, "!=")
+      }
+      _ => return,
+    };
+
+    let left = nullish_comparison(&bin_expr.left, op);
+    let right = nullish_comparison(&bin_expr.right, op);
+    if let (Some((left, left_is_null)), Some((right, right_is_null))) =
+      (left, right)
+    {
+      if left_is_null != right_is_null && equal_tokens(&left, &right) {
+        ctx.add_diagnostic_with_hint(
+          bin_expr.span(),
+          CODE,
+          message,
+          NoEqNullHint::Loose(left.text().to_string(), loose_op.to_string()),
+        );
+      }
+    }
+  }
+}
+
+impl Handler for NoEqNullHandler {
+  fn bin_expr(&mut self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    match self.mode {
+      Mode::Never => self.check_loose(bin_expr, ctx),
+      Mode::Always => self.check_explicit(bin_expr, ctx),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_eq_null_valid() {
+    assert_lint_ok! {
+      NoEqNull,
+      "if (foo === null) {}",
+      "if (foo !== null) {}",
+      "if (null === foo) {}",
+      "if (foo == undefined) {}",
+      "if (foo == bar) {}",
+      "if (foo === null || foo === undefined) {}",
+    };
+
+    assert_lint_ok! {
+      NoEqNull,
+      options: "always",
+      "if (foo == null) {}",
+      "if (foo != null) {}",
+      "if (foo === null) {}",
+      "if (foo === null || bar === undefined) {}",
+      "if (foo === null || foo === null) {}",
+      "if (foo === null && foo === undefined) {}",
+      "if (foo !== null || foo !== undefined) {}",
+      "if (foo === null || foo === 0) {}",
+    };
+  }
+
+  #[test]
+  fn no_eq_null_invalid() {
+    assert_lint_err! {
+      NoEqNull,
+      "if (foo == null) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::StrictEq,
+          hint: NoEqNullHint::Strict,
+        }
+      ],
+      "if (foo != null) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::StrictNotEq,
+          hint: NoEqNullHint::Strict,
+        }
+      ],
+      "if (null == foo.bar) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::StrictEq,
+          hint: NoEqNullHint::Strict,
+        }
+      ],
+      "const isSet = (x) => x != null;": [
+        {
+          col: 21,
+          message: NoEqNullMessage::StrictNotEq,
+          hint: NoEqNullHint::Strict,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoEqNull,
+      options: "always",
+      "if (foo === null || foo === undefined) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::EqNull,
+          hint: variant!(NoEqNullHint, Loose, "foo", "=="),
+        }
+      ],
+      "if (undefined === foo.bar || null === foo.bar) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::EqNull,
+          hint: variant!(NoEqNullHint, Loose, "foo.bar", "=="),
+        }
+      ],
+      "if (foo !== null && foo !== undefined) {}": [
+        {
+          col: 4,
+          message: NoEqNullMessage::NotEqNull,
+          hint: variant!(NoEqNullHint, Loose, "foo", "!="),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,299 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::globals::GLOBALS;
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoExtendNative {
+  options: NoExtendNativeOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoExtendNativeOptions {
+  /// Builtins whose prototypes are allowed to be extended, e.g. `"Array"`.
+  exceptions: Vec<String>,
+}
+
+const CODE: &str = "no-extend-native";
+const HINT: &str = "Modifying a builtin prototype affects every other module using it; define a helper function or a subclass instead";
+
+#[derive(Display)]
+enum NoExtendNativeMessage {
+  #[display(fmt = "Do not extend the native `{}` prototype", _0)]
+  Extended(String),
+}
+
+impl LintRule for NoExtendNative {
+  fn new() -> Box<Self> {
+    Box::new(NoExtendNative::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoExtendNativeHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows adding properties to the prototypes of builtin objects
+
+The prototypes of builtins like `Array` or `String` are shared by all code in
+the program. Adding properties to them can break other modules that don't
+expect them, and clashes with methods added to the language later on.
+
+Both assignments like `Array.prototype.foo = ...` and calls of
+`Object.defineProperty()` or `Object.defineProperties()` on a builtin
+prototype are reported. The `exceptions` option takes a list of builtins whose
+prototypes may be extended anyway, e.g. `["Array"]`.
+
+### Invalid:
+```typescript
+Array.prototype.last = function () {
+  return this[this.length - 1];
+};
+Object.defineProperty(String.prototype, "shout", { value: shout });
+```
+
+### Valid:
+```typescript
+function last(array) {
+  return array[array.length - 1];
+}
+
+class MyArray extends Array {
+  last() {
+    return this[this.length - 1];
+  }
+}
+```
+"#
+  }
+}
+
+/// Checks if the name refers to a builtin constructor like `Array`.
+fn is_builtin(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_uppercase())
+    && GLOBALS.iter().any(|(global, _)| *global == name)
+}
+
+struct NoExtendNativeHandler<'a> {
+  options: &'a NoExtendNativeOptions,
+}
+
+impl<'a> NoExtendNativeHandler<'a> {
+  /// Returns the name of the builtin if the expression is its prototype,
+  /// like `Array.prototype`.
+  fn native_prototype(
+    &self,
+    expr: &AstView::Expr,
+    ctx: &Context,
+  ) -> Option<String> {
+    let member_expr = match expr {
+      AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+        member_expr
+      }
+      _ => return None,
+    };
+
+    match (&member_expr.obj, &member_expr.prop) {
+      (
+        AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+        AstView::Expr::Ident(prop),
+      ) if *prop.sym() == *"prototype" => {
+        let name = obj.sym().to_string();
+        let is_excepted = self.options.exceptions.contains(&name);
+        if is_builtin(&name) && !is_excepted && is_global(obj, ctx.scope()) {
+          Some(name)
+        } else {
+          None
+        }
+      }
+      _ => None,
+    }
+  }
+}
+
+impl<'a> Handler for NoExtendNativeHandler<'a> {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &AstView::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    let target = match &assign_expr.left {
+      AstView::PatOrExpr::Expr(expr) => expr,
+      AstView::PatOrExpr::Pat(AstView::Pat::Expr(expr)) => expr,
+      AstView::PatOrExpr::Pat(_) => return,
+    };
+
+    if let AstView::Expr::Member(member_expr) = target {
+      if let AstView::ExprOrSuper::Expr(obj) = &member_expr.obj {
+        if let Some(name) = self.native_prototype(obj, ctx) {
+          ctx.add_diagnostic_with_hint(
+            assign_expr.span(),
+            CODE,
+            NoExtendNativeMessage::Extended(name),
+            HINT,
+          );
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let member_expr = match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(AstView::Expr::Member(member_expr))
+        if !member_expr.computed() =>
+      {
+        member_expr
+      }
+      _ => return,
+    };
+
+    let is_define_property = match (&member_expr.obj, &member_expr.prop) {
+      (
+        AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+        AstView::Expr::Ident(prop),
+      ) => {
+        *obj.sym() == *"Object"
+          && matches!(&**prop.sym(), "defineProperty" | "defineProperties")
+          && is_global(obj, ctx.scope())
+      }
+      _ => false,
+    };
+    if !is_define_property {
+      return;
+    }
+
+    if let Some(target) = call_expr.args.first() {
+      if let Some(name) = self.native_prototype(&target.expr, ctx) {
+        ctx.add_diagnostic_with_hint(
+          call_expr.span(),
+          CODE,
+          NoExtendNativeMessage::Extended(name),
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_extend_native_valid() {
+    assert_lint_ok! {
+      NoExtendNative,
+      "x.prototype.foo = 1;",
+      "Foo.prototype.bar = function () {};",
+      "Array.foo = 1;",
+      "Array.prototype;",
+      "const last = Array.prototype.last;",
+      "Array.prototype.foo.bar = 1;",
+      "Object.prototype.toString.call(foo);",
+      "Object.defineProperty(Foo.prototype, 'bar', { value: 1 });",
+      "Object.defineProperty(foo, 'bar', { value: 1 });",
+      "foo.defineProperty(Array.prototype, 'bar', { value: 1 });",
+      "function foo(Array) { Array.prototype.bar = 1; }",
+      "class Array {} Array.prototype.foo = 1;",
+      "const { prototype } = Array;",
+    };
+
+    assert_lint_ok! {
+      NoExtendNative,
+      options: { "exceptions": ["Array"] },
+      "Array.prototype.last = function () {};",
+      "Object.defineProperty(Array.prototype, 'last', { value: last });",
+    };
+  }
+
+  #[test]
+  fn no_extend_native_invalid() {
+    assert_lint_err! {
+      NoExtendNative,
+      "Array.prototype.last = function () {};": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "Array"),
+          hint: HINT,
+        }
+      ],
+      "String.prototype['shout'] = shout;": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "String"),
+          hint: HINT,
+        }
+      ],
+      "Number.prototype.count += 1;": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "Number"),
+          hint: HINT,
+        }
+      ],
+      "Object.defineProperty(String.prototype, 'shout', { value: shout });": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "String"),
+          hint: HINT,
+        }
+      ],
+      "Object.defineProperties(Promise.prototype, { foo: { value: 1 } });": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "Promise"),
+          hint: HINT,
+        }
+      ],
+      "function foo() { Object.prototype.bar = 1; }": [
+        {
+          col: 17,
+          message: variant!(NoExtendNativeMessage, Extended, "Object"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoExtendNative,
+      options: { "exceptions": ["Array"] },
+      "Map.prototype.foo = 1;": [
+        {
+          col: 0,
+          message: variant!(NoExtendNativeMessage, Extended, "Map"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,289 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{is_global, skip_wrappers, static_member_prop_name};
+use dprint_swc_ecma_ast_view as AstView;
+use std::collections::HashSet;
+use swc_common::Spanned;
+use swc_ecmascript::utils::{ident::IdentLike, Id};
+
+pub struct NoFloatingPromises;
+
+const CODE: &str = "no-floating-promises";
+const MESSAGE: &str =
+  "Promises must be awaited or have their rejections handled";
+const HINT: &str = "Add `await`, handle rejections with `.catch()`, or mark the promise as intentionally ignored with `void`";
+
+/// Static methods of `Promise` that return a promise.
+const PROMISE_METHODS: &[&str] =
+  &["all", "allSettled", "any", "race", "reject", "resolve"];
+
+impl LintRule for NoFloatingPromises {
+  fn new() -> Box<Self> {
+    Box::new(NoFloatingPromises)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    let mut collector = AsyncFunctionCollector::default();
+    collector.traverse(program, context);
+
+    NoFloatingPromisesHandler {
+      async_functions: collector.async_functions,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires promises in expression statements to be handled
+
+A promise that is neither awaited nor has a rejection handler silently
+swallows its errors, or crashes the program with an unhandled rejection.
+
+Without type information this rule can only recognize some promises: calls of
+`async` functions declared in the same module, `.then()` and `.finally()`
+chains, `fetch()`, `new Promise()` and static methods like `Promise.all()`.
+A statement is fine when it awaits the promise, ends with `.catch()` or a
+`.then()` with a rejection handler, possibly followed by `.finally()`, or is
+explicitly ignored with `void`.
+
+### Invalid:
+```typescript
+async function foo() {}
+foo();
+
+fetch(url).then((res) => res.json());
+Promise.all([foo(), bar()]);
+```
+
+### Valid:
+```typescript
+async function foo() {}
+await foo();
+void foo();
+const promise = foo();
+
+fetch(url).then((res) => res.json()).catch(console.error);
+fetch(url).then(onSuccess, onError);
+```
+"#
+  }
+}
+
+/// Collects the functions declared with `async`, like `async function foo()`
+/// or `const foo = async () => {}`.
+#[derive(Default)]
+struct AsyncFunctionCollector {
+  async_functions: HashSet<Id>,
+}
+
+impl Handler for AsyncFunctionCollector {
+  fn fn_decl(&mut self, fn_decl: &AstView::FnDecl, _ctx: &mut Context) {
+    if fn_decl.function.is_async() {
+      self.async_functions.insert(fn_decl.ident.inner.to_id());
+    }
+  }
+
+  fn var_declarator(
+    &mut self,
+    declarator: &AstView::VarDeclarator,
+    _ctx: &mut Context,
+  ) {
+    if let (AstView::Pat::Ident(binding), Some(init)) =
+      (&declarator.name, &declarator.init)
+    {
+      if is_async_function(init) {
+        self.async_functions.insert(binding.id.inner.to_id());
+      }
+    }
+  }
+}
+
+fn is_async_function(expr: &AstView::Expr) -> bool {
+  match skip_wrappers(expr) {
+    AstView::Expr::Arrow(arrow) => arrow.is_async(),
+    AstView::Expr::Fn(fn_expr) => fn_expr.function.is_async(),
+    _ => false,
+  }
+}
+
+/// Returns the object and method name of a method call like `foo.bar()`.
+fn method_call<'a>(
+  call_expr: &AstView::CallExpr<'a>,
+) -> Option<(AstView::Expr<'a>, String)> {
+  let callee = match &call_expr.callee {
+    AstView::ExprOrSuper::Expr(callee) => skip_wrappers(callee),
+    AstView::ExprOrSuper::Super(_) => return None,
+  };
+  match callee {
+    AstView::Expr::Member(member_expr) => match member_expr.obj {
+      AstView::ExprOrSuper::Expr(obj) => {
+        Some((obj, static_member_prop_name(member_expr.inner)?))
+      }
+      AstView::ExprOrSuper::Super(_) => None,
+    },
+    _ => None,
+  }
+}
+
+/// Checks if the promise is handled by a final `.catch()` or a `.then()`
+/// with a rejection handler, possibly followed by `.finally()`.
+fn is_handled(expr: &AstView::Expr) -> bool {
+  match skip_wrappers(expr) {
+    AstView::Expr::Call(call_expr) => match method_call(call_expr) {
+      Some((_, method)) if method == "catch" => !call_expr.args.is_empty(),
+      Some((_, method)) if method == "then" => call_expr.args.len() >= 2,
+      Some((obj, method)) if method == "finally" => is_handled(&obj),
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+fn is_global_named(expr: &AstView::Expr, name: &str, ctx: &Context) -> bool {
+  matches!(
+    skip_wrappers(expr),
+    AstView::Expr::Ident(ident)
+      if *ident.sym() == *name && is_global(ident, ctx.scope())
+  )
+}
+
+struct NoFloatingPromisesHandler {
+  async_functions: HashSet<Id>,
+}
+
+impl NoFloatingPromisesHandler {
+  /// Checks if the expression obviously evaluates to a promise.
+  fn is_promise(&self, expr: &AstView::Expr, ctx: &Context) -> bool {
+    match skip_wrappers(expr) {
+      AstView::Expr::Call(call_expr) => {
+        if let Some((obj, method)) = method_call(call_expr) {
+          return match method.as_str() {
+            "then" | "catch" | "finally" => true,
+            method => {
+              is_global_named(&obj, "Promise", ctx)
+                && PROMISE_METHODS.contains(&method)
+            }
+          };
+        }
+
+        match &call_expr.callee {
+          AstView::ExprOrSuper::Expr(callee) => match skip_wrappers(callee) {
+            AstView::Expr::Ident(ident) => {
+              self.async_functions.contains(&ident.inner.to_id())
+                || is_global_named(callee, "fetch", ctx)
+            }
+            callee => is_async_function(&callee),
+          },
+          AstView::ExprOrSuper::Super(_) => false,
+        }
+      }
+      AstView::Expr::New(new_expr) => {
+        is_global_named(&new_expr.callee, "Promise", ctx)
+      }
+      _ => false,
+    }
+  }
+}
+
+impl Handler for NoFloatingPromisesHandler {
+  fn expr_stmt(&mut self, expr_stmt: &AstView::ExprStmt, ctx: &mut Context) {
+    if self.is_promise(&expr_stmt.expr, ctx) && !is_handled(&expr_stmt.expr) {
+      ctx.add_diagnostic_with_hint(expr_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_floating_promises_valid() {
+    assert_lint_ok! {
+      NoFloatingPromises,
+      "async function foo() {} await foo();",
+      "async function foo() {} void foo();",
+      "async function foo() {} const bar = foo();",
+      "async function foo() {} foo().catch(console.error);",
+      "async function foo() {} foo().then(bar).catch(baz);",
+      "async function foo() {} foo().then(bar, baz);",
+      "async function foo() {} async function bar() { return foo(); }",
+      "function foo() {} foo();",
+      "const foo = () => {}; foo();",
+      "async function foo() {} function bar(foo) { foo(); }",
+      "fetch(url).catch(() => {});",
+      "void fetch(url);",
+      "function bar(fetch) { fetch(url); }",
+      "function fetch() {} fetch(url);",
+      "const Promise = { all() {} }; Promise.all([a, b]);",
+      "class Promise {} new Promise();",
+      "async function foo() {} foo().catch(bar).finally(baz);",
+      "fetch(url).then(a, b).finally(c).finally(d);",
+      "Promise.all([a, b]).catch(console.error);",
+      "Promise.foo();",
+      "new Promise((resolve) => resolve()).then(a, b);",
+      "foo.bar();",
+    };
+  }
+
+  #[test]
+  fn no_floating_promises_invalid() {
+    assert_lint_err! {
+      NoFloatingPromises,
+      "async function foo() {} foo();": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      "const foo = async () => {}; foo();": [
+        { col: 28, message: MESSAGE, hint: HINT }
+      ],
+      "const foo = async function () {}; (foo)();": [
+        { col: 34, message: MESSAGE, hint: HINT }
+      ],
+      "async function foo() {} function bar() { foo(); }": [
+        { col: 41, message: MESSAGE, hint: HINT }
+      ],
+      "async function foo() {} foo().then(bar);": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      "async function foo() {} foo().then(bar).finally(baz);": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      "fetch(url);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "fetch(url).then((res) => res.json());": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "foo.then(bar);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "Promise.all([a, b]);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "Promise.reject(new Error());": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "new Promise((resolve) => setTimeout(resolve));": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "(async () => { await foo(); })();": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "async function foo() {} foo().catch();": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,250 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp};
+use swc_common::Spanned;
+
+pub struct NoImpliedEval;
+
+const CODE: &str = "no-implied-eval";
+
+#[derive(Display)]
+enum NoImpliedEvalMessage {
+  #[display(fmt = "Passing a string to `{}` evaluates it as code", _0)]
+  StringArg(String),
+  #[display(
+    fmt = "The `Function` constructor evaluates its arguments as code"
+  )]
+  FunctionConstructor,
+}
+
+#[derive(Display)]
+enum NoImpliedEvalHint {
+  #[display(fmt = "Pass a function instead, e.g. `{}(() => foo(), 100)`", _0)]
+  PassFunction(String),
+  #[display(fmt = "Define a regular function instead")]
+  DefineFunction,
+}
+
+const TIMER_FNS: [&str; 2] = ["setTimeout", "setInterval"];
+const GLOBAL_OBJS: [&str; 3] = ["globalThis", "window", "self"];
+
+impl LintRule for NoImpliedEval {
+  fn new() -> Box<Self> {
+    Box::new(NoImpliedEval)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoImpliedEvalHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows passing strings of code to `setTimeout`, `setInterval` and `Function`
+
+When `setTimeout()` or `setInterval()` receive a string instead of a function,
+the string is evaluated as code, just like with `eval()`. The same goes for the
+arguments of the `Function` constructor. This is slow, hides errors until
+runtime, can open the door to code injection and is blocked by most Content
+Security Policies.
+
+String literals, template literals and concatenations of them are reported.
+
+### Invalid:
+```typescript
+setTimeout("alert('Hi!');", 100);
+setInterval(`update(${id})`, 1000);
+const add = new Function("a", "b", "return a + b");
+```
+
+### Valid:
+```typescript
+setTimeout(() => alert("Hi!"), 100);
+setInterval(() => update(id), 1000);
+const add = (a, b) => a + b;
+```
+"#
+  }
+}
+
+/// Returns the name of the global function being called, also when accessed
+/// through `globalThis`, `window` or `self`.
+fn global_fn_name(callee: &AstView::Expr, ctx: &Context) -> Option<String> {
+  let ident = match callee {
+    AstView::Expr::Ident(ident) if is_global(ident, ctx.scope()) => ident,
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      match (&member_expr.obj, &member_expr.prop) {
+        (
+          AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+          AstView::Expr::Ident(prop),
+        ) if GLOBAL_OBJS.contains(&&**obj.sym())
+          && is_global(obj, ctx.scope()) =>
+        {
+          prop
+        }
+        _ => return None,
+      }
+    }
+    _ => return None,
+  };
+  Some(ident.sym().to_string())
+}
+
+/// Checks if the expression evaluates to a string, i.e. it's a string or
+/// template literal, or a concatenation involving one.
+fn is_string(expr: &AstView::Expr) -> bool {
+  match expr {
+    AstView::Expr::Lit(AstView::Lit::Str(_)) | AstView::Expr::Tpl(_) => true,
+    AstView::Expr::Paren(paren_expr) => is_string(&paren_expr.expr),
+    AstView::Expr::Bin(bin_expr) if bin_expr.op() == BinaryOp::Add => {
+      is_string(&bin_expr.left) || is_string(&bin_expr.right)
+    }
+    _ => false,
+  }
+}
+
+struct NoImpliedEvalHandler;
+
+impl Handler for NoImpliedEvalHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let name = match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(callee) => match global_fn_name(callee, ctx) {
+        Some(name) => name,
+        None => return,
+      },
+      AstView::ExprOrSuper::Super(_) => return,
+    };
+
+    if name == "Function" {
+      ctx.add_diagnostic_with_hint(
+        call_expr.span(),
+        CODE,
+        NoImpliedEvalMessage::FunctionConstructor,
+        NoImpliedEvalHint::DefineFunction,
+      );
+      return;
+    }
+
+    if TIMER_FNS.contains(&name.as_str()) {
+      if let Some(arg) = call_expr.args.first() {
+        if is_string(&arg.expr) {
+          ctx.add_diagnostic_with_hint(
+            arg.span(),
+            CODE,
+            NoImpliedEvalMessage::StringArg(name.clone()),
+            NoImpliedEvalHint::PassFunction(name),
+          );
+        }
+      }
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &AstView::NewExpr, ctx: &mut Context) {
+    if global_fn_name(&new_expr.callee, ctx).as_deref() == Some("Function") {
+      ctx.add_diagnostic_with_hint(
+        new_expr.span(),
+        CODE,
+        NoImpliedEvalMessage::FunctionConstructor,
+        NoImpliedEvalHint::DefineFunction,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_implied_eval_valid() {
+    assert_lint_ok! {
+      NoImpliedEval,
+      "setTimeout(() => foo(), 100);",
+      "setTimeout(foo, 100);",
+      "setInterval(function () { foo(); }, 100);",
+      "setTimeout(foo + bar, 100);",
+      "setTimeout();",
+      "foo.setTimeout('foo()', 100);",
+      "function foo(setTimeout) { setTimeout('foo()', 100); }",
+      "clearTimeout('foo');",
+      "new foo.Function('return 1');",
+      "function foo(Function) { new Function('return 1'); }",
+    };
+  }
+
+  #[test]
+  fn no_implied_eval_invalid() {
+    assert_lint_err! {
+      NoImpliedEval,
+      "setTimeout('foo()', 100);": [
+        {
+          col: 11,
+          message: variant!(NoImpliedEvalMessage, StringArg, "setTimeout"),
+          hint: variant!(NoImpliedEvalHint, PassFunction, "setTimeout"),
+        }
+      ],
+      "setInterval(`update(${id})`, 1000);": [
+        {
+          col: 12,
+          message: variant!(NoImpliedEvalMessage, StringArg, "setInterval"),
+          hint: variant!(NoImpliedEvalHint, PassFunction, "setInterval"),
+        }
+      ],
+      "setTimeout('foo(' + bar + ')');": [
+        {
+          col: 11,
+          message: variant!(NoImpliedEvalMessage, StringArg, "setTimeout"),
+          hint: variant!(NoImpliedEvalHint, PassFunction, "setTimeout"),
+        }
+      ],
+      "globalThis.setTimeout('foo()');": [
+        {
+          col: 22,
+          message: variant!(NoImpliedEvalMessage, StringArg, "setTimeout"),
+          hint: variant!(NoImpliedEvalHint, PassFunction, "setTimeout"),
+        }
+      ],
+      "window.setInterval(('foo()'), 100);": [
+        {
+          col: 19,
+          message: variant!(NoImpliedEvalMessage, StringArg, "setInterval"),
+          hint: variant!(NoImpliedEvalHint, PassFunction, "setInterval"),
+        }
+      ],
+      "const add = new Function('a', 'b', 'return a + b');": [
+        {
+          col: 12,
+          message: NoImpliedEvalMessage::FunctionConstructor,
+          hint: NoImpliedEvalHint::DefineFunction,
+        }
+      ],
+      "const add = Function('a', 'b', 'return a + b');": [
+        {
+          col: 12,
+          message: NoImpliedEvalMessage::FunctionConstructor,
+          hint: NoImpliedEvalHint::DefineFunction,
+        }
+      ],
+      "new globalThis.Function(code);": [
+        {
+          col: 0,
+          message: NoImpliedEvalMessage::FunctionConstructor,
+          hint: NoImpliedEvalHint::DefineFunction,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,400 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::skip_paren_ancestors;
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, TsKeywordTypeKind};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoInvalidVoidType {
+  options: NoInvalidVoidTypeOptions,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoInvalidVoidTypeOptions {
+  /// Allows `void` as a generic type argument or type parameter default,
+  /// e.g. `Promise<void>`.
+  allow_in_generic_type_arguments: bool,
+  /// Allows `void` as the type of a `this` parameter.
+  allow_as_this_parameter: bool,
+}
+
+impl Default for NoInvalidVoidTypeOptions {
+  fn default() -> Self {
+    Self {
+      allow_in_generic_type_arguments: true,
+      allow_as_this_parameter: false,
+    }
+  }
+}
+
+const CODE: &str = "no-invalid-void-type";
+const HINT: &str =
+  "Use `undefined` instead for values that are always `undefined`";
+
+#[derive(Display)]
+enum NoInvalidVoidTypeMessage {
+  #[display(fmt = "`void` is only valid as a return type")]
+  NotReturn,
+  #[display(
+    fmt = "`void` is only valid as a return type or generic type argument"
+  )]
+  NotReturnOrGeneric,
+  #[display(
+    fmt = "`void` is only valid as a return type or `this` parameter type"
+  )]
+  NotReturnOrThisParam,
+  #[display(
+    fmt = "`void` is only valid as a return type, generic type argument or `this` parameter type"
+  )]
+  NotReturnOrThisParamOrGeneric,
+  #[display(fmt = "`void` is not valid as a constituent of a union type")]
+  UnionConstituent,
+}
+
+impl LintRule for NoInvalidVoidType {
+  fn new() -> Box<Self> {
+    Box::new(NoInvalidVoidType::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoInvalidVoidTypeHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `void` outside of return types and generic type arguments
+
+`void` means that a function's return value should be ignored. Anywhere else,
+like in a variable or parameter type or as part of a union, it's confusing and
+usually `undefined` was meant instead.
+
+`void` is allowed as a return type, including in a union with `never`.
+`allowInGenericTypeArguments` (`true` by default) also allows it as a generic
+type argument like `Promise<void>` and as a type parameter default, and
+`allowAsThisParameter` (`false` by default) allows `this: void` parameters.
+
+### Invalid:
+```typescript
+let foo: void;
+function bar(baz: void) {}
+type Qux = string | void;
+interface Quux {
+  corge: void;
+}
+
+// with { "allowInGenericTypeArguments": false }
+type Grault = Promise<void>;
+```
+
+### Valid:
+```typescript
+function foo(): void {}
+const bar = (): void => {};
+type Baz = () => void;
+function qux(): void | never {}
+type Quux = Promise<void>;
+type Corge<T = void> = T;
+
+// with { "allowAsThisParameter": true }
+function grault(this: void) {}
+```
+"#
+  }
+}
+
+fn is_keyword(ty: &AstView::TsType, kind: TsKeywordTypeKind) -> bool {
+  matches!(
+    ty,
+    AstView::TsType::TsKeywordType(keyword) if keyword.keyword_kind() == kind
+  )
+}
+
+fn is_return_type(type_ann: &AstView::TsTypeAnn) -> bool {
+  matches!(
+    type_ann.parent,
+    AstView::Node::Function(_)
+      | AstView::Node::ArrowExpr(_)
+      | AstView::Node::TsFnType(_)
+      | AstView::Node::TsConstructorType(_)
+      | AstView::Node::TsMethodSignature(_)
+      | AstView::Node::TsCallSignatureDecl(_)
+      | AstView::Node::TsConstructSignatureDecl(_)
+      | AstView::Node::TsGetterSignature(_)
+  )
+}
+
+fn is_this_param(type_ann: &AstView::TsTypeAnn) -> bool {
+  matches!(
+    type_ann.parent,
+    AstView::Node::BindingIdent(binding) if binding.id.sym() == "this"
+  )
+}
+
+/// Where `void` or a union containing it appears.
+enum Position {
+  Return,
+  Generic,
+  ThisParam,
+  Other,
+}
+
+fn position(node: AstView::Node) -> Position {
+  let (node, parent) = skip_paren_ancestors(node);
+  match parent {
+    Some(AstView::Node::TsTypeAnn(type_ann)) if is_return_type(type_ann) => {
+      Position::Return
+    }
+    Some(AstView::Node::TsTypeAnn(type_ann)) if is_this_param(type_ann) => {
+      Position::ThisParam
+    }
+    Some(AstView::Node::TsTypeParamInstantiation(_)) => Position::Generic,
+    Some(AstView::Node::TsTypeParam(type_param))
+      if type_param.default.map(|ty| ty.span()) == Some(node.span()) =>
+    {
+      Position::Generic
+    }
+    _ => Position::Other,
+  }
+}
+
+struct NoInvalidVoidTypeHandler {
+  options: NoInvalidVoidTypeOptions,
+}
+
+impl NoInvalidVoidTypeHandler {
+  fn is_allowed(&self, position: Position) -> bool {
+    match position {
+      Position::Return => true,
+      Position::Generic => self.options.allow_in_generic_type_arguments,
+      Position::ThisParam => self.options.allow_as_this_parameter,
+      Position::Other => false,
+    }
+  }
+
+  fn message(&self) -> NoInvalidVoidTypeMessage {
+    match (
+      self.options.allow_in_generic_type_arguments,
+      self.options.allow_as_this_parameter,
+    ) {
+      (false, false) => NoInvalidVoidTypeMessage::NotReturn,
+      (true, false) => NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+      (false, true) => NoInvalidVoidTypeMessage::NotReturnOrThisParam,
+      (true, true) => NoInvalidVoidTypeMessage::NotReturnOrThisParamOrGeneric,
+    }
+  }
+}
+
+impl Handler for NoInvalidVoidTypeHandler {
+  fn ts_keyword_type(
+    &mut self,
+    ts_keyword_type: &AstView::TsKeywordType,
+    ctx: &mut Context,
+  ) {
+    if ts_keyword_type.keyword_kind() != TsKeywordTypeKind::TsVoidKeyword {
+      return;
+    }
+
+    let (_, parent) = skip_paren_ancestors(ts_keyword_type.into());
+    if let Some(AstView::Node::TsUnionType(union_type)) = parent {
+      // `void | never` is the same as `void`.
+      let is_void_or_never = |ty: &AstView::TsType| {
+        is_keyword(ty, TsKeywordTypeKind::TsVoidKeyword)
+          || is_keyword(ty, TsKeywordTypeKind::TsNeverKeyword)
+      };
+      let is_valid_union = union_type.types.iter().all(is_void_or_never)
+        && self.is_allowed(position(union_type.into()));
+      if !is_valid_union {
+        ctx.add_diagnostic_with_hint(
+          ts_keyword_type.span(),
+          CODE,
+          NoInvalidVoidTypeMessage::UnionConstituent,
+          HINT,
+        );
+      }
+      return;
+    }
+
+    if !self.is_allowed(position(ts_keyword_type.into())) {
+      ctx.add_diagnostic_with_hint(
+        ts_keyword_type.span(),
+        CODE,
+        self.message(),
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_invalid_void_type_valid() {
+    assert_lint_ok! {
+      NoInvalidVoidType,
+      "function foo(): void {}",
+      "function foo(): (void) {}",
+      "const foo = function(): void {};",
+      "const foo = (): void => {};",
+      "class Foo { bar(): void {} }",
+      "type Foo = () => void;",
+      "type Foo = new () => void;",
+      "interface Foo { bar(): void; (): void; new (): void; }",
+      "let foo: { bar(): void };",
+      "function foo(): void | never {}",
+      "type Foo = Promise<void>;",
+      "const foo = new Promise<void>(() => {});",
+      "type Foo<T = void> = T;",
+      "let foo: Map<string, (() => void)>;",
+      "let foo: Array<void>;",
+    };
+
+    assert_lint_ok! {
+      NoInvalidVoidType,
+      options: { "allowAsThisParameter": true },
+      "function foo(this: void) {}",
+      "type Foo = (this: void) => void;",
+    };
+  }
+
+  #[test]
+  fn no_invalid_void_type_invalid() {
+    assert_lint_err! {
+      NoInvalidVoidType,
+      "let foo: void;": [
+        {
+          col: 9,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "function foo(bar: void) {}": [
+        {
+          col: 18,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "interface Foo { bar: void; }": [
+        {
+          col: 21,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "let foo: void[];": [
+        {
+          col: 9,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "type Foo<T extends void> = T;": [
+        {
+          col: 19,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "function foo(this: void) {}": [
+        {
+          col: 19,
+          message: NoInvalidVoidTypeMessage::NotReturnOrGeneric,
+          hint: HINT,
+        }
+      ],
+      "type Foo = string | void;": [
+        {
+          col: 20,
+          message: NoInvalidVoidTypeMessage::UnionConstituent,
+          hint: HINT,
+        }
+      ],
+      "function foo(): string | void {}": [
+        {
+          col: 25,
+          message: NoInvalidVoidTypeMessage::UnionConstituent,
+          hint: HINT,
+        }
+      ],
+      "let foo: void | never;": [
+        {
+          col: 9,
+          message: NoInvalidVoidTypeMessage::UnionConstituent,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoInvalidVoidType,
+      options: { "allowInGenericTypeArguments": false },
+      "type Foo = Promise<void>;": [
+        {
+          col: 19,
+          message: NoInvalidVoidTypeMessage::NotReturn,
+          hint: HINT,
+        }
+      ],
+      "type Foo<T = void> = T;": [
+        {
+          col: 13,
+          message: NoInvalidVoidTypeMessage::NotReturn,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoInvalidVoidType,
+      options: { "allowAsThisParameter": true },
+      "let foo: void;": [
+        {
+          col: 9,
+          message: NoInvalidVoidTypeMessage::NotReturnOrThisParamOrGeneric,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoInvalidVoidType,
+      options: { "allowAsThisParameter": true, "allowInGenericTypeArguments": false },
+      "let foo: Promise<void>;": [
+        {
+          col: 17,
+          message: NoInvalidVoidTypeMessage::NotReturnOrThisParam,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,119 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::static_member_prop_name;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoIterator;
+
+const CODE: &str = "no-iterator";
+const MESSAGE: &str =
+  "The `__iterator__` property is obsolete and not supported in Deno";
+const HINT: &str = "Use `Symbol.iterator` instead, e.g. `obj[Symbol.iterator]`";
+
+impl LintRule for NoIterator {
+  fn new() -> Box<Self> {
+    Box::new(NoIterator)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoIteratorHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `__iterator__` property
+
+`__iterator__` was a non-standard extension in old versions of Firefox to
+make objects iterable. It never became part of the language and has no effect
+in Deno. The standard way is to define a `Symbol.iterator` method.
+
+### Invalid:
+```typescript
+Foo.prototype.__iterator__ = function () {
+  return new FooIterator(this);
+};
+foo.__iterator__ = function () {};
+foo["__iterator__"] = function () {};
+```
+
+### Valid:
+```typescript
+Foo.prototype[Symbol.iterator] = function () {
+  return new FooIterator(this);
+};
+const __iterator__ = foo; // not a property access
+```
+"#
+  }
+}
+
+struct NoIteratorHandler;
+
+impl Handler for NoIteratorHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &AstView::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if static_member_prop_name(member_expr.inner).as_deref()
+      == Some("__iterator__")
+    {
+      ctx.add_diagnostic_with_hint(member_expr.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_iterator_valid() {
+    assert_lint_ok! {
+      NoIterator,
+      "const a = test[__iterator__];",
+      "const __iterator__ = foo;",
+      "foo[Symbol.iterator] = function() {};",
+      "foo.iterator = function() {};",
+      "const a = { __iterator__: foo };",
+    };
+  }
+
+  #[test]
+  fn no_iterator_invalid() {
+    assert_lint_err! {
+      NoIterator,
+      "const a = test.__iterator__;": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "Foo.prototype.__iterator__ = function() {};": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "const a = test['__iterator__'];": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "const a = test[`__iterator__`];": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "test[`__iterator__`] = function() {};": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "for (const x of foo.__iterator__()) {}": [
+        { col: 16, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,248 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoLabels {
+  options: NoLabelsOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoLabelsOptions {
+  /// Allows labels on `for`, `for...in`, `for...of`, `while` and
+  /// `do...while` loops.
+  allow_loop: bool,
+  /// Allows labels on `switch` statements.
+  allow_switch: bool,
+}
+
+const CODE: &str = "no-labels";
+const HINT: &str = "Labels make the control flow hard to follow; extract the code into a function and use `return`, or use a flag variable instead";
+
+#[derive(Display)]
+enum NoLabelsMessage {
+  #[display(fmt = "Unexpected label `{}`", _0)]
+  Unexpected(String),
+}
+
+impl LintRule for NoLabels {
+  fn new() -> Box<Self> {
+    Box::new(NoLabels::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoLabelsHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows labeled statements
+
+Labels together with `break` and `continue` make it possible to jump out of
+nested blocks, which makes the control flow hard to follow. Unlike
+`no-unused-labels`, this rule reports all labels, used or not.
+
+Labels on loops are allowed with `allowLoop`, and labels on `switch`
+statements with `allowSwitch`. Both are `false` by default.
+
+### Invalid:
+```typescript
+outer: for (const row of rows) {
+  for (const cell of row) {
+    if (cell === target) break outer;
+  }
+}
+
+block: {
+  if (done) break block;
+  work();
+}
+```
+
+### Valid:
+```typescript
+function find(rows, target) {
+  for (const row of rows) {
+    for (const cell of row) {
+      if (cell === target) return cell;
+    }
+  }
+}
+
+// with { "allowLoop": true }
+outer: for (const row of rows) {
+  for (const cell of row) {
+    if (cell === target) break outer;
+  }
+}
+```
+"#
+  }
+}
+
+struct NoLabelsHandler {
+  options: NoLabelsOptions,
+}
+
+impl NoLabelsHandler {
+  fn is_allowed(&self, body: &AstView::Stmt) -> bool {
+    match body {
+      AstView::Stmt::For(_)
+      | AstView::Stmt::ForIn(_)
+      | AstView::Stmt::ForOf(_)
+      | AstView::Stmt::While(_)
+      | AstView::Stmt::DoWhile(_) => self.options.allow_loop,
+      AstView::Stmt::Switch(_) => self.options.allow_switch,
+      _ => false,
+    }
+  }
+}
+
+impl Handler for NoLabelsHandler {
+  fn labeled_stmt(
+    &mut self,
+    labeled_stmt: &AstView::LabeledStmt,
+    ctx: &mut Context,
+  ) {
+    if !self.is_allowed(&labeled_stmt.body) {
+      ctx.add_diagnostic_with_hint(
+        labeled_stmt.label.span(),
+        CODE,
+        NoLabelsMessage::Unexpected(labeled_stmt.label.sym().to_string()),
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_labels_valid() {
+    assert_lint_ok! {
+      NoLabels,
+      "for (const x of xs) { break; }",
+      "while (true) { continue; }",
+      "switch (a) { case 0: break; }",
+      "const label = 1;",
+      "const obj = { label: 1 };",
+    };
+
+    assert_lint_ok! {
+      NoLabels,
+      options: { "allowLoop": true },
+      "A: for (const x of xs) { break A; }",
+      "A: for (const x in xs) { continue A; }",
+      "A: for (;;) { break A; }",
+      "A: while (true) { break A; }",
+      "A: do { break A; } while (true);",
+    };
+
+    assert_lint_ok! {
+      NoLabels,
+      options: { "allowSwitch": true },
+      "A: switch (a) { case 0: break A; }",
+    };
+  }
+
+  #[test]
+  fn no_labels_invalid() {
+    assert_lint_err! {
+      NoLabels,
+      "A: for (const x of xs) { break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+      "A: { break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+      "A: switch (a) { case 0: break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+      "A: while (true) { B: while (true) { break A; } }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        },
+        {
+          col: 18,
+          message: variant!(NoLabelsMessage, Unexpected, "B"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoLabels,
+      options: { "allowLoop": true },
+      "A: switch (a) { case 0: break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+      "A: if (a) { break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoLabels,
+      options: { "allowSwitch": true },
+      "A: while (true) { break A; }": [
+        {
+          col: 0,
+          message: variant!(NoLabelsMessage, Unexpected, "A"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
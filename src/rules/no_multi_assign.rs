@@ -0,0 +1,137 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::Spanned;
+
+pub struct NoMultiAssign;
+
+const CODE: &str = "no-multi-assign";
+const MESSAGE: &str = "Unexpected chained assignment";
+const HINT: &str = "Split it into separate assignments, one per target";
+
+impl LintRule for NoMultiAssign {
+  fn new() -> Box<Self> {
+    Box::new(NoMultiAssign)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoMultiAssignHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows chained assignments
+
+Chaining assignments like `a = b = c` is hard to read, and in declarations it
+can create a global variable unexpectedly: `const a = b = 1` only declares
+`a`, while `b` is assigned as a global if it's not declared elsewhere.
+
+### Invalid:
+```typescript
+a = b = c;
+const d = e = f;
+class G {
+  h = i = j;
+}
+```
+
+### Valid:
+```typescript
+b = c;
+a = b;
+
+e = f;
+const d = e;
+```
+"#
+  }
+}
+
+struct NoMultiAssignHandler;
+
+impl Handler for NoMultiAssignHandler {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &AstView::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    // `a = (b = c)` is still a chain.
+    let parent = assign_expr
+      .ancestors()
+      .find(|ancestor| !matches!(ancestor, AstView::Node::ParenExpr(_)));
+
+    let is_chained = matches!(
+      parent,
+      Some(AstView::Node::AssignExpr(_))
+        | Some(AstView::Node::VarDeclarator(_))
+        | Some(AstView::Node::ClassProp(_))
+        | Some(AstView::Node::PrivateProp(_))
+    );
+
+    if is_chained {
+      ctx.add_diagnostic_with_hint(assign_expr.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_multi_assign_valid() {
+    assert_lint_ok! {
+      NoMultiAssign,
+      "a = b;",
+      "const a = b;",
+      "let a; a = b;",
+      "const a = 1, b = 2;",
+      "for (let a = 0, b = 0; a < b; a++) {}",
+      "a = b; b = c;",
+      "const a = () => b = c;",
+      "const a = function() { b = c; };",
+      "const a = foo(b = c);",
+      "const a = [b = c];",
+      "const { a = 1 } = b;",
+      "class A { b = 1; }",
+      "a = b ? c = 1 : d;",
+    };
+  }
+
+  #[test]
+  fn no_multi_assign_invalid() {
+    assert_lint_err! {
+      NoMultiAssign,
+      "a = b = c;": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "a = b = c = d;": [
+        { col: 4, message: MESSAGE, hint: HINT },
+        { col: 8, message: MESSAGE, hint: HINT },
+      ],
+      "a = (b = c);": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "a += b = c;": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "const a = b = c;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "let a, b = c = d;": [{ col: 11, message: MESSAGE, hint: HINT }],
+      "var a = b = c, d = e = f;": [
+        { col: 8, message: MESSAGE, hint: HINT },
+        { col: 19, message: MESSAGE, hint: HINT },
+      ],
+      "class A { b = c = d; }": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "class A { #b = c = d; }": [{ col: 15, message: MESSAGE, hint: HINT }],
+      "for (let a = b = 0; a < 1; a++) {}": [
+        { col: 13, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
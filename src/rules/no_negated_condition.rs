@@ -0,0 +1,167 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, UnaryOp};
+use swc_common::Spanned;
+
+pub struct NoNegatedCondition;
+
+const CODE: &str = "no-negated-condition";
+const MESSAGE: &str = "Unexpected negated condition";
+const HINT: &str =
+  "Remove the negation and swap the branches, e.g. `if (x) { b } else { a }`";
+
+impl LintRule for NoNegatedCondition {
+  fn new() -> Box<Self> {
+    Box::new(NoNegatedCondition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoNegatedConditionHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows negated conditions in `if` statements with an `else` branch and in ternaries
+
+A negated condition with both branches present makes the reader flip the
+logic in their head. Removing the negation and swapping the branches says the
+same thing more directly.
+
+`if` statements without an `else` branch and `else if` chains are not
+reported.
+
+### Invalid:
+```typescript
+if (!a) {
+  foo();
+} else {
+  bar();
+}
+
+if (a !== b) {
+  foo();
+} else {
+  bar();
+}
+
+const c = !d ? foo : bar;
+const e = f != g ? foo : bar;
+```
+
+### Valid:
+```typescript
+if (a) {
+  bar();
+} else {
+  foo();
+}
+
+if (!a) {
+  foo();
+}
+
+if (!a) {
+  foo();
+} else if (b) {
+  bar();
+}
+
+const c = d ? bar : foo;
+```
+"#
+  }
+}
+
+fn is_negated(test: &AstView::Expr) -> bool {
+  match test {
+    AstView::Expr::Unary(unary_expr) => unary_expr.op() == UnaryOp::Bang,
+    AstView::Expr::Bin(bin_expr) => {
+      matches!(bin_expr.op(), BinaryOp::NotEq | BinaryOp::NotEqEq)
+    }
+    _ => false,
+  }
+}
+
+struct NoNegatedConditionHandler;
+
+impl Handler for NoNegatedConditionHandler {
+  fn if_stmt(&mut self, if_stmt: &AstView::IfStmt, ctx: &mut Context) {
+    match if_stmt.alt {
+      // `else if` chains read naturally even with a negated first condition.
+      None | Some(AstView::Stmt::If(_)) => return,
+      Some(_) => {}
+    }
+
+    if is_negated(&if_stmt.test) {
+      ctx.add_diagnostic_with_hint(if_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn cond_expr(&mut self, cond_expr: &AstView::CondExpr, ctx: &mut Context) {
+    if is_negated(&cond_expr.test) {
+      ctx.add_diagnostic_with_hint(cond_expr.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_negated_condition_valid() {
+    assert_lint_ok! {
+      NoNegatedCondition,
+      "if (a) {}",
+      "if (a) {} else {}",
+      "if (!a) {}",
+      "if (!a) {} else if (b) {}",
+      "if (!a) {} else if (b) {} else {}",
+      "if (a == b) {} else {}",
+      "if (a === b) {} else {}",
+      "if (a > b) {} else {}",
+      "if (!a && !b) {} else {}",
+      "if (!(a && b)) {}",
+      "const c = a ? b : c;",
+      "const c = a === b ? c : d;",
+      "const c = !a && b ? c : d;",
+    };
+  }
+
+  #[test]
+  fn no_negated_condition_invalid() {
+    assert_lint_err! {
+      NoNegatedCondition,
+      "if (!a) {;} else {;}": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "if (a != b) {;} else {;}": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "if (a !== b) {;} else {;}": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "if (!(a && b)) {;} else {;}": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "if (a) {;} else if (!b) {;} else {;}": [
+        { col: 16, message: MESSAGE, hint: HINT }
+      ],
+      "!a ? b : c": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const d = a != b ? c : d;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const d = a !== b ? c : d;": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "const d = !a ? !b ? c : d : e;": [
+        { col: 10, message: MESSAGE, hint: HINT },
+        { col: 15, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
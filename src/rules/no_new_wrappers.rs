@@ -0,0 +1,147 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoNewWrappers;
+
+const CODE: &str = "no-new-wrappers";
+
+#[derive(Display)]
+enum NoNewWrappersMessage {
+  #[display(fmt = "Do not use `{}` as a constructor", _0)]
+  Wrapper(String),
+}
+
+#[derive(Display)]
+enum NoNewWrappersHint {
+  #[display(
+    fmt = "Call `{}()` without `new` to convert a value, or use a literal instead",
+    _0
+  )]
+  RemoveNew(String),
+}
+
+const WRAPPERS: [&str; 3] = ["String", "Number", "Boolean"];
+
+impl LintRule for NoNewWrappers {
+  fn new() -> Box<Self> {
+    Box::new(NoNewWrappers)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoNewWrappersHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows creating primitive wrapper objects with `new`
+
+`new String()`, `new Number()` and `new Boolean()` create objects instead of
+primitive values. These behave surprisingly: `new Boolean(false)` is truthy,
+`typeof new Number(1)` is `"object"` and two wrappers of the same value are
+never `===` to each other. Calling the functions without `new` converts the
+value to a primitive instead.
+
+### Invalid:
+```typescript
+const str = new String("foo");
+const num = new Number(input);
+const bool = new Boolean(false);
+```
+
+### Valid:
+```typescript
+const str = "foo";
+const num = Number(input);
+const bool = false;
+```
+"#
+  }
+}
+
+struct NoNewWrappersHandler;
+
+impl Handler for NoNewWrappersHandler {
+  fn new_expr(&mut self, new_expr: &AstView::NewExpr, ctx: &mut Context) {
+    if let AstView::Expr::Ident(ident) = &new_expr.callee {
+      let name = ident.sym().to_string();
+      if WRAPPERS.contains(&name.as_str()) && is_global(ident, ctx.scope()) {
+        ctx.add_diagnostic_with_hint(
+          new_expr.span(),
+          CODE,
+          NoNewWrappersMessage::Wrapper(name.clone()),
+          NoNewWrappersHint::RemoveNew(name),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_new_wrappers_valid() {
+    assert_lint_ok! {
+      NoNewWrappers,
+      "const str = String(foo);",
+      "const num = Number(foo);",
+      "const bool = Boolean(foo);",
+      "const obj = new Object();",
+      "const str = new foo.String('bar');",
+      "function foo(String) { return new String('bar'); }",
+      "import { Number } from './number.ts'; new Number(1);",
+    };
+  }
+
+  #[test]
+  fn no_new_wrappers_invalid() {
+    assert_lint_err! {
+      NoNewWrappers,
+      "const str = new String('foo');": [
+        {
+          col: 12,
+          message: variant!(NoNewWrappersMessage, Wrapper, "String"),
+          hint: variant!(NoNewWrappersHint, RemoveNew, "String"),
+        }
+      ],
+      "const num = new Number(foo);": [
+        {
+          col: 12,
+          message: variant!(NoNewWrappersMessage, Wrapper, "Number"),
+          hint: variant!(NoNewWrappersHint, RemoveNew, "Number"),
+        }
+      ],
+      "const bool = new Boolean;": [
+        {
+          col: 13,
+          message: variant!(NoNewWrappersMessage, Wrapper, "Boolean"),
+          hint: variant!(NoNewWrappersHint, RemoveNew, "Boolean"),
+        }
+      ],
+      "function foo() { if (new Boolean(false)) {} }": [
+        {
+          col: 21,
+          message: variant!(NoNewWrappersMessage, Wrapper, "Boolean"),
+          hint: variant!(NoNewWrappersHint, RemoveNew, "Boolean"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,268 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use derive_more::Display;
+use swc_ecmascript::ast::{
+  ClassProp, Expr, Ident, MemberExpr, Prop, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct NoNodeGlobals;
+
+const CODE: &str = "no-node-globals";
+
+#[derive(Display)]
+enum NoNodeGlobalsMessage {
+  #[display(fmt = "`{}` is a Node.js global and isn't available in Deno", _0)]
+  NodeGlobal(String),
+}
+
+#[derive(Display)]
+enum NoNodeGlobalsHint {
+  #[display(
+    fmt = "Use `Deno.env`, `Deno.args`, `Deno.cwd()` or `Deno.exit()` instead"
+  )]
+  Process,
+  #[display(
+    fmt = "Use `Uint8Array` with `TextEncoder`/`TextDecoder`, or import `Buffer` from the standard library's `node/buffer.ts`"
+  )]
+  Buffer,
+  #[display(fmt = "Use `new URL(\".\", import.meta.url).pathname` instead")]
+  Dirname,
+  #[display(fmt = "Use `new URL(import.meta.url).pathname` instead")]
+  Filename,
+  #[display(fmt = "Use an ES module `import` declaration instead")]
+  Require,
+  #[display(fmt = "Use ES module `export` declarations instead")]
+  Module,
+}
+
+fn hint_for(name: &str) -> Option<NoNodeGlobalsHint> {
+  use NoNodeGlobalsHint::*;
+  let hint = match name {
+    "process" => Process,
+    "Buffer" => Buffer,
+    "__dirname" => Dirname,
+    "__filename" => Filename,
+    "require" => Require,
+    "module" => Module,
+    _ => return None,
+  };
+  Some(hint)
+}
+
+impl LintRule for NoNodeGlobals {
+  fn new() -> Box<Self> {
+    Box::new(NoNodeGlobals)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoNodeGlobalsVisitor { context };
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows references to globals that only exist in Node.js
+
+`process`, `Buffer`, `__dirname`, `__filename`, `require` and `module` are
+provided by Node.js but not by Deno, so code using them throws a
+`ReferenceError` at runtime. Deno offers its own APIs or standard library
+modules for all of them.
+
+Locally declared bindings with the same names, as well as feature checks like
+`typeof process`, are not reported.
+
+### Invalid:
+```typescript
+const port = process.env.PORT;
+const data = Buffer.from("hello");
+const config = require("./config.json");
+const dir = __dirname;
+module.exports = { foo };
+```
+
+### Valid:
+```typescript
+const port = Deno.env.get("PORT");
+const data = new TextEncoder().encode("hello");
+import config from "./config.json" assert { type: "json" };
+const dir = new URL(".", import.meta.url).pathname;
+export { foo };
+
+import process from "https://deno.land/std/node/process.ts";
+process.cwd();
+```
+"#
+  }
+}
+
+struct NoNodeGlobalsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoNodeGlobalsVisitor<'c, 'view> {
+  fn check(&mut self, ident: &Ident) {
+    let hint = match hint_for(&ident.sym) {
+      Some(hint) => hint,
+      None => return,
+    };
+
+    // Skip local bindings like `function foo(process) {}` or
+    // `import { Buffer } from "..."`.
+    if self.context.scope().var(&ident.to_id()).is_some() {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      ident.span,
+      CODE,
+      NoNodeGlobalsMessage::NodeGlobal(ident.sym.to_string()),
+      hint,
+    );
+  }
+}
+
+impl<'c, 'view> Visit for NoNodeGlobalsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+    e.obj.visit_with(e, self);
+    if e.computed {
+      e.prop.visit_with(e, self);
+    }
+  }
+
+  fn visit_unary_expr(&mut self, e: &UnaryExpr, _: &dyn Node) {
+    // `typeof process !== "undefined"` is a deliberate feature check.
+    if e.op == UnaryOp::TypeOf {
+      return;
+    }
+
+    e.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+    e.visit_children_with(self);
+
+    if let Expr::Ident(ident) = e {
+      self.check(ident)
+    }
+  }
+
+  fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
+    p.value.visit_with(p, self)
+  }
+
+  fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+    p.visit_children_with(self);
+
+    if let Prop::Shorthand(i) = &p {
+      self.check(i);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_node_globals_valid() {
+    assert_lint_ok! {
+      NoNodeGlobals,
+      "Deno.env.get('PORT');",
+      "foo.process.env;",
+      "foo.require('bar');",
+      "const obj = { process: 1, Buffer: 2 };",
+      "class Foo { module = 1; }",
+      "function foo(process) { process.exit(); }",
+      "const require = createRequire(import.meta.url); require('foo');",
+      "import { Buffer } from 'https://deno.land/std/node/buffer.ts'; Buffer.from('foo');",
+      "import process from 'https://deno.land/std/node/process.ts'; process.cwd();",
+      "let module; module = {};",
+      "if (typeof process !== 'undefined') {}",
+      "const pid: NodeJS.Process = foo;",
+    };
+  }
+
+  #[test]
+  fn no_node_globals_invalid() {
+    assert_lint_err! {
+      NoNodeGlobals,
+      "const port = process.env.PORT;": [
+        {
+          col: 13,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "process"),
+          hint: NoNodeGlobalsHint::Process,
+        }
+      ],
+      "const data = Buffer.from('hello');": [
+        {
+          col: 13,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "Buffer"),
+          hint: NoNodeGlobalsHint::Buffer,
+        }
+      ],
+      "const dir = __dirname;": [
+        {
+          col: 12,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "__dirname"),
+          hint: NoNodeGlobalsHint::Dirname,
+        }
+      ],
+      "console.log(__filename);": [
+        {
+          col: 12,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "__filename"),
+          hint: NoNodeGlobalsHint::Filename,
+        }
+      ],
+      "const fs = require('fs');": [
+        {
+          col: 11,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "require"),
+          hint: NoNodeGlobalsHint::Require,
+        }
+      ],
+      "module.exports = { foo };": [
+        {
+          col: 0,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "module"),
+          hint: NoNodeGlobalsHint::Module,
+        }
+      ],
+      "const obj = { process };": [
+        {
+          col: 14,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "process"),
+          hint: NoNodeGlobalsHint::Process,
+        }
+      ],
+      "foo[Buffer];": [
+        {
+          col: 4,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "Buffer"),
+          hint: NoNodeGlobalsHint::Buffer,
+        }
+      ],
+      "function foo(process) {} process.exit(1);": [
+        {
+          col: 25,
+          message: variant!(NoNodeGlobalsMessage, NodeGlobal, "process"),
+          hint: NoNodeGlobalsHint::Process,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,180 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::static_member_prop_name;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoProto;
+
+const CODE: &str = "no-proto";
+const MESSAGE: &str = "The `__proto__` property is deprecated";
+
+#[derive(Display)]
+enum NoProtoHint {
+  #[display(fmt = "Use `Object.getPrototypeOf` instead")]
+  Get,
+  #[display(fmt = "Use `Object.setPrototypeOf` instead")]
+  Set,
+  #[display(
+    fmt = "Use `Object.create` or `Object.setPrototypeOf` to set the prototype instead"
+  )]
+  ObjectLit,
+}
+
+impl LintRule for NoProto {
+  fn new() -> Box<Self> {
+    Box::new(NoProto)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoProtoHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `__proto__` property
+
+`__proto__` is a legacy accessor for the prototype of an object. It's only
+kept for compatibility, isn't available on objects that don't inherit from
+`Object.prototype`, and is deprecated. `Object.getPrototypeOf` and
+`Object.setPrototypeOf` do the same thing reliably.
+
+### Invalid:
+```typescript
+const a = obj.__proto__;
+obj["__proto__"] = b;
+const c = { __proto__: d };
+```
+
+### Valid:
+```typescript
+const a = Object.getPrototypeOf(obj);
+Object.setPrototypeOf(obj, b);
+const c = Object.create(d);
+```
+"#
+  }
+}
+
+struct NoProtoHandler;
+
+impl Handler for NoProtoHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &AstView::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if static_member_prop_name(member_expr.inner).as_deref()
+      != Some("__proto__")
+    {
+      return;
+    }
+
+    let is_assigned = matches!(
+      member_expr.parent,
+      AstView::Node::AssignExpr(assign_expr)
+        if assign_expr.left.span() == member_expr.span()
+    );
+    let hint = if is_assigned {
+      NoProtoHint::Set
+    } else {
+      NoProtoHint::Get
+    };
+    ctx.add_diagnostic_with_hint(member_expr.span(), CODE, MESSAGE, hint);
+  }
+
+  fn key_value_prop(
+    &mut self,
+    key_value_prop: &AstView::KeyValueProp,
+    ctx: &mut Context,
+  ) {
+    // `{ ["__proto__"]: foo }` defines an own property, so it's fine.
+    let is_proto = match &key_value_prop.key {
+      AstView::PropName::Ident(ident) => *ident.sym() == *"__proto__",
+      AstView::PropName::Str(s) => *s.value() == *"__proto__",
+      _ => false,
+    };
+
+    if is_proto {
+      ctx.add_diagnostic_with_hint(
+        key_value_prop.key.span(),
+        CODE,
+        MESSAGE,
+        NoProtoHint::ObjectLit,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_proto_valid() {
+    assert_lint_ok! {
+      NoProto,
+      "const a = Object.getPrototypeOf(obj);",
+      "Object.setPrototypeOf(obj, b);",
+      "const a = obj.proto;",
+      "const a = obj[__proto__];",
+      "const a = obj['proto'];",
+      "const a = { ['__proto__']: b };",
+      "const a = { proto: b };",
+      "const __proto__ = 1;",
+      "const a = __proto__;",
+      "const { __proto__: a } = obj;",
+      "class A { __proto__() {} }",
+    };
+  }
+
+  #[test]
+  fn no_proto_invalid() {
+    assert_lint_err! {
+      NoProto,
+      "const a = obj.__proto__;": [
+        { col: 10, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "const a = obj['__proto__'];": [
+        { col: 10, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "const a = obj[`__proto__`];": [
+        { col: 10, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "obj.__proto__.foo();": [
+        { col: 0, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "obj.__proto__ = b;": [
+        { col: 0, message: MESSAGE, hint: NoProtoHint::Set }
+      ],
+      "obj['__proto__'] = b;": [
+        { col: 0, message: MESSAGE, hint: NoProtoHint::Set }
+      ],
+      "a = obj.__proto__;": [
+        { col: 4, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "obj.__proto__.foo = b;": [
+        { col: 0, message: MESSAGE, hint: NoProtoHint::Get }
+      ],
+      "const a = { __proto__: b };": [
+        { col: 12, message: MESSAGE, hint: NoProtoHint::ObjectLit }
+      ],
+      "const a = { '__proto__': b };": [
+        { col: 12, message: MESSAGE, hint: NoProtoHint::ObjectLit }
+      ],
+    };
+  }
+}
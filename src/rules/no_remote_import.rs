@@ -0,0 +1,395 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{Expr, ExprOrSpread, Lit};
+
+#[derive(Default)]
+pub struct NoRemoteImport {
+  options: NoRemoteImportOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoRemoteImportOptions {
+  /// Hosts like `"deno.land"` or `"*.example.com"`, and URL patterns like
+  /// `"https://deno.land/std@*/"` that may be imported from.
+  allow: Vec<String>,
+}
+
+const CODE: &str = "no-remote-import";
+
+#[derive(Display)]
+enum NoRemoteImportMessage {
+  #[display(fmt = "Import from the remote host `{}` isn't allowed", _0)]
+  NotAllowed(String),
+}
+
+#[derive(Display)]
+enum NoRemoteImportHint {
+  #[display(
+    fmt = "Import it from an allowed source, or add `{}` to the `allow` option",
+    _0
+  )]
+  Allow(String),
+}
+
+impl LintRule for NoRemoteImport {
+  fn new() -> Box<Self> {
+    Box::new(NoRemoteImport::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoRemoteImportHandler {
+      options: &self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows imports from remote URLs that aren't explicitly allowed
+
+Deno can import modules directly from `http://` and `https://` URLs. This rule
+restricts such imports to trusted sources, e.g. to enforce a policy of only
+using the standard library and an internal CDN.
+
+Allowed sources are configured with the `allow` option. An entry without a
+scheme is matched against the host of the URL, like `"deno.land"`, and an entry
+with a scheme is matched against the beginning of the URL, like
+`"https://deno.land/std@*/"`. A URL pattern has to match up to a `/`, `?` or
+`#`, so `"https://deno.land"` allows all of `https://deno.land/` but not
+`https://deno.land.example.com/`. In both, `*` matches any sequence of
+characters other than `/`, e.g. `"*.example.com"`. Schemes and hosts are
+compared case-insensitively. Without any entry, all remote imports are
+reported.
+
+Static imports, re-exports and dynamic imports with a string literal are
+checked.
+
+### Invalid:
+```typescript
+// with { "allow": ["https://deno.land/std@*/", "cdn.example.com"] }
+import { serve } from "https://deno.land/x/foo/mod.ts";
+export * from "http://cdn.example.org/mod.ts";
+const mod = await import("https://esm.sh/lodash");
+```
+
+### Valid:
+```typescript
+// with { "allow": ["https://deno.land/std@*/", "cdn.example.com"] }
+import { serve } from "https://deno.land/std@0.95.0/http/server.ts";
+export * from "https://cdn.example.com/mod.ts";
+import { foo } from "./foo.ts";
+```
+"#
+  }
+}
+
+/// Splits a remote URL, i.e. one starting with `http://` or `https://` in any
+/// case, into its origin and the rest. The scheme and the host are case
+/// insensitive, so the origin is lowercased.
+fn split_remote_url(url: &str) -> Option<(String, &str)> {
+  let scheme_len = ["https://", "http://"]
+    .iter()
+    .find(|scheme| {
+      matches!(url.get(..scheme.len()), Some(s) if s.eq_ignore_ascii_case(scheme))
+    })?
+    .len();
+  let end = url[scheme_len..]
+    .find(&['/', '?', '#'][..])
+    .map_or(url.len(), |i| scheme_len + i);
+  Some((url[..end].to_ascii_lowercase(), &url[end..]))
+}
+
+/// Returns the host of an origin returned by `split_remote_url`.
+fn origin_host(origin: &str) -> &str {
+  let authority = origin
+    .split_once("://")
+    .map_or(origin, |(_, authority)| authority);
+  let host = authority.rsplit('@').next().unwrap_or(authority);
+  host.split(':').next().unwrap_or(host)
+}
+
+/// Checks if the text matches the pattern, where `*` matches any sequence of
+/// characters other than `/`.
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+  match pattern.split_once('*') {
+    None => pattern == text,
+    Some((head, tail)) => match text.strip_prefix(head) {
+      Some(rest) => (0..=rest.find('/').unwrap_or(rest.len()))
+        .filter(|i| rest.is_char_boundary(*i))
+        .any(|i| matches_pattern(tail, &rest[i..])),
+      None => false,
+    },
+  }
+}
+
+/// Checks if the URL starts with a match of the pattern that ends at a `/`,
+/// `?` or `#`, or at the end of the URL, so that `https://deno.land` doesn't
+/// match `https://deno.land.example.com`.
+fn matches_url_pattern(pattern: &str, url: &str) -> bool {
+  (0..=url.len())
+    .filter(|i| url.is_char_boundary(*i))
+    .any(|i| {
+      let (prefix, rest) = url.split_at(i);
+      let at_boundary = rest.is_empty()
+        || prefix.ends_with('/')
+        || rest.starts_with(&['/', '?', '#'][..]);
+      at_boundary && matches_pattern(pattern, prefix)
+    })
+}
+
+struct NoRemoteImportHandler<'a> {
+  options: &'a NoRemoteImportOptions,
+}
+
+impl<'a> NoRemoteImportHandler<'a> {
+  fn is_allowed(&self, url: &str, host: &str) -> bool {
+    self.options.allow.iter().any(|allowed| {
+      if allowed.contains("://") {
+        let pattern = match split_remote_url(allowed) {
+          Some((origin, rest)) => format!("{}{}", origin, rest),
+          None => allowed.clone(),
+        };
+        matches_url_pattern(&pattern, url)
+      } else {
+        matches_pattern(&allowed.to_ascii_lowercase(), host)
+      }
+    })
+  }
+
+  fn check(&self, span: Span, specifier: &str, ctx: &mut Context) {
+    if let Some((origin, rest)) = split_remote_url(specifier) {
+      let host = origin_host(&origin);
+      if !self.is_allowed(&format!("{}{}", origin, rest), host) {
+        ctx.add_diagnostic_with_hint(
+          span,
+          CODE,
+          NoRemoteImportMessage::NotAllowed(host.to_string()),
+          NoRemoteImportHint::Allow(host.to_string()),
+        );
+      }
+    }
+  }
+}
+
+impl<'a> Handler for NoRemoteImportHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &AstView::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    self.check(import_decl.src.span(), import_decl.src.value(), ctx);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &AstView::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if let Some(src) = named_export.src {
+      self.check(src.span(), src.value(), ctx);
+    }
+  }
+
+  fn export_all(&mut self, export_all: &AstView::ExportAll, ctx: &mut Context) {
+    self.check(export_all.src.span(), export_all.src.value(), ctx);
+  }
+
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let is_dynamic_import = matches!(
+      &call_expr.callee,
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(ident))
+        if *ident.sym() == *"import"
+    );
+    if !is_dynamic_import {
+      return;
+    }
+    if let Some(ExprOrSpread { spread: None, expr }) =
+      call_expr.inner.args.first()
+    {
+      match &**expr {
+        Expr::Lit(Lit::Str(str)) => self.check(str.span, &str.value, ctx),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+          if let Some(quasi) = tpl.quasis.first() {
+            self.check(tpl.span, &quasi.raw.value, ctx);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_remote_import_valid() {
+    assert_lint_ok! {
+      NoRemoteImport,
+      "import { foo } from './foo.ts';",
+      "import { foo } from '../foo/mod.ts';",
+      "import { foo } from 'file:///foo.ts';",
+      "export * from './foo.ts';",
+      "export { foo } from './foo.ts';",
+      "export { foo };",
+      "import(specifier);",
+      "import(`https://${host}/mod.ts`);",
+      "foo('https://deno.land/x/foo/mod.ts');",
+    };
+
+    assert_lint_ok! {
+      NoRemoteImport,
+      options: {
+        "allow": ["https://deno.land/std@*/", "cdn.example.com", "*.internal.dev"]
+      },
+      "import { serve } from 'https://deno.land/std@0.95.0/http/server.ts';",
+      "import { foo } from 'https://cdn.example.com/foo.ts';",
+      "import { foo } from 'http://cdn.example.com:8080/foo.ts';",
+      "import { foo } from 'https://user@cdn.example.com/foo.ts';",
+      "import { foo } from 'https://a.b.internal.dev/foo.ts';",
+      "export * from 'https://cdn.example.com/mod.ts';",
+      "export { foo } from 'https://cdn.example.com/mod.ts';",
+      "import('https://cdn.example.com/mod.ts');",
+      "import type { Foo } from 'https://cdn.example.com/types.ts';",
+      "import { foo } from 'HTTPS://CDN.Example.com/foo.ts';",
+      "import { serve } from 'HTTPS://Deno.Land/std@0.95.0/http/server.ts';",
+    };
+    assert_lint_ok! {
+      NoRemoteImport,
+      options: { "allow": ["https://deno.land", "https://*.example.com/"] },
+      "import { foo } from 'https://deno.land/x/foo/mod.ts';",
+      "import { foo } from 'https://deno.land?foo';",
+      "import { foo } from 'https://cdn.example.com/foo.ts';",
+    };
+  }
+
+  #[test]
+  fn no_remote_import_invalid() {
+    assert_lint_err! {
+      NoRemoteImport,
+      "import { foo } from 'https://deno.land/x/foo/mod.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "deno.land"),
+          hint: variant!(NoRemoteImportHint, Allow, "deno.land"),
+        }
+      ],
+      "import 'http://example.com/polyfill.js';": [
+        {
+          col: 7,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "example.com"),
+          hint: variant!(NoRemoteImportHint, Allow, "example.com"),
+        }
+      ],
+      "import { foo } from 'HTTPS://Evil.com/foo.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "evil.com"),
+          hint: variant!(NoRemoteImportHint, Allow, "evil.com"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRemoteImport,
+      options: {
+        "allow": ["https://deno.land/std@*/", "cdn.example.com", "*.internal.dev"]
+      },
+      "import { foo } from 'https://deno.land/x/foo/mod.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "deno.land"),
+          hint: variant!(NoRemoteImportHint, Allow, "deno.land"),
+        }
+      ],
+      "import { serve } from 'http://deno.land/std@0.95.0/http/server.ts';": [
+        {
+          col: 22,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "deno.land"),
+          hint: variant!(NoRemoteImportHint, Allow, "deno.land"),
+        }
+      ],
+      "export * from 'https://cdn.example.org/mod.ts';": [
+        {
+          col: 14,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "cdn.example.org"),
+          hint: variant!(NoRemoteImportHint, Allow, "cdn.example.org"),
+        }
+      ],
+      "export { foo } from 'https://evil.com/cdn.example.com/mod.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "evil.com"),
+          hint: variant!(NoRemoteImportHint, Allow, "evil.com"),
+        }
+      ],
+      "import { foo } from 'https://internal.dev/foo.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "internal.dev"),
+          hint: variant!(NoRemoteImportHint, Allow, "internal.dev"),
+        }
+      ],
+      "const mod = await import('https://esm.sh/lodash');": [
+        {
+          col: 25,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "esm.sh"),
+          hint: variant!(NoRemoteImportHint, Allow, "esm.sh"),
+        }
+      ],
+      "import(`https://esm.sh/lodash`);": [
+        {
+          col: 7,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "esm.sh"),
+          hint: variant!(NoRemoteImportHint, Allow, "esm.sh"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRemoteImport,
+      options: { "allow": ["https://deno.land", "https://*.example.com/"] },
+      "import { foo } from 'https://deno.land.evil.com/mod.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "deno.land.evil.com"),
+          hint: variant!(NoRemoteImportHint, Allow, "deno.land.evil.com"),
+        }
+      ],
+      "import { foo } from 'https://evil.com/cdn.example.com/mod.ts';": [
+        {
+          col: 20,
+          message: variant!(NoRemoteImportMessage, NotAllowed, "evil.com"),
+          hint: variant!(NoRemoteImportHint, Allow, "evil.com"),
+        }
+      ],
+    };
+  }
+}
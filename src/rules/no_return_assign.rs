@@ -0,0 +1,306 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoReturnAssign {
+  mode: NoReturnAssignMode,
+}
+
+const CODE: &str = "no-return-assign";
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum NoReturnAssignMode {
+  /// Allows assignments that are explicitly wrapped in parentheses.
+  ExceptParens,
+  /// Disallows all assignments, parenthesized or not.
+  Always,
+}
+
+impl Default for NoReturnAssignMode {
+  fn default() -> Self {
+    NoReturnAssignMode::ExceptParens
+  }
+}
+
+#[derive(Display)]
+enum NoReturnAssignMessage {
+  #[display(fmt = "Return statement should not contain assignment")]
+  Return,
+  #[display(fmt = "Arrow function should not return assignment")]
+  Arrow,
+}
+
+#[derive(Display)]
+enum NoReturnAssignHint {
+  #[display(
+    fmt = "Move the assignment out of the return statement, or use a comparison operator if you meant to compare"
+  )]
+  Return,
+  #[display(
+    fmt = "Wrap the function body in braces, or use a comparison operator if you meant to compare"
+  )]
+  Arrow,
+}
+
+impl LintRule for NoReturnAssign {
+  fn new() -> Box<Self> {
+    Box::new(NoReturnAssign::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoReturnAssignHandler { mode: self.mode }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assignment operators in `return` statements and arrow function bodies
+
+It is easy to mistype `=` for `==` or `===`, and a `return` statement that
+assigns a value is hard to tell apart from one that compares. Arrow functions
+returning an assignment suffer from the same ambiguity.
+
+By default (`"except-parens"`), assignments that are explicitly wrapped in
+parentheses are allowed. Set the option to `"always"` to disallow them as well.
+
+### Invalid:
+```typescript
+function foo() {
+  return a = b;
+}
+
+const bar = (a, b) => a = b;
+```
+
+### Valid:
+```typescript
+function foo() {
+  return a === b;
+}
+
+function baz() {
+  a = b;
+  return a;
+}
+
+// allowed unless the option is set to "always"
+const bar = (a, b) => (a = b);
+```
+"#
+  }
+}
+
+struct NoReturnAssignHandler {
+  mode: NoReturnAssignMode,
+}
+
+impl Handler for NoReturnAssignHandler {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &AstView::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    if self.mode == NoReturnAssignMode::ExceptParens
+      && matches!(assign_expr.parent, AstView::Node::ParenExpr(_))
+    {
+      return;
+    }
+
+    let mut child = assign_expr.into_node();
+    while let Some(parent) = child.parent() {
+      use AstView::Node::*;
+      match parent {
+        ReturnStmt(return_stmt) => {
+          ctx.add_diagnostic_with_hint(
+            return_stmt.span(),
+            CODE,
+            NoReturnAssignMessage::Return,
+            NoReturnAssignHint::Return,
+          );
+          return;
+        }
+        ArrowExpr(arrow_expr) => {
+          if let AstView::BlockStmtOrExpr::Expr(body) = arrow_expr.body {
+            if body.span() == child.span() {
+              ctx.add_diagnostic_with_hint(
+                arrow_expr.span(),
+                CODE,
+                NoReturnAssignMessage::Arrow,
+                NoReturnAssignHint::Arrow,
+              );
+            }
+          }
+          return;
+        }
+        Function(_) | Class(_) | GetterProp(_) | SetterProp(_) => return,
+        _ => {}
+      }
+      child = parent;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_assign_valid() {
+    assert_lint_ok! {
+      NoReturnAssign,
+      "module.exports = {'a': 1};",
+      "var result = a * b;",
+      "function x() { var result = a * b; return result; }",
+      "function x() { return (result = a * b); }",
+      "function x() { var result = a * b; return; }",
+      "function x() { return function y() { result = a * b }; }",
+      "function x() { return class { foo() { result = a * b } }; }",
+      "function x() { return { get y() { a = b; return 1; } }; }",
+      "function x() { return { set y(v) { a = v; } }; }",
+      "() => { return (result = a * b); }",
+      "() => (result = a * b)",
+      "() => (a = b) => 0",
+      "function x() { return result || (result = a * b); }",
+      "const foo = (a = 1, b) => a === b",
+      "() => a === b",
+      "function x() { return a === b; }",
+    };
+
+    assert_lint_ok! {
+      NoReturnAssign,
+      options: "always",
+      "function x() { var result = a * b; return result; }",
+      "function x() { return function y() { result = a * b }; }",
+      "() => { result = a * b; }",
+      "() => a === b",
+    };
+  }
+
+  #[test]
+  fn no_return_assign_invalid() {
+    assert_lint_err! {
+      NoReturnAssign,
+      "function x() { return result = a * b; };": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "function x() { return result += a * b; };": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "function x() { return (a = b, c); }": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "function x() { return a = b, c }": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "() => { return result = a * b; }": [
+        {
+          col: 8,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "() => result = a * b": [
+        {
+          col: 0,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::Arrow,
+        }
+      ],
+      "const foo = (a, b, c) => (a = b, c == b)": [
+        {
+          col: 12,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::Arrow,
+        }
+      ],
+      "() => () => a = b": [
+        {
+          col: 6,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::Arrow,
+        }
+      ],
+      r#"
+function x() {
+  if (foo) {
+    return bar = baz;
+  }
+}
+      "#: [
+        {
+          line: 4,
+          col: 4,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoReturnAssign,
+      options: "always",
+      "function x() { return result || (result = a * b); }": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "function x() { return (result = a * b); };": [
+        {
+          col: 15,
+          message: NoReturnAssignMessage::Return,
+          hint: NoReturnAssignHint::Return,
+        }
+      ],
+      "() => (result = a * b)": [
+        {
+          col: 0,
+          message: NoReturnAssignMessage::Arrow,
+          hint: NoReturnAssignHint::Arrow,
+        }
+      ],
+    };
+  }
+}
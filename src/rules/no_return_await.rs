@@ -0,0 +1,324 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::enclosing_stmt_or_arrow_at_start;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, NodeTrait};
+use swc_common::Spanned;
+
+pub struct NoReturnAwait;
+
+const CODE: &str = "no-return-await";
+const MESSAGE: &str = "Redundant use of `await` on a return value";
+const HINT: &str = "Awaiting a Promise that's returned anyway only delays its settling by an extra microtask. Return the Promise directly";
+const FIX_DESC: &str = "Remove the `await`";
+
+impl LintRule for NoReturnAwait {
+  fn new() -> Box<Self> {
+    Box::new(NoReturnAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoReturnAwaitHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary `return await`
+
+The value returned from an `async` function is resolved anyway, so awaiting it
+right before returning doesn't change the result. It only keeps the function
+suspended for an extra microtask.
+
+Inside a `try` block, `return await` is meaningful, as it lets `catch` and
+`finally` handle a rejection, so it isn't reported there.
+
+### Invalid:
+```typescript
+async function foo() {
+  return await bar();
+}
+
+const baz = async () => await qux();
+```
+
+### Valid:
+```typescript
+async function foo() {
+  return bar();
+}
+
+async function baz() {
+  try {
+    return await qux();
+  } catch (e) {
+    return fallback;
+  }
+}
+```
+"#
+  }
+}
+
+/// Checks if the value of the node is what the enclosing `return` statement
+/// or arrow function returns, e.g. `bar` in `return foo ? bar : baz`.
+/// Returns the node that returns the value.
+fn returning_node<'a>(node: AstView::Node<'a>) -> Option<AstView::Node<'a>> {
+  let span = node.span();
+  let parent = node.parent()?;
+  match parent {
+    AstView::Node::ParenExpr(_) => returning_node(parent),
+    AstView::Node::CondExpr(cond_expr) if cond_expr.test.span() != span => {
+      returning_node(parent)
+    }
+    AstView::Node::BinExpr(bin_expr)
+      if matches!(
+        bin_expr.op(),
+        BinaryOp::LogicalAnd
+          | BinaryOp::LogicalOr
+          | BinaryOp::NullishCoalescing
+      ) && bin_expr.right.span() == span =>
+    {
+      returning_node(parent)
+    }
+    AstView::Node::SeqExpr(seq_expr)
+      if seq_expr.exprs.last().map(|expr| expr.span()) == Some(span) =>
+    {
+      returning_node(parent)
+    }
+    AstView::Node::ReturnStmt(_) | AstView::Node::ArrowExpr(_) => Some(parent),
+    _ => None,
+  }
+}
+
+/// Checks if the `return` statement is inside a `try` block, or a `catch`
+/// block followed by `finally`, of the enclosing function.
+fn is_in_try(return_stmt: AstView::Node) -> bool {
+  let mut child_span = return_stmt.span();
+  for ancestor in return_stmt.ancestors() {
+    match ancestor {
+      AstView::Node::TryStmt(try_stmt) => {
+        if try_stmt.block.span() == child_span {
+          return true;
+        }
+        let in_handler = try_stmt
+          .handler
+          .map_or(false, |handler| handler.span() == child_span);
+        if in_handler && try_stmt.finalizer.is_some() {
+          return true;
+        }
+      }
+      AstView::Node::Function(_) | AstView::Node::ArrowExpr(_) => return false,
+      _ => {}
+    }
+    child_span = ancestor.span();
+  }
+  false
+}
+
+struct NoReturnAwaitHandler;
+
+impl Handler for NoReturnAwaitHandler {
+  fn await_expr(&mut self, await_expr: &AstView::AwaitExpr, ctx: &mut Context) {
+    let returning = match returning_node(await_expr.into()) {
+      Some(node) => node,
+      None => return,
+    };
+    if matches!(returning, AstView::Node::ReturnStmt(_)) && is_in_try(returning)
+    {
+      return;
+    }
+
+    let span = await_expr.span();
+    let has_comments = ctx
+      .all_comments()
+      .any(|comment| span.contains(comment.span));
+    if has_comments {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      return;
+    }
+
+    // An arrow function body starting with an object literal, like in
+    // `{ a: 1 }.a`, would be parsed as a block without parentheses.
+    let arg = await_expr.arg.text();
+    let replacement = if matches!(
+      enclosing_stmt_or_arrow_at_start(await_expr.into()),
+      Some(AstView::Node::ArrowExpr(_))
+    ) && arg.starts_with('{')
+    {
+      format!("({})", arg)
+    } else {
+      arg.to_string()
+    };
+    ctx.add_diagnostic_with_fix(
+      span,
+      CODE,
+      MESSAGE,
+      HINT,
+      FIX_DESC,
+      vec![(span, replacement)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_await_valid() {
+    assert_lint_ok! {
+      NoReturnAwait,
+      "async function foo() { return bar(); }",
+      "async function foo() { const x = await bar(); return x; }",
+      "async function foo() { await bar(); return; }",
+      "async function foo() { return (await bar()).baz; }",
+      "async function foo() { return await bar() + 1; }",
+      "async function foo() { return [await bar()]; }",
+      "async function foo() { return (await bar()) ? a : b; }",
+      "async function foo() { return await a || b; }",
+      "async function foo() { return foo(await bar()); }",
+      "const foo = async () => bar();",
+      "const foo = async () => { await bar(); };",
+      "async function foo() { try { return await bar(); } catch (e) {} }",
+      "async function foo() { try { return await bar(); } finally { baz(); } }",
+      "async function foo() { try {} catch (e) { return await bar(); } finally { baz(); } }",
+      "async function foo() { try { if (a) { return await bar(); } } catch (e) {} }",
+      "async function foo() { try { return a ? await b : c; } catch (e) {} }",
+    };
+  }
+
+  #[test]
+  fn no_return_await_invalid() {
+    assert_lint_err! {
+      NoReturnAwait,
+      "async function foo() { return await bar(); }": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return bar(); }"),
+        }
+      ],
+      "const foo = async () => await bar();": [
+        {
+          col: 24,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const foo = async () => bar();"),
+        }
+      ],
+      "async function foo() { return (await bar()); }": [
+        {
+          col: 31,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return (bar()); }"),
+        }
+      ],
+      "async function foo() { return a ? await b : await c; }": [
+        {
+          col: 34,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return a ? b : await c; }"),
+        },
+        {
+          col: 44,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return a ? await b : c; }"),
+        }
+      ],
+      "async function foo() { return a || await b; }": [
+        {
+          col: 35,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return a || b; }"),
+        }
+      ],
+      "async function foo() { return (a, await b); }": [
+        {
+          col: 34,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return (a, b); }"),
+        }
+      ],
+      "async function foo() { try {} catch (e) { return await bar(); } }": [
+        {
+          col: 49,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { try {} catch (e) { return bar(); } }"),
+        }
+      ],
+      "async function foo() { try {} finally { return await bar(); } }": [
+        {
+          col: 47,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { try {} finally { return bar(); } }"),
+        }
+      ],
+      "async function foo() { try { const f = async () => { return await bar(); }; } catch (e) {} }": [
+        {
+          col: 60,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { try { const f = async () => { return bar(); }; } catch (e) {} }"),
+        }
+      ],
+      "const foo = async () => await { a };": [
+        {
+          col: 24,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const foo = async () => ({ a });"),
+        }
+      ],
+      "const foo = async () => await {a: 1}.a;": [
+        {
+          col: 24,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const foo = async () => ({a: 1}.a);"),
+        }
+      ],
+      "const foo = async () => bar || await {a: 1}.a;": [
+        {
+          col: 31,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const foo = async () => bar || {a: 1}.a;"),
+        }
+      ],
+      "async function foo() { return await {a: 1}.a; }": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function foo() { return {a: 1}.a; }"),
+        }
+      ],
+      "async function foo() { return await /* comment */ bar(); }": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,115 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::equal_tokens;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp};
+use swc_common::Spanned;
+
+pub struct NoSelfCompare;
+
+const CODE: &str = "no-self-compare";
+const MESSAGE: &str = "Comparing a value against itself is pointless";
+const HINT: &str = "If you're testing for `NaN`, use `Number.isNaN()` instead; otherwise one of the operands is likely a typo";
+
+impl LintRule for NoSelfCompare {
+  fn new() -> Box<Self> {
+    Box::new(NoSelfCompare)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoSelfCompareHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows comparisons where both sides are exactly the same
+
+Comparing a value against itself always gives the same result, except for
+`NaN`, which is the only value not equal to itself. Such a comparison is
+either a typo or an obscure way to check for `NaN`, which `Number.isNaN()`
+expresses much more clearly.
+
+### Invalid:
+```typescript
+if (x === x) {}
+if (a.b !== a.b) {}
+if (foo > foo) {}
+```
+
+### Valid:
+```typescript
+if (x === y) {}
+if (Number.isNaN(x)) {}
+if (a.b !== a.c) {}
+```
+"#
+  }
+}
+
+fn is_comparison(op: BinaryOp) -> bool {
+  use BinaryOp::*;
+  matches!(op, EqEq | NotEq | EqEqEq | NotEqEq | Lt | LtEq | Gt | GtEq)
+}
+
+struct NoSelfCompareHandler;
+
+impl Handler for NoSelfCompareHandler {
+  fn bin_expr(&mut self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    if is_comparison(bin_expr.op())
+      && equal_tokens(&bin_expr.left, &bin_expr.right)
+    {
+      ctx.add_diagnostic_with_hint(bin_expr.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_self_compare_valid() {
+    assert_lint_ok! {
+      NoSelfCompare,
+      "if (x === y) {}",
+      "if (a.b !== a.c) {}",
+      "if (foo() === bar()) {}",
+      "x + x;",
+      "x = x;",
+      "x === x.y;",
+      "x.y === x;",
+      "Number.isNaN(x);",
+      "'x' === x;",
+    };
+  }
+
+  #[test]
+  fn no_self_compare_invalid() {
+    assert_lint_err! {
+      NoSelfCompare,
+      "if (x === x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (x !== x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (x == x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (x != x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (x > x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (x <= x) {}": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "a.b !== a.b;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a[0] === a[ 0 ];": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo() === foo();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "function foo() { return bar === bar; }": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
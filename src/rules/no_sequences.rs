@@ -0,0 +1,226 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoSequences {
+  options: NoSequencesOptions,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoSequencesOptions {
+  /// Allows sequence expressions that are explicitly wrapped in parentheses.
+  allow_in_parentheses: bool,
+}
+
+impl Default for NoSequencesOptions {
+  fn default() -> Self {
+    Self {
+      allow_in_parentheses: true,
+    }
+  }
+}
+
+const CODE: &str = "no-sequences";
+const MESSAGE: &str = "Unexpected use of comma operator";
+const HINT: &str = "Split the expressions into separate statements";
+
+impl LintRule for NoSequences {
+  fn new() -> Box<Self> {
+    Box::new(NoSequences::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoSequencesHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the comma operator
+
+The comma operator evaluates each of its operands and returns the last one,
+which is rarely what's intended. In most cases it's an accident, e.g. a
+`return a, b;` that was supposed to return something else.
+
+Sequence expressions in the initialization and update parts of a `for`
+statement are allowed. Sequences explicitly wrapped in parentheses are allowed
+as well, unless `allowInParentheses` is set to `false`. In places where the
+syntax already requires parentheses (the test of `if`, `while`, `do-while`, the
+discriminant of `switch`, the object of `with`, and arrow function bodies), a
+second pair of parentheses is needed.
+
+### Invalid:
+```typescript
+foo = doSomething(), val;
+
+function bar() {
+  return a, b;
+}
+
+if (doSomething(), !!test) {}
+
+const baz = () => (a, b);
+```
+
+### Valid:
+```typescript
+foo = (doSomething(), val);
+
+for (let i = 0, j = 10; i < j; i++, j--) {}
+
+if ((doSomething(), !!test)) {}
+
+const baz = () => ((a, b));
+```
+"#
+  }
+}
+
+struct NoSequencesHandler {
+  options: NoSequencesOptions,
+}
+
+impl NoSequencesHandler {
+  fn is_allowed(&self, seq_expr: &AstView::SeqExpr) -> bool {
+    use AstView::Node::*;
+    match seq_expr.parent {
+      ForStmt(for_stmt) => {
+        let span = Some(seq_expr.span());
+        for_stmt.init.map(|init| init.span()) == span
+          || for_stmt.update.map(|update| update.span()) == span
+      }
+      ParenExpr(paren_expr) if self.options.allow_in_parentheses => {
+        // The parentheses of an arrow function body are needed just to make
+        // it an expression body, so the sequence has to be wrapped twice.
+        !matches!(
+          paren_expr.parent,
+          ArrowExpr(AstView::ArrowExpr {
+            body: AstView::BlockStmtOrExpr::Expr(body),
+            ..
+          }) if body.span() == paren_expr.span()
+        )
+      }
+      _ => false,
+    }
+  }
+}
+
+impl Handler for NoSequencesHandler {
+  fn seq_expr(&mut self, seq_expr: &AstView::SeqExpr, ctx: &mut Context) {
+    if self.is_allowed(seq_expr) {
+      return;
+    }
+
+    let span = seq_expr
+      .exprs
+      .first()
+      .and_then(|first| first.next_token())
+      .map_or_else(|| seq_expr.span(), |comma| comma.span);
+    ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_sequences_valid() {
+    assert_lint_ok! {
+      NoSequences,
+      "var arr = [1, 2];",
+      "var obj = {a: 1, b: 2};",
+      "var a = 1, b = 2;",
+      "var foo = (1, 2);",
+      "(0,eval)(\"foo()\");",
+      "for (i = 1, j = 2;; i++, j++);",
+      "foo(a, (b, c), d);",
+      "do {} while ((doSomething(), !!test));",
+      "for ((doSomething(), somethingElse()); (doSomething(), !!test); );",
+      "if ((doSomething(), !!test));",
+      "switch ((doSomething(), val)) {}",
+      "while ((doSomething(), !!test));",
+      "with ((doSomething(), val)) {}",
+      "a => ((doSomething(), a))",
+      "var foo = (1, 2), bar = (3, 4);",
+    };
+
+    assert_lint_ok! {
+      NoSequences,
+      options: { "allowInParentheses": false },
+      "for (i = 1, j = 2;; i++, j++);",
+      "var a = 1, b = 2;",
+    };
+  }
+
+  #[test]
+  fn no_sequences_invalid() {
+    assert_lint_err! {
+      NoSequences,
+      "1, 2;": [{ col: 1, message: MESSAGE, hint: HINT }],
+      "a = 1, 2": [{ col: 5, message: MESSAGE, hint: HINT }],
+      "do {} while (doSomething(), !!test);": [
+        { col: 26, message: MESSAGE, hint: HINT }
+      ],
+      "for (; doSomething(), !!test; );": [
+        { col: 20, message: MESSAGE, hint: HINT }
+      ],
+      "if (doSomething(), !!test);": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      "switch (doSomething(), val) {}": [
+        { col: 21, message: MESSAGE, hint: HINT }
+      ],
+      "while (doSomething(), !!test);": [
+        { col: 20, message: MESSAGE, hint: HINT }
+      ],
+      "with (doSomething(), val) {}": [
+        { col: 19, message: MESSAGE, hint: HINT }
+      ],
+      "a => (doSomething(), a)": [{ col: 19, message: MESSAGE, hint: HINT }],
+      "(1), 2": [{ col: 3, message: MESSAGE, hint: HINT }],
+      "((1)) , (2)": [{ col: 6, message: MESSAGE, hint: HINT }],
+      "while((1) , 2);": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "function foo() { return a, b; }": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+    };
+
+    assert_lint_err! {
+      NoSequences,
+      options: { "allowInParentheses": false },
+      "var foo = (1, 2);": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "if ((doSomething(), !!test));": [
+        { col: 18, message: MESSAGE, hint: HINT }
+      ],
+      "a => ((doSomething(), a))": [{ col: 20, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
@@ -0,0 +1,212 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::Spanned;
+
+pub struct NoSyncFnInAsyncFn;
+
+const CODE: &str = "no-sync-fn-in-async-fn";
+
+#[derive(Display)]
+enum NoSyncFnInAsyncFnMessage {
+  #[display(fmt = "Blocking `Deno.{}` call inside an async function", _0)]
+  Blocking(String),
+}
+
+#[derive(Display)]
+enum NoSyncFnInAsyncFnHint {
+  #[display(
+    fmt = "Use `await Deno.{}` instead so the event loop isn't blocked",
+    _0
+  )]
+  UseAsync(String),
+}
+
+impl LintRule for NoSyncFnInAsyncFn {
+  fn new() -> Box<Self> {
+    Box::new(NoSyncFnInAsyncFn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoSyncFnInAsyncFnHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows blocking `Deno.*Sync` calls inside async functions
+
+Synchronous APIs like `Deno.readTextFileSync` block the whole event loop until
+they finish, so no other task can make progress in the meantime. Inside an
+`async` function the async counterpart can simply be awaited instead.
+
+Only the function directly containing the call is considered: a regular
+callback nested in an async function is not reported.
+
+### Invalid:
+```typescript
+async function readConfig() {
+  const text = Deno.readTextFileSync("./config.json");
+  return JSON.parse(text);
+}
+```
+
+### Valid:
+```typescript
+async function readConfig() {
+  const text = await Deno.readTextFile("./config.json");
+  return JSON.parse(text);
+}
+
+function readConfigSync() {
+  return JSON.parse(Deno.readTextFileSync("./config.json"));
+}
+```
+"#
+  }
+}
+
+/// Returns the names of the `Deno.*Sync` function being called and of its
+/// async counterpart, if any.
+fn sync_fn_names(
+  callee: &AstView::Expr,
+  ctx: &Context,
+) -> Option<(String, String)> {
+  let member_expr = match callee {
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      member_expr
+    }
+    _ => return None,
+  };
+
+  match (&member_expr.obj, &member_expr.prop) {
+    (
+      AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+      AstView::Expr::Ident(prop),
+    ) if *obj.sym() == *"Deno" && is_global(obj, ctx.scope()) => {
+      let name = prop.sym();
+      match name.strip_suffix("Sync") {
+        Some(async_name) if !async_name.is_empty() => {
+          Some((name.to_string(), async_name.to_string()))
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the function directly containing the node is `async`.
+fn is_in_async_fn(node: AstView::Node) -> bool {
+  for ancestor in node.ancestors() {
+    match ancestor {
+      AstView::Node::Function(function) => return function.is_async(),
+      AstView::Node::ArrowExpr(arrow_expr) => return arrow_expr.is_async(),
+      AstView::Node::Constructor(_)
+      | AstView::Node::GetterProp(_)
+      | AstView::Node::SetterProp(_) => return false,
+      _ => {}
+    }
+  }
+  false
+}
+
+struct NoSyncFnInAsyncFnHandler;
+
+impl Handler for NoSyncFnInAsyncFnHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let callee = match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(callee) => callee,
+      AstView::ExprOrSuper::Super(_) => return,
+    };
+
+    if let Some((name, async_name)) = sync_fn_names(callee, ctx) {
+      if is_in_async_fn(call_expr.into()) {
+        ctx.add_diagnostic_with_hint(
+          call_expr.span(),
+          CODE,
+          NoSyncFnInAsyncFnMessage::Blocking(name),
+          NoSyncFnInAsyncFnHint::UseAsync(async_name),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_sync_fn_in_async_fn_valid() {
+    assert_lint_ok! {
+      NoSyncFnInAsyncFn,
+      "Deno.readTextFileSync('foo.txt');",
+      "function foo() { Deno.readTextFileSync('foo.txt'); }",
+      "const foo = () => Deno.readTextFileSync('foo.txt');",
+      "async function foo() { await Deno.readTextFile('foo.txt'); }",
+      "async function foo() { function bar() { Deno.readTextFileSync('foo.txt'); } }",
+      "async function foo() { [1].map(() => Deno.removeSync('foo')); }",
+      "async function foo() { class Foo { constructor() { Deno.removeSync('foo'); } } }",
+      "async function foo(Deno) { Deno.readTextFileSync('foo.txt'); }",
+      "async function foo() { foo.readTextFileSync('foo.txt'); }",
+      "async function foo() { Deno['readTextFileSync']('foo.txt'); }",
+      "async function foo() { Deno.Sync(); }",
+    };
+  }
+
+  #[test]
+  fn no_sync_fn_in_async_fn_invalid() {
+    assert_lint_err! {
+      NoSyncFnInAsyncFn,
+      "async function foo() { Deno.readTextFileSync('foo.txt'); }": [
+        {
+          col: 23,
+          message: variant!(NoSyncFnInAsyncFnMessage, Blocking, "readTextFileSync"),
+          hint: variant!(NoSyncFnInAsyncFnHint, UseAsync, "readTextFile"),
+        }
+      ],
+      "const foo = async () => Deno.statSync('foo');": [
+        {
+          col: 24,
+          message: variant!(NoSyncFnInAsyncFnMessage, Blocking, "statSync"),
+          hint: variant!(NoSyncFnInAsyncFnHint, UseAsync, "stat"),
+        }
+      ],
+      "class Foo { async bar() { Deno.writeFileSync('foo', data); } }": [
+        {
+          col: 26,
+          message: variant!(NoSyncFnInAsyncFnMessage, Blocking, "writeFileSync"),
+          hint: variant!(NoSyncFnInAsyncFnHint, UseAsync, "writeFile"),
+        }
+      ],
+      "function foo() { return async () => { Deno.removeSync('foo'); }; }": [
+        {
+          col: 38,
+          message: variant!(NoSyncFnInAsyncFnMessage, Blocking, "removeSync"),
+          hint: variant!(NoSyncFnInAsyncFnHint, UseAsync, "remove"),
+        }
+      ],
+      "async function foo() { if (bar) { Deno.fsyncSync(rid); } }": [
+        {
+          col: 34,
+          message: variant!(NoSyncFnInAsyncFnMessage, Blocking, "fsyncSync"),
+          hint: variant!(NoSyncFnInAsyncFnHint, UseAsync, "fsync"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,109 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+
+pub struct NoTemplateCurlyInString;
+
+const CODE: &str = "no-template-curly-in-string";
+const MESSAGE: &str = "Template literal placeholder syntax in a regular string";
+const HINT: &str = "Use a template literal with backticks (`) if the placeholder should be replaced with a value";
+
+impl LintRule for NoTemplateCurlyInString {
+  fn new() -> Box<Self> {
+    Box::new(NoTemplateCurlyInString)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoTemplateCurlyInStringHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows template literal placeholders in regular strings
+
+Placeholders like `${name}` are only replaced in template literals, which are
+written with backticks. In a string with single or double quotes they're kept
+as is, so such a string is almost always meant to be a template literal.
+
+### Invalid:
+```typescript
+const greeting = "Hello ${name}!";
+const total = 'Total: ${price * count}';
+```
+
+### Valid:
+```typescript
+const greeting = `Hello ${name}!`;
+const total = "Total: " + price * count;
+const price = "$10";
+```
+"#
+  }
+}
+
+/// Checks if the string contains something like `${foo}`.
+fn has_placeholder(value: &str) -> bool {
+  value.match_indices("${").any(|(start, _)| {
+    let rest = &value[start + 2..];
+    matches!(rest.find('}'), Some(end) if end > 0)
+  })
+}
+
+struct NoTemplateCurlyInStringHandler;
+
+impl Handler for NoTemplateCurlyInStringHandler {
+  fn str(&mut self, str: &AstView::Str, ctx: &mut Context) {
+    if has_placeholder(str.value()) {
+      ctx.add_diagnostic_with_hint(str.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_template_curly_in_string_valid() {
+    assert_lint_ok! {
+      NoTemplateCurlyInString,
+      "`Hello ${name}!`;",
+      "'Hello, World!';",
+      "'$10';",
+      "'${';",
+      "'${}';",
+      "'{foo}';",
+      "'$ {foo}';",
+      "'${foo';",
+      "tag`${foo}`;",
+      "`${'foo'}`;",
+    };
+  }
+
+  #[test]
+  fn no_template_curly_in_string_invalid() {
+    assert_lint_err! {
+      NoTemplateCurlyInString,
+      "'Hello ${name}!';": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const total = \"Total: ${price * count}\";": [
+        { col: 14, message: MESSAGE, hint: HINT }
+      ],
+      "foo('${bar}');": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "'${} and ${foo}';": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "'$${price}';": [{ col: 0, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
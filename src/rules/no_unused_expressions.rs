@@ -0,0 +1,293 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::may_have_side_effects;
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{BinaryOp, Expr, UnaryOp};
+
+#[derive(Default)]
+pub struct NoUnusedExpressions {
+  options: NoUnusedExpressionsOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoUnusedExpressionsOptions {
+  /// Allows short-circuit evaluations used for their side effects, like
+  /// `a && b()`.
+  allow_short_circuit: bool,
+  /// Allows ternaries used for their side effects, like `a ? b() : c()`.
+  allow_ternary: bool,
+  /// Allows tagged templates, like `` tag`foo` ``.
+  allow_tagged_templates: bool,
+}
+
+const CODE: &str = "no-unused-expressions";
+const MESSAGE: &str =
+  "Expected an assignment or function call and instead saw an expression";
+const HINT: &str = "Remove the expression, or make use of its value";
+
+impl LintRule for NoUnusedExpressions {
+  fn new() -> Box<Self> {
+    Box::new(NoUnusedExpressions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoUnusedExpressionsHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows expression statements that have no effect
+
+An expression statement that neither calls, constructs, assigns, updates nor
+awaits anything computes a value only to throw it away. That's usually a
+mistake, like a comparison written where an assignment was intended, or a
+function that's referenced but never called. Calls of functions known to be
+pure, like `Math.max()`, don't count.
+
+Directives like `"use strict"`, JSX elements and expressions discarded with
+`void` are allowed. The `allowShortCircuit`,
+`allowTernary` and `allowTaggedTemplates` options (all `false` by default)
+additionally allow `a && b()`, `a ? b() : c()` and `` tag`foo` `` respectively.
+
+### Invalid:
+```typescript
+a === b;
+obj.prop;
+foo;
+a && b();
+a ? b() : c();
+```
+
+### Valid:
+```typescript
+a = b;
+foo();
+new Foo();
+i++;
+delete obj.prop;
+void promise;
+await promise;
+
+// with { "allowShortCircuit": true, "allowTernary": true }
+a && b();
+a ? b() : c();
+```
+"#
+  }
+}
+
+fn is_string_stmt(stmt: &AstView::Stmt) -> bool {
+  matches!(
+    stmt,
+    AstView::Stmt::Expr(expr_stmt)
+      if matches!(expr_stmt.expr, AstView::Expr::Lit(AstView::Lit::Str(_)))
+  )
+}
+
+/// Checks if the statement is part of the directive prologue of a program or
+/// a function, like `"use strict";`.
+fn is_directive(expr_stmt: &AstView::ExprStmt) -> bool {
+  let span = expr_stmt.span();
+  let is_in_prologue = |stmts: &mut dyn Iterator<Item = AstView::Stmt>| {
+    stmts
+      .take_while(is_string_stmt)
+      .any(|stmt| stmt.span() == span)
+  };
+  match expr_stmt.parent {
+    AstView::Node::Script(script) => {
+      is_in_prologue(&mut script.body.iter().copied())
+    }
+    AstView::Node::Module(module) => is_in_prologue(
+      &mut module
+        .body
+        .iter()
+        .take_while(|item| matches!(item, AstView::ModuleItem::Stmt(_)))
+        .filter_map(|item| match item {
+          AstView::ModuleItem::Stmt(stmt) => Some(*stmt),
+          AstView::ModuleItem::ModuleDecl(_) => None,
+        }),
+    ),
+    AstView::Node::BlockStmt(block_stmt)
+      if matches!(
+        block_stmt.parent,
+        AstView::Node::Function(_) | AstView::Node::ArrowExpr(_)
+      ) =>
+    {
+      is_in_prologue(&mut block_stmt.stmts.iter().copied())
+    }
+    _ => false,
+  }
+}
+
+struct NoUnusedExpressionsHandler {
+  options: NoUnusedExpressionsOptions,
+}
+
+impl NoUnusedExpressionsHandler {
+  /// Checks if the value of the expression is unused without any side effect
+  /// like a call or an assignment.
+  fn is_disallowed(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Bin(bin_expr)
+        if matches!(
+          bin_expr.op,
+          BinaryOp::LogicalAnd
+            | BinaryOp::LogicalOr
+            | BinaryOp::NullishCoalescing
+        ) =>
+      {
+        !self.options.allow_short_circuit || self.is_disallowed(&bin_expr.right)
+      }
+      Expr::Cond(cond_expr) => {
+        !self.options.allow_ternary
+          || self.is_disallowed(&cond_expr.cons)
+          || self.is_disallowed(&cond_expr.alt)
+      }
+      Expr::TaggedTpl(_) => !self.options.allow_tagged_templates,
+      // `void` explicitly discards the value.
+      Expr::Unary(unary_expr) if unary_expr.op == UnaryOp::Void => false,
+      Expr::Paren(paren_expr) => self.is_disallowed(&paren_expr.expr),
+      _ => !may_have_side_effects(expr),
+    }
+  }
+}
+
+impl Handler for NoUnusedExpressionsHandler {
+  fn expr_stmt(&mut self, expr_stmt: &AstView::ExprStmt, ctx: &mut Context) {
+    if self.is_disallowed(&expr_stmt.inner.expr) && !is_directive(expr_stmt) {
+      ctx.add_diagnostic_with_hint(expr_stmt.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unused_expressions_valid() {
+    assert_lint_ok! {
+      NoUnusedExpressions,
+      "a = b;",
+      "a += b;",
+      "a++;",
+      "--a;",
+      "foo();",
+      "foo?.();",
+      "obj.foo?.bar();",
+      "new Foo();",
+      "delete obj.prop;",
+      "void foo;",
+      "async function f() { await foo; }",
+      "function* f() { yield foo; }",
+      "import('foo');",
+      "foo()!;",
+      "(foo() as any);",
+      "'use strict';",
+      "'use strict'; 'use asm'; foo();",
+      "function f() { 'use strict'; return 1; }",
+      "const f = () => { 'use strict'; };",
+      "class A { foo() { 'use strict'; } }",
+      "if (a) { b(); }",
+      "a, b();",
+      "!foo();",
+      "a === foo();",
+      "[foo()];",
+      "obj[foo()];",
+      "`${foo()}`;",
+      "delete a;",
+      { src: "<div />;", filename: "foo.tsx" },
+      { src: "[<div />, a];", filename: "foo.tsx" },
+      "for (a; b; c) {}",
+    };
+
+    assert_lint_ok! {
+      NoUnusedExpressions,
+      options: { "allowShortCircuit": true },
+      "a && b();",
+      "a || b();",
+      "a ?? (b = c);",
+      "a && b && c();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedExpressions,
+      options: { "allowTernary": true },
+      "a ? b() : c();",
+      "a ? (b = 1) : c ? d() : e();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedExpressions,
+      options: { "allowTaggedTemplates": true },
+      "tag`foo`;",
+    };
+  }
+
+  #[test]
+  fn no_unused_expressions_invalid() {
+    assert_lint_err! {
+      NoUnusedExpressions,
+      "a === b;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "obj.prop;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "obj?.prop;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "0;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "(a);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "`foo`;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "tag`foo`;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a && b();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a ? b() : c();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "[a, b];": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "({ a: b, [c]: d, e() {} });": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "`${a}`;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "obj[key].prop;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Math.max(a, b);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "function f() { new.target; }": [
+        { col: 15, message: MESSAGE, hint: HINT }
+      ],
+      "this;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo!;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "function f() { foo(); 'use strict'; }": [
+        { col: 22, message: MESSAGE, hint: HINT }
+      ],
+      "if (a) { 'use strict'; }": [{ col: 9, message: MESSAGE, hint: HINT }],
+      "foo(); 'use strict';": [{ col: 7, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      NoUnusedExpressions,
+      options: { "allowShortCircuit": true, "allowTernary": true },
+      "a && b;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a ? b() : c;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a || (b ? c() : d);": [{ col: 0, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
@@ -0,0 +1,224 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{equal_tokens, is_null_or_undefined, skip_wrappers};
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use if_chain::if_chain;
+use swc_common::Spanned;
+
+pub struct NoUselessCall;
+
+const CODE: &str = "no-useless-call";
+
+#[derive(Display)]
+enum NoUselessCallMessage {
+  #[display(fmt = "Unnecessary `.{}()` call", _0)]
+  Unnecessary(String),
+}
+
+#[derive(Display)]
+enum NoUselessCallHint {
+  #[display(fmt = "Call the function directly, e.g. `foo(a, b)`")]
+  Call,
+  #[display(
+    fmt = "Call the function directly, e.g. `foo(a, b)` or `foo(...args)`"
+  )]
+  Apply,
+}
+
+impl LintRule for NoUselessCall {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessCall)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoUselessCallHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary `.call()` and `.apply()`
+
+`Function.prototype.call()` and `Function.prototype.apply()` exist to call a
+function with a specific `this`. When the `this` passed is the same as the one
+the function would be called with anyway, that is, `null` or `undefined` for
+plain functions and the object itself for methods, a direct call does the same
+and is easier to read.
+
+### Invalid:
+```typescript
+foo.call(undefined, a, b);
+foo.apply(null, [a, b]);
+obj.foo.call(obj, a, b);
+obj.foo.apply(obj, args);
+```
+
+### Valid:
+```typescript
+foo(a, b);
+obj.foo(...args);
+
+// `this` differs
+foo.call(obj, a, b);
+obj.foo.apply(otherObj, args);
+```
+"#
+  }
+}
+
+struct NoUselessCallHandler;
+
+impl Handler for NoUselessCallHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if_chain! {
+      if let AstView::ExprOrSuper::Expr(callee) = &call_expr.callee;
+      if let AstView::Expr::Member(callee) = skip_wrappers(callee);
+      if !callee.computed();
+      if let AstView::Expr::Ident(prop) = callee.prop;
+      let method = prop.sym().to_string();
+      if let Some(hint) = match (method.as_str(), call_expr.args.len()) {
+        ("call", len) if len >= 1 => Some(NoUselessCallHint::Call),
+        ("apply", 2) => Some(NoUselessCallHint::Apply),
+        _ => None,
+      };
+      if call_expr.args.iter().all(|arg| arg.spread().is_none());
+      if let AstView::ExprOrSuper::Expr(called) = &callee.obj;
+      then {
+        let this_arg = &call_expr.args[0].expr;
+        let is_useless = match skip_wrappers(called) {
+          AstView::Expr::Member(called) => match &called.obj {
+            AstView::ExprOrSuper::Expr(expected_this) => {
+              equal_tokens(expected_this, this_arg)
+            }
+            AstView::ExprOrSuper::Super(_) => false,
+          },
+          _ => is_null_or_undefined(this_arg),
+        };
+
+        if is_useless {
+          ctx.add_diagnostic_with_hint(
+            call_expr.span(),
+            CODE,
+            NoUselessCallMessage::Unnecessary(method),
+            hint,
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_call_valid() {
+    assert_lint_ok! {
+      NoUselessCall,
+      "foo.call(obj, a, b);",
+      "foo.apply(obj, args);",
+      "obj.foo.call(null, a);",
+      "obj.foo.call(otherObj, a);",
+      "obj.foo.apply(undefined, args);",
+      "a.b.foo.call(a.b.c);",
+      "foo.call();",
+      "foo.apply(null);",
+      "foo.apply(null, args, extra);",
+      "foo.call(...args);",
+      "foo.apply(null, ...args);",
+      "foo[call](null, a);",
+      "foo.bind(null, a);",
+      "class A extends B { foo() { super.foo.call(this, a); } }",
+    };
+  }
+
+  #[test]
+  fn no_useless_call_invalid() {
+    assert_lint_err! {
+      NoUselessCall,
+      "foo.call(undefined, a, b);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "foo.call(null);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "foo.call(void 0, a);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "foo.apply(null, [a, b]);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "apply"),
+          hint: NoUselessCallHint::Apply,
+        }
+      ],
+      "foo.apply(undefined, args);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "apply"),
+          hint: NoUselessCallHint::Apply,
+        }
+      ],
+      "obj.foo.call(obj, a, b);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "a.b.c.foo.apply(a.b.c, args);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "apply"),
+          hint: NoUselessCallHint::Apply,
+        }
+      ],
+      "obj[key].call(obj, a);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "foo?.call(undefined, a);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+      "(foo.call)(null, a);": [
+        {
+          col: 0,
+          message: variant!(NoUselessCallMessage, Unnecessary, "call"),
+          hint: NoUselessCallHint::Call,
+        }
+      ],
+    };
+  }
+}
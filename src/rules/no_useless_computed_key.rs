@@ -0,0 +1,302 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::{Span, Spanned};
+
+pub struct NoUselessComputedKey;
+
+const CODE: &str = "no-useless-computed-key";
+const HINT: &str = "Remove the square brackets around the key";
+const FIX_DESC: &str = "Use a plain key";
+
+#[derive(Display)]
+enum NoUselessComputedKeyMessage {
+  #[display(fmt = "Unnecessarily computed property key `{}` found", _0)]
+  Unnecessary(String),
+}
+
+impl LintRule for NoUselessComputedKey {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessComputedKey)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoUselessComputedKeyHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows computed property keys that are just literals
+
+Wrapping a string or number literal in square brackets to use it as a
+property key has no effect, so the brackets are just noise.
+
+Keys whose meaning changes when written without brackets are not reported,
+such as `["__proto__"]` in object literals and `["constructor"]` in classes.
+
+### Invalid:
+```typescript
+const a = { ["b"]: 1 };
+const c = { [0]: 1 };
+class D {
+  ["e"]() {}
+  ["f"] = 1;
+}
+```
+
+### Valid:
+```typescript
+const a = { "b": 1 };
+const c = { 0: 1 };
+const d = { [e]: 1 };
+class F {
+  "g"() {}
+  "h" = 1;
+}
+```
+"#
+  }
+}
+
+/// Returns the value of the key if it's a string or number literal.
+fn literal_key_value(expr: &AstView::Expr) -> Option<String> {
+  match expr {
+    AstView::Expr::Lit(AstView::Lit::Str(s)) => Some(s.value().to_string()),
+    AstView::Expr::Lit(AstView::Lit::Num(n)) => Some(n.value().to_string()),
+    _ => None,
+  }
+}
+
+fn report(ctx: &mut Context, span: Span, key: &AstView::Expr) {
+  let key_text = key.text().to_string();
+  ctx.add_diagnostic_with_fix(
+    span,
+    CODE,
+    NoUselessComputedKeyMessage::Unnecessary(key_text.clone()),
+    HINT,
+    FIX_DESC,
+    vec![(span, key_text)],
+  );
+}
+
+struct NoUselessComputedKeyHandler;
+
+impl Handler for NoUselessComputedKeyHandler {
+  fn computed_prop_name(
+    &mut self,
+    computed_prop_name: &AstView::ComputedPropName,
+    ctx: &mut Context,
+  ) {
+    let value = match literal_key_value(&computed_prop_name.expr) {
+      Some(value) => value,
+      None => return,
+    };
+
+    use AstView::Node::*;
+    let is_useless = match computed_prop_name.parent {
+      // `{ ["__proto__"]: foo }` defines an own property, while
+      // `{ __proto__: foo }` sets the prototype.
+      KeyValueProp(_) => value != "__proto__",
+      MethodProp(_) | GetterProp(_) | SetterProp(_) => true,
+      // `["constructor"]() {}` is a regular method, not a constructor.
+      ClassMethod(class_method) => {
+        !(value == "constructor" && !class_method.is_static()
+          || value == "prototype" && class_method.is_static())
+      }
+      _ => false,
+    };
+
+    if is_useless {
+      report(ctx, computed_prop_name.span(), &computed_prop_name.expr);
+    }
+  }
+
+  fn class_prop(&mut self, class_prop: &AstView::ClassProp, ctx: &mut Context) {
+    if !class_prop.computed() {
+      return;
+    }
+
+    let value = match literal_key_value(&class_prop.key) {
+      Some(value) => value,
+      None => return,
+    };
+
+    // These are syntax errors when written without brackets.
+    if value == "constructor" || value == "prototype" && class_prop.is_static()
+    {
+      return;
+    }
+
+    if let (Some(open), Some(close)) =
+      (class_prop.key.previous_token(), class_prop.key.next_token())
+    {
+      report(ctx, open.span.to(close.span), &class_prop.key);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_computed_key_valid() {
+    assert_lint_ok! {
+      NoUselessComputedKey,
+      "({ 'a': 0, b(){} })",
+      "({ [x]: 0 });",
+      "({ a: 0, [b](){} })",
+      "({ ['__proto__']: [] })",
+      "({ [`a`]: 0 })",
+      "({ [1 + 2]: 0 })",
+      "class Foo { a() {} }",
+      "class Foo { [x]() {} }",
+      "class Foo { ['constructor']() {} }",
+      "class Foo { static ['prototype']() {} }",
+      "class Foo { 'a' = 1 }",
+      "class Foo { [x] = 1 }",
+      "class Foo { ['constructor'] = 1 }",
+      "class Foo { static ['prototype'] = 1 }",
+    };
+  }
+
+  #[test]
+  fn no_useless_computed_key_invalid() {
+    assert_lint_err! {
+      NoUselessComputedKey,
+      "({ ['0']: 0 })": [
+        {
+          col: 3,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'0'"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ '0': 0 })"),
+        }
+      ],
+      "({ ['0+1,234']: 0 })": [
+        {
+          col: 3,
+          message: variant!(
+            NoUselessComputedKeyMessage,
+            Unnecessary,
+            "'0+1,234'"
+          ),
+          hint: HINT,
+          fix: (FIX_DESC, "({ '0+1,234': 0 })"),
+        }
+      ],
+      "({ [0]: 0 })": [
+        {
+          col: 3,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "0"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ 0: 0 })"),
+        }
+      ],
+      "({ ['x']: 0 })": [
+        {
+          col: 3,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'x'"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ 'x': 0 })"),
+        }
+      ],
+      "({ ['x']() {} })": [
+        {
+          col: 3,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'x'"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ 'x'() {} })"),
+        }
+      ],
+      "({ get ['x']() { return 1; } })": [
+        {
+          col: 7,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'x'"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ get 'x'() { return 1; } })"),
+        }
+      ],
+      "({ [ 'x' ]: 0 })": [
+        {
+          col: 3,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'x'"),
+          hint: HINT,
+          fix: (FIX_DESC, "({ 'x': 0 })"),
+        }
+      ],
+      "({ ['__proto__']() {} })": [
+        {
+          col: 3,
+          message: variant!(
+            NoUselessComputedKeyMessage,
+            Unnecessary,
+            "'__proto__'"
+          ),
+          hint: HINT,
+          fix: (FIX_DESC, "({ '__proto__'() {} })"),
+        }
+      ],
+      "class Foo { ['a']() {} }": [
+        {
+          col: 12,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'a'"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { 'a'() {} }"),
+        }
+      ],
+      "class Foo { static ['constructor']() {} }": [
+        {
+          col: 19,
+          message: variant!(
+            NoUselessComputedKeyMessage,
+            Unnecessary,
+            "'constructor'"
+          ),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { static 'constructor'() {} }"),
+        }
+      ],
+      "class Foo { ['prototype']() {} }": [
+        {
+          col: 12,
+          message: variant!(
+            NoUselessComputedKeyMessage,
+            Unnecessary,
+            "'prototype'"
+          ),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { 'prototype'() {} }"),
+        }
+      ],
+      "class Foo { ['a'] = 1 }": [
+        {
+          col: 12,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "'a'"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { 'a' = 1 }"),
+        }
+      ],
+      "class Foo { static [ 2 ]: number }": [
+        {
+          col: 19,
+          message: variant!(NoUselessComputedKeyMessage, Unnecessary, "2"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { static 2: number }"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,266 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::Spanned;
+
+pub struct NoUselessRename;
+
+const CODE: &str = "no-useless-rename";
+const HINT: &str =
+  "Remove the rename, e.g. write `{ foo }` instead of `{ foo: foo }`";
+const FIX_DESC: &str = "Remove the rename";
+
+#[derive(Display)]
+enum NoUselessRenameMessage {
+  #[display(fmt = "Import `{}` is unnecessarily renamed", _0)]
+  Import(String),
+  #[display(fmt = "Export `{}` is unnecessarily renamed", _0)]
+  Export(String),
+  #[display(fmt = "Destructured `{}` is unnecessarily renamed", _0)]
+  Destructuring(String),
+}
+
+impl LintRule for NoUselessRename {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessRename)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoUselessRenameHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows renaming imports, exports and destructured bindings to the same name
+
+Renaming a binding to the name it already has does nothing and only adds noise.
+
+### Invalid:
+```typescript
+import { foo as foo } from "./foo.ts";
+export { bar as bar };
+const { baz: baz } = obj;
+const { qux: qux = 1 } = obj;
+```
+
+### Valid:
+```typescript
+import { foo } from "./foo.ts";
+import { foo as foo2 } from "./foo.ts";
+export { bar };
+const { baz } = obj;
+const { qux = 1 } = obj;
+```
+"#
+  }
+}
+
+struct NoUselessRenameHandler;
+
+impl Handler for NoUselessRenameHandler {
+  fn import_named_specifier(
+    &mut self,
+    import_named_specifier: &AstView::ImportNamedSpecifier,
+    ctx: &mut Context,
+  ) {
+    if let Some(imported) = import_named_specifier.imported {
+      let local = import_named_specifier.local;
+      if imported.sym() == local.sym() {
+        let span = import_named_specifier.span();
+        ctx.add_diagnostic_with_fix(
+          span,
+          CODE,
+          NoUselessRenameMessage::Import(local.sym().to_string()),
+          HINT,
+          FIX_DESC,
+          vec![(span, local.text().to_string())],
+        );
+      }
+    }
+  }
+
+  fn export_named_specifier(
+    &mut self,
+    export_named_specifier: &AstView::ExportNamedSpecifier,
+    ctx: &mut Context,
+  ) {
+    if let Some(exported) = export_named_specifier.exported {
+      let orig = export_named_specifier.orig;
+      if exported.sym() == orig.sym() {
+        let span = export_named_specifier.span();
+        ctx.add_diagnostic_with_fix(
+          span,
+          CODE,
+          NoUselessRenameMessage::Export(orig.sym().to_string()),
+          HINT,
+          FIX_DESC,
+          vec![(span, orig.text().to_string())],
+        );
+      }
+    }
+  }
+
+  fn key_value_pat_prop(
+    &mut self,
+    key_value_pat_prop: &AstView::KeyValuePatProp,
+    ctx: &mut Context,
+  ) {
+    let key = match &key_value_pat_prop.key {
+      AstView::PropName::Ident(ident) => ident.sym().to_string(),
+      AstView::PropName::Str(s) => s.value().to_string(),
+      _ => return,
+    };
+
+    // `{ foo: foo = 1 }` can be written as `{ foo = 1 }` as well.
+    let binding = match &key_value_pat_prop.value {
+      AstView::Pat::Ident(binding) => binding,
+      AstView::Pat::Assign(assign_pat) => match &assign_pat.left {
+        AstView::Pat::Ident(binding) => binding,
+        _ => return,
+      },
+      _ => return,
+    };
+
+    if *binding.id.sym() == *key {
+      let span = key_value_pat_prop.span();
+      ctx.add_diagnostic_with_fix(
+        span,
+        CODE,
+        NoUselessRenameMessage::Destructuring(key),
+        HINT,
+        FIX_DESC,
+        vec![(span, key_value_pat_prop.value.text().to_string())],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_rename_valid() {
+    assert_lint_ok! {
+      NoUselessRename,
+      "import { foo } from 'foo';",
+      "import { foo as bar } from 'foo';",
+      "import * as foo from 'foo';",
+      "import foo from 'foo';",
+      "export { foo };",
+      "export { foo as bar };",
+      "export { foo as bar } from 'foo';",
+      "export * as foo from 'foo';",
+      "const { foo } = obj;",
+      "const { foo: bar } = obj;",
+      "const { foo = 1 } = obj;",
+      "const { foo: bar = 1 } = obj;",
+      "const { foo: { foo } } = obj;",
+      "const { [foo]: foo } = obj;",
+      "const { ...foo } = obj;",
+      "function f({ foo: bar }) {}",
+      "({ foo: bar } = obj);",
+      "const obj = { foo: foo };",
+    };
+  }
+
+  #[test]
+  fn no_useless_rename_invalid() {
+    assert_lint_err! {
+      NoUselessRename,
+      "import { foo as foo } from 'foo';": [
+        {
+          col: 9,
+          message: variant!(NoUselessRenameMessage, Import, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "import { foo } from 'foo';"),
+        }
+      ],
+      "import { foo as foo, bar as baz } from 'foo';": [
+        {
+          col: 9,
+          message: variant!(NoUselessRenameMessage, Import, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "import { foo, bar as baz } from 'foo';"),
+        }
+      ],
+      "export { foo as foo };": [
+        {
+          col: 9,
+          message: variant!(NoUselessRenameMessage, Export, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "export { foo };"),
+        }
+      ],
+      "export { foo as foo } from 'foo';": [
+        {
+          col: 9,
+          message: variant!(NoUselessRenameMessage, Export, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "export { foo } from 'foo';"),
+        }
+      ],
+      "const { foo: foo } = obj;": [
+        {
+          col: 8,
+          message: variant!(NoUselessRenameMessage, Destructuring, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "const { foo } = obj;"),
+        }
+      ],
+      "const { 'foo': foo } = obj;": [
+        {
+          col: 8,
+          message: variant!(NoUselessRenameMessage, Destructuring, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "const { foo } = obj;"),
+        }
+      ],
+      "const { foo: foo = 1 } = obj;": [
+        {
+          col: 8,
+          message: variant!(NoUselessRenameMessage, Destructuring, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "const { foo = 1 } = obj;"),
+        }
+      ],
+      "const { foo: { bar: bar } } = obj;": [
+        {
+          col: 15,
+          message: variant!(NoUselessRenameMessage, Destructuring, "bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "const { foo: { bar } } = obj;"),
+        }
+      ],
+      "function f({ foo: foo }) {}": [
+        {
+          col: 13,
+          message: variant!(NoUselessRenameMessage, Destructuring, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "function f({ foo }) {}"),
+        }
+      ],
+      "for (const { foo: foo } of list) {}": [
+        {
+          col: 13,
+          message: variant!(NoUselessRenameMessage, Destructuring, "foo"),
+          hint: HINT,
+          fix: (FIX_DESC, "for (const { foo } of list) {}"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,158 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view::{self as AstView, UnaryOp};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct NoVoid {
+  options: NoVoidOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoVoidOptions {
+  /// Allows `void` used as a statement, e.g. `void somePromise();`
+  allow_as_statement: bool,
+}
+
+const CODE: &str = "no-void";
+const MESSAGE: &str = "Expected `undefined` and instead saw `void`";
+const HINT: &str = "Use `undefined` directly instead of the `void` operator";
+
+impl LintRule for NoVoid {
+  fn new() -> Box<Self> {
+    Box::new(NoVoid::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    NoVoidHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `void` operator
+
+The `void` operator evaluates its operand and returns `undefined`. It's mostly
+seen as an obscure way of writing `undefined`, which makes code harder to read.
+
+Setting `allowAsStatement` to `true` permits `void` used as a statement, which
+is a common idiom to mark a promise as intentionally not awaited.
+
+### Invalid:
+```typescript
+const foo = void bar();
+function baz() {
+  return void 0;
+}
+```
+
+### Valid:
+```typescript
+const foo = undefined;
+function baz() {
+  return undefined;
+}
+
+// allowed with `allowAsStatement`
+void somePromise();
+```
+"#
+  }
+}
+
+struct NoVoidHandler {
+  options: NoVoidOptions,
+}
+
+impl Handler for NoVoidHandler {
+  fn unary_expr(&mut self, unary_expr: &AstView::UnaryExpr, ctx: &mut Context) {
+    if unary_expr.op() != UnaryOp::Void {
+      return;
+    }
+
+    if self.options.allow_as_statement
+      && matches!(unary_expr.parent, AstView::Node::ExprStmt(_))
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(unary_expr.span(), CODE, MESSAGE, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_void_valid() {
+    assert_lint_ok! {
+      NoVoid,
+      "const foo = undefined;",
+      "function foo() { return undefined; }",
+      "typeof foo;",
+      "!foo;",
+    };
+
+    assert_lint_ok! {
+      NoVoid,
+      options: { "allowAsStatement": true },
+      "void 0;",
+      "void foo();",
+      "async function f() { void somePromise(); }",
+    };
+  }
+
+  #[test]
+  fn no_void_invalid() {
+    assert_lint_err! {
+      NoVoid,
+      "void 0": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "void foo();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const foo = void bar();": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "function foo() { return void 0; }": [
+        { col: 24, message: MESSAGE, hint: HINT }
+      ],
+      "void (void 0);": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 6, message: MESSAGE, hint: HINT },
+      ],
+    };
+
+    assert_lint_err! {
+      NoVoid,
+      options: { "allowAsStatement": true },
+      "const foo = void bar();": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "foo(void 0);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "void foo(), void bar();": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,449 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait, SpannedExt};
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::parser::token::Token;
+
+#[derive(Default)]
+pub struct ObjectShorthand {
+  mode: ObjectShorthandMode,
+}
+
+const CODE: &str = "object-shorthand";
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum ObjectShorthandMode {
+  /// Requires shorthand for both properties and methods.
+  Always,
+  /// Requires shorthand for methods only.
+  Methods,
+  /// Requires shorthand for properties only.
+  Properties,
+  /// Disallows shorthand for both properties and methods.
+  Never,
+}
+
+impl Default for ObjectShorthandMode {
+  fn default() -> Self {
+    ObjectShorthandMode::Always
+  }
+}
+
+impl ObjectShorthandMode {
+  fn expects_method_shorthand(self) -> bool {
+    matches!(self, Self::Always | Self::Methods)
+  }
+
+  fn expects_property_shorthand(self) -> bool {
+    matches!(self, Self::Always | Self::Properties)
+  }
+}
+
+#[derive(Display)]
+enum ObjectShorthandMessage {
+  #[display(fmt = "Expected property shorthand")]
+  ExpectedProperty,
+  #[display(fmt = "Expected method shorthand")]
+  ExpectedMethod,
+  #[display(fmt = "Unexpected property shorthand")]
+  UnexpectedProperty,
+  #[display(fmt = "Unexpected method shorthand")]
+  UnexpectedMethod,
+}
+
+#[derive(Display)]
+enum ObjectShorthandHint {
+  #[display(fmt = "Write `{{ foo }}` instead of `{{ foo: foo }}`")]
+  ExpectedProperty,
+  #[display(
+    fmt = "Write `{{ foo() {{}} }}` instead of `{{ foo: function() {{}} }}`"
+  )]
+  ExpectedMethod,
+  #[display(fmt = "Write `{{ foo: foo }}` instead of `{{ foo }}`")]
+  UnexpectedProperty,
+  #[display(
+    fmt = "Write `{{ foo: function() {{}} }}` instead of `{{ foo() {{}} }}`"
+  )]
+  UnexpectedMethod,
+}
+
+#[derive(Display)]
+enum ObjectShorthandFix {
+  #[display(fmt = "Use property shorthand")]
+  ExpectedProperty,
+  #[display(fmt = "Use method shorthand")]
+  ExpectedMethod,
+  #[display(fmt = "Use a longform property")]
+  UnexpectedProperty,
+}
+
+impl LintRule for ObjectShorthand {
+  fn new() -> Box<Self> {
+    Box::new(ObjectShorthand::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    ObjectShorthandHandler { mode: self.mode }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces or disallows shorthand syntax for object literal properties and methods
+
+ES2015 added a shorthand syntax for properties whose value is a variable of
+the same name and for methods. This rule requires that shorthand to be used
+wherever possible.
+
+The rule accepts one of the following options:
+
+- `"always"` (default) requires shorthand for both properties and methods
+- `"methods"` requires shorthand for methods only
+- `"properties"` requires shorthand for properties only
+- `"never"` disallows shorthand for both properties and methods
+
+Named function expressions are not reported since their name is visible inside
+the function, which a method shorthand wouldn't preserve.
+
+### Invalid:
+```typescript
+const foo = {
+  bar: bar,
+  baz: function () {},
+  qux: async function* () {},
+};
+```
+
+### Valid:
+```typescript
+const foo = {
+  bar,
+  baz() {},
+  async *qux() {},
+  quux: function quux() {},
+};
+```
+"#
+  }
+}
+
+/// Builds a method shorthand equivalent to the key-value property whose value
+/// is the given function expression, e.g. `foo(a) {}` for
+/// `foo: function (a) {}`.
+fn build_method(
+  key: &AstView::PropName,
+  fn_expr: &AstView::FnExpr,
+) -> Option<String> {
+  let function = fn_expr.function;
+  // Everything from the type parameters or the parameter list on is kept.
+  let rest_lo = match function.type_params {
+    Some(type_params) => type_params.lo(),
+    None => fn_expr
+      .tokens()
+      .iter()
+      .find(|t| t.token == Token::LParen)?
+      .span
+      .lo(),
+  };
+  let rest = &fn_expr.text()[(rest_lo - fn_expr.lo()).0 as usize..];
+
+  Some(format!(
+    "{}{}{}{}",
+    if function.is_async() { "async " } else { "" },
+    if function.is_generator() { "*" } else { "" },
+    key.text(),
+    rest
+  ))
+}
+
+struct ObjectShorthandHandler {
+  mode: ObjectShorthandMode,
+}
+
+impl ObjectShorthandHandler {
+  fn check_key_value_prop(
+    &self,
+    key_value_prop: &AstView::KeyValueProp,
+    ctx: &mut Context,
+  ) {
+    let span = key_value_prop.span();
+    match key_value_prop.value {
+      AstView::Expr::Ident(value) if self.mode.expects_property_shorthand() => {
+        if let AstView::PropName::Ident(key) = &key_value_prop.key {
+          // `{ __proto__: __proto__ }` sets the prototype, while
+          // `{ __proto__ }` defines an own property.
+          if key.sym() == value.sym() && *key.sym() != *"__proto__" {
+            ctx.add_diagnostic_with_fix(
+              span,
+              CODE,
+              ObjectShorthandMessage::ExpectedProperty,
+              ObjectShorthandHint::ExpectedProperty,
+              ObjectShorthandFix::ExpectedProperty,
+              vec![(span, value.text().to_string())],
+            );
+          }
+        }
+      }
+      AstView::Expr::Fn(fn_expr)
+        if fn_expr.ident.is_none() && self.mode.expects_method_shorthand() =>
+      {
+        match build_method(&key_value_prop.key, fn_expr) {
+          Some(method) => ctx.add_diagnostic_with_fix(
+            span,
+            CODE,
+            ObjectShorthandMessage::ExpectedMethod,
+            ObjectShorthandHint::ExpectedMethod,
+            ObjectShorthandFix::ExpectedMethod,
+            vec![(span, method)],
+          ),
+          None => ctx.add_diagnostic_with_hint(
+            span,
+            CODE,
+            ObjectShorthandMessage::ExpectedMethod,
+            ObjectShorthandHint::ExpectedMethod,
+          ),
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl Handler for ObjectShorthandHandler {
+  fn object_lit(&mut self, object_lit: &AstView::ObjectLit, ctx: &mut Context) {
+    for prop in &object_lit.props {
+      let prop = match prop {
+        AstView::PropOrSpread::Prop(prop) => prop,
+        AstView::PropOrSpread::Spread(_) => continue,
+      };
+
+      match prop {
+        AstView::Prop::KeyValue(key_value_prop) => {
+          self.check_key_value_prop(key_value_prop, ctx);
+        }
+        AstView::Prop::Shorthand(ident)
+          if self.mode == ObjectShorthandMode::Never =>
+        {
+          let span = ident.span();
+          ctx.add_diagnostic_with_fix(
+            span,
+            CODE,
+            ObjectShorthandMessage::UnexpectedProperty,
+            ObjectShorthandHint::UnexpectedProperty,
+            ObjectShorthandFix::UnexpectedProperty,
+            vec![(span, format!("{0}: {0}", ident.text()))],
+          );
+        }
+        AstView::Prop::Method(method_prop)
+          if self.mode == ObjectShorthandMode::Never =>
+        {
+          ctx.add_diagnostic_with_hint(
+            method_prop.span(),
+            CODE,
+            ObjectShorthandMessage::UnexpectedMethod,
+            ObjectShorthandHint::UnexpectedMethod,
+          );
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn object_shorthand_valid() {
+    assert_lint_ok! {
+      ObjectShorthand,
+      "const a = { x };",
+      "const a = { x: y };",
+      "const a = { x: y.x };",
+      "const a = { 'x': x };",
+      "const a = { [x]: x };",
+      "const a = { __proto__: __proto__ };",
+      "const a = { x() {} };",
+      "const a = { async *x() {} };",
+      "const a = { x: function x() {} };",
+      "const a = { x: () => {} };",
+      "const a = { get x() { return 1; }, set x(v) {} };",
+      "const a = { ...x };",
+    };
+
+    assert_lint_ok! {
+      ObjectShorthand,
+      options: "methods",
+      "const a = { x: x };",
+      "const a = { x() {} };",
+    };
+
+    assert_lint_ok! {
+      ObjectShorthand,
+      options: "properties",
+      "const a = { x };",
+      "const a = { x: function() {} };",
+    };
+
+    assert_lint_ok! {
+      ObjectShorthand,
+      options: "never",
+      "const a = { x: x };",
+      "const a = { x: function() {} };",
+      "const a = { ...x };",
+    };
+  }
+
+  #[test]
+  fn object_shorthand_invalid() {
+    assert_lint_err! {
+      ObjectShorthand,
+      "const a = { x: x };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedProperty,
+          hint: ObjectShorthandHint::ExpectedProperty,
+          fix: (ObjectShorthandFix::ExpectedProperty, "const a = { x };"),
+        }
+      ],
+      "const a = { x: x, y: y };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedProperty,
+          hint: ObjectShorthandHint::ExpectedProperty,
+          fix: (ObjectShorthandFix::ExpectedProperty, "const a = { x, y: y };"),
+        },
+        {
+          col: 18,
+          message: ObjectShorthandMessage::ExpectedProperty,
+          hint: ObjectShorthandHint::ExpectedProperty,
+          fix: (ObjectShorthandFix::ExpectedProperty, "const a = { x: x, y };"),
+        }
+      ],
+      "const a = { x: function() {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (ObjectShorthandFix::ExpectedMethod, "const a = { x() {} };"),
+        }
+      ],
+      "const a = { x: function (b, c) { return b + c; } };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (
+            ObjectShorthandFix::ExpectedMethod,
+            "const a = { x(b, c) { return b + c; } };"
+          ),
+        }
+      ],
+      "const a = { 'x-y': function() {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (ObjectShorthandFix::ExpectedMethod, "const a = { 'x-y'() {} };"),
+        }
+      ],
+      "const a = { [x]: function() {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (ObjectShorthandFix::ExpectedMethod, "const a = { [x]() {} };"),
+        }
+      ],
+      "const a = { x: async function* () {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (ObjectShorthandFix::ExpectedMethod, "const a = { async *x() {} };"),
+        }
+      ],
+      "const a = { x: function<T>(b: T): T { return b; } };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (
+            ObjectShorthandFix::ExpectedMethod,
+            "const a = { x<T>(b: T): T { return b; } };"
+          ),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ObjectShorthand,
+      options: "methods",
+      "const a = { x: function() {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedMethod,
+          hint: ObjectShorthandHint::ExpectedMethod,
+          fix: (ObjectShorthandFix::ExpectedMethod, "const a = { x() {} };"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ObjectShorthand,
+      options: "properties",
+      "const a = { x: x };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::ExpectedProperty,
+          hint: ObjectShorthandHint::ExpectedProperty,
+          fix: (ObjectShorthandFix::ExpectedProperty, "const a = { x };"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ObjectShorthand,
+      options: "never",
+      "const a = { x };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::UnexpectedProperty,
+          hint: ObjectShorthandHint::UnexpectedProperty,
+          fix: (ObjectShorthandFix::UnexpectedProperty, "const a = { x: x };"),
+        }
+      ],
+      "const a = { x() {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::UnexpectedMethod,
+          hint: ObjectShorthandHint::UnexpectedMethod,
+        }
+      ],
+    };
+  }
+}
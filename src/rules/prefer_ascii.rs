@@ -0,0 +1,321 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use serde::Deserialize;
+use swc_common::{BytePos, Span, Spanned};
+
+#[derive(Default)]
+pub struct PreferAscii {
+  options: PreferAsciiOptions,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct PreferAsciiOptions {
+  /// Checks identifiers, including property names.
+  identifiers: bool,
+  /// Checks string literals and template literals.
+  strings: bool,
+  /// Checks comments.
+  comments: bool,
+}
+
+impl Default for PreferAsciiOptions {
+  fn default() -> Self {
+    Self {
+      identifiers: true,
+      strings: true,
+      comments: true,
+    }
+  }
+}
+
+const CODE: &str = "prefer-ascii";
+
+#[derive(Display)]
+enum PreferAsciiMessage {
+  #[display(fmt = "Non-ASCII character `{}` ({}) in {}", _0, _1, _2)]
+  NonAscii(String, String, String),
+}
+
+#[derive(Display)]
+enum PreferAsciiHint {
+  #[display(fmt = "Rename it using ASCII characters only")]
+  Rename,
+  #[display(fmt = "Use the escape sequence `{}` instead", _0)]
+  Escape(String),
+  #[display(fmt = "Write it using ASCII characters only")]
+  Rewrite,
+}
+
+impl LintRule for PreferAscii {
+  fn new() -> Box<Self> {
+    Box::new(PreferAscii::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    if self.options.comments {
+      let comments = context.all_comments().collect::<Vec<_>>();
+      for comment in comments {
+        // The text of a comment starts after `//` or `/*`.
+        let start = comment.span.lo + BytePos(2);
+        check_text(context, start, &comment.text, Category::Comment);
+      }
+    }
+
+    PreferAsciiHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows non-ASCII characters in identifiers, strings and comments
+
+Characters outside of ASCII may be rendered incorrectly, or be hard to tell
+apart from similar looking ones, depending on the terminal, font or diff tool
+used. Keeping the source code ASCII only makes it reviewable anywhere. In
+strings, escape sequences like `\u00E9` produce the same value. JSX attribute
+values don't support escape sequences, but can be written as an expression
+like `title={"\u00E9"}` instead.
+
+Identifiers, strings and comments are checked by default. Each category can be
+turned off with the `identifiers`, `strings` and `comments` options, e.g.
+`{ "comments": false }`.
+
+### Invalid:
+```typescript
+const café = "naïve";
+// Ünïcödé comment
+```
+
+### Valid:
+```typescript
+const cafe = "na\u00EFve";
+// ASCII comment
+```
+"#
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Category {
+  Identifier,
+  String,
+  /// A string attribute value in JSX, where escape sequences aren't
+  /// supported.
+  JsxString,
+  Comment,
+}
+
+impl Category {
+  fn description(self) -> &'static str {
+    match self {
+      Category::Identifier => "an identifier",
+      Category::String | Category::JsxString => "a string",
+      Category::Comment => "a comment",
+    }
+  }
+
+  fn hint(self, c: char) -> PreferAsciiHint {
+    match self {
+      Category::Identifier => PreferAsciiHint::Rename,
+      Category::String => PreferAsciiHint::Escape(escape_sequence(c)),
+      Category::JsxString | Category::Comment => PreferAsciiHint::Rewrite,
+    }
+  }
+}
+
+/// Returns the escape sequence of the character for a string literal.
+fn escape_sequence(c: char) -> String {
+  let code = c as u32;
+  if code <= 0xffff {
+    format!("\\u{:04X}", code)
+  } else {
+    format!("\\u{{{:X}}}", code)
+  }
+}
+
+/// Reports each non-ASCII character of the text, which starts at `start` in
+/// the source.
+fn check_text(
+  ctx: &mut Context,
+  start: BytePos,
+  text: &str,
+  category: Category,
+) {
+  for (offset, c) in text.char_indices().filter(|(_, c)| !c.is_ascii()) {
+    let lo = start + BytePos(offset as u32);
+    let hi = lo + BytePos(c.len_utf8() as u32);
+    ctx.add_diagnostic_with_hint(
+      Span::new(lo, hi, Default::default()),
+      CODE,
+      PreferAsciiMessage::NonAscii(
+        c.to_string(),
+        format!("U+{:04X}", c as u32),
+        category.description().to_string(),
+      ),
+      category.hint(c),
+    );
+  }
+}
+
+struct PreferAsciiHandler {
+  options: PreferAsciiOptions,
+}
+
+impl Handler for PreferAsciiHandler {
+  fn ident(&mut self, ident: &AstView::Ident, ctx: &mut Context) {
+    if self.options.identifiers {
+      check_text(ctx, ident.span().lo, ident.text(), Category::Identifier);
+    }
+  }
+
+  fn str(&mut self, str: &AstView::Str, ctx: &mut Context) {
+    // The raw and cooked values of a template element are strings as well,
+    // but are checked as part of the element.
+    let category = match str.parent() {
+      Some(AstView::Node::TplElement(_)) => return,
+      Some(AstView::Node::JSXAttr(_)) => Category::JsxString,
+      _ => Category::String,
+    };
+    if self.options.strings {
+      check_text(ctx, str.span().lo, str.text(), category);
+    }
+  }
+
+  fn tpl_element(
+    &mut self,
+    tpl_element: &AstView::TplElement,
+    ctx: &mut Context,
+  ) {
+    if self.options.strings {
+      let start = tpl_element.span().lo;
+      check_text(ctx, start, tpl_element.text(), Category::String);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_ascii_valid() {
+    assert_lint_ok! {
+      PreferAscii,
+      "const cafe = 'naive';",
+      "const a = '\\u00e9';",
+      "const a = `\\u{1F600} ${b}`;",
+      "const \\u0061bc = 1;",
+      "// ASCII comment",
+      "/* ASCII comment */",
+      "obj.prop;",
+    };
+
+    assert_lint_ok! {
+      PreferAscii,
+      options: { "identifiers": false, "strings": false, "comments": false },
+      "const café = 'naïve'; // ü",
+    };
+  }
+
+  #[test]
+  fn prefer_ascii_invalid() {
+    assert_lint_err! {
+      PreferAscii,
+      "const café = 1;": [
+        {
+          col: 9,
+          message: variant!(PreferAsciiMessage, NonAscii, "é", "U+00E9", "an identifier"),
+          hint: PreferAsciiHint::Rename,
+        }
+      ],
+      "obj.naïve;": [
+        {
+          col: 6,
+          message: variant!(PreferAsciiMessage, NonAscii, "ï", "U+00EF", "an identifier"),
+          hint: PreferAsciiHint::Rename,
+        }
+      ],
+      "const a = 'naïve';": [
+        {
+          col: 13,
+          message: variant!(PreferAsciiMessage, NonAscii, "ï", "U+00EF", "a string"),
+          hint: variant!(PreferAsciiHint, Escape, "\\u00EF"),
+        }
+      ],
+      "const a = `😀 ${b}`;": [
+        {
+          col: 11,
+          message: variant!(PreferAsciiMessage, NonAscii, "😀", "U+1F600", "a string"),
+          hint: variant!(PreferAsciiHint, Escape, "\\u{1F600}"),
+        }
+      ],
+      "// Ünïcode": [
+        {
+          col: 3,
+          message: variant!(PreferAsciiMessage, NonAscii, "Ü", "U+00DC", "a comment"),
+          hint: PreferAsciiHint::Rewrite,
+        },
+        {
+          col: 5,
+          message: variant!(PreferAsciiMessage, NonAscii, "ï", "U+00EF", "a comment"),
+          hint: PreferAsciiHint::Rewrite,
+        }
+      ],
+      "<div title=\"é\" />;": {
+        filename: "foo.tsx",
+        errors: [
+          {
+            col: 12,
+            message: variant!(PreferAsciiMessage, NonAscii, "é", "U+00E9", "a string"),
+            hint: PreferAsciiHint::Rewrite,
+          }
+        ],
+      },
+      "/* – */ a;": [
+        {
+          col: 3,
+          message: variant!(PreferAsciiMessage, NonAscii, "–", "U+2013", "a comment"),
+          hint: PreferAsciiHint::Rewrite,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferAscii,
+      options: { "identifiers": false, "comments": false },
+      "const café = 'é'; // é": [
+        {
+          col: 14,
+          message: variant!(PreferAsciiMessage, NonAscii, "é", "U+00E9", "a string"),
+          hint: variant!(PreferAsciiHint, Escape, "\\u00E9"),
+        }
+      ],
+    };
+  }
+}
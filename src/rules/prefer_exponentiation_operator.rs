@@ -0,0 +1,410 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{enclosing_stmt_or_arrow_at_start, is_global};
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, NodeTrait};
+use if_chain::if_chain;
+use swc_common::Spanned;
+
+pub struct PreferExponentiationOperator;
+
+const CODE: &str = "prefer-exponentiation-operator";
+const MESSAGE: &str = "Use the `**` operator instead of `Math.pow()`";
+const HINT: &str = "Rewrite it like `a ** b`";
+const FIX_DESC: &str = "Replace with the `**` operator";
+
+impl LintRule for PreferExponentiationOperator {
+  fn new() -> Box<Self> {
+    Box::new(PreferExponentiationOperator)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferExponentiationOperatorHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using the `**` operator instead of `Math.pow()`
+
+Since ES2016, `a ** b` computes the same as `Math.pow(a, b)`. The operator is
+more concise and reads more like the math it expresses.
+
+### Invalid:
+```typescript
+const squared = Math.pow(x, 2);
+const volume = Math.pow(a + b, 3);
+```
+
+### Valid:
+```typescript
+const squared = x ** 2;
+const volume = (a + b) ** 3;
+```
+"#
+  }
+}
+
+/// Checks if the callee is the global `Math.pow`.
+fn is_math_pow(call_expr: &AstView::CallExpr, ctx: &Context) -> bool {
+  if_chain! {
+    if let AstView::ExprOrSuper::Expr(AstView::Expr::Member(callee)) =
+      &call_expr.callee;
+    if !callee.computed();
+    if let AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)) = &callee.obj;
+    if *obj.sym() == *"Math";
+    if is_global(obj, ctx.scope());
+    if let AstView::Expr::Ident(prop) = &callee.prop;
+    then {
+      *prop.sym() == *"pow"
+    } else {
+      false
+    }
+  }
+}
+
+/// Checks if the expression binds less tightly than `**`, so it has to be
+/// wrapped in parentheses when used as one of its operands.
+fn has_lower_precedence(expr: &AstView::Expr) -> bool {
+  matches!(
+    expr,
+    AstView::Expr::Bin(_)
+      | AstView::Expr::Cond(_)
+      | AstView::Expr::Assign(_)
+      | AstView::Expr::Arrow(_)
+      | AstView::Expr::Seq(_)
+      | AstView::Expr::Yield(_)
+      | AstView::Expr::TsAs(_)
+  )
+}
+
+/// Checks if the base of `**` needs parentheses. On top of the lower
+/// precedence expressions, unary expressions aren't allowed there at all,
+/// e.g. `-a ** b` is a syntax error.
+fn base_needs_parens(expr: &AstView::Expr) -> bool {
+  has_lower_precedence(expr)
+    || matches!(
+      expr,
+      AstView::Expr::Unary(_)
+        | AstView::Expr::Await(_)
+        | AstView::Expr::TsTypeAssertion(_)
+    )
+}
+
+/// Checks if the exponent of `**` needs parentheses. As the operator is right
+/// associative, `a ** b ** c` doesn't.
+fn exponent_needs_parens(expr: &AstView::Expr) -> bool {
+  match expr {
+    AstView::Expr::Bin(bin_expr) => bin_expr.op() != BinaryOp::Exp,
+    _ => has_lower_precedence(expr),
+  }
+}
+
+/// Checks if the `**` expression replacing the call needs parentheses in its
+/// context, e.g. `-Math.pow(a, b)` or `Math.pow(a, b).toFixed()`.
+fn needs_parens(call_expr: &AstView::CallExpr) -> bool {
+  let span = call_expr.span();
+  match call_expr.parent {
+    AstView::Node::BinExpr(bin_expr) => {
+      bin_expr.op() == BinaryOp::Exp && bin_expr.left.span() == span
+    }
+    AstView::Node::UnaryExpr(_)
+    | AstView::Node::AwaitExpr(_)
+    | AstView::Node::TaggedTpl(_) => true,
+    AstView::Node::MemberExpr(member_expr) => member_expr.obj.span() == span,
+    AstView::Node::CallExpr(parent) => parent.callee.span() == span,
+    AstView::Node::NewExpr(parent) => parent.callee.span() == span,
+    _ => false,
+  }
+}
+
+/// Checks if the `**` expression replacing the call needs parentheses so that
+/// it's not parsed as a declaration or a block, e.g. for
+/// `Math.pow(function () {}.length, 2);` or `() => Math.pow({ a }.a, 2)`.
+fn needs_parens_at_start(call_expr: &AstView::CallExpr, text: &str) -> bool {
+  let starts_with_word = |text: &str, word: &str| match text.strip_prefix(word)
+  {
+    Some(rest) => {
+      !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+    }
+    None => false,
+  };
+  match enclosing_stmt_or_arrow_at_start(call_expr.into_node()) {
+    Some(AstView::Node::ExprStmt(_)) => {
+      text.starts_with('{')
+        || starts_with_word(text, "function")
+        || starts_with_word(text, "class")
+        || (starts_with_word(text, "async")
+          && starts_with_word(text["async".len()..].trim_start(), "function"))
+        || (starts_with_word(text, "let")
+          && text["let".len()..].trim_start().starts_with('['))
+    }
+    Some(AstView::Node::ArrowExpr(_)) => text.starts_with('{'),
+    _ => false,
+  }
+}
+
+fn wrap_if(text: &str, needs_parens: bool) -> String {
+  if needs_parens {
+    format!("({})", text)
+  } else {
+    text.to_string()
+  }
+}
+
+struct PreferExponentiationOperatorHandler;
+
+impl Handler for PreferExponentiationOperatorHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if !is_math_pow(call_expr, ctx) {
+      return;
+    }
+
+    let (base, exponent) = match call_expr.args[..] {
+      [base, exponent]
+        if base.spread().is_none() && exponent.spread().is_none() =>
+      {
+        (base.expr, exponent.expr)
+      }
+      _ => return,
+    };
+
+    let span = call_expr.span();
+    // Comments would be lost when rewriting, so only offer a fix for calls
+    // without any.
+    let has_comments = ctx
+      .all_comments()
+      .any(|comment| span.contains(comment.span));
+    if has_comments {
+      ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      return;
+    }
+
+    let replacement = format!(
+      "{} ** {}",
+      wrap_if(base.text(), base_needs_parens(&base)),
+      wrap_if(exponent.text(), exponent_needs_parens(&exponent)),
+    );
+    ctx.add_diagnostic_with_fix(
+      span,
+      CODE,
+      MESSAGE,
+      HINT,
+      FIX_DESC,
+      vec![(
+        span,
+        wrap_if(
+          &replacement,
+          needs_parens(call_expr)
+            || needs_parens_at_start(call_expr, &replacement),
+        ),
+      )],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_exponentiation_operator_valid() {
+    assert_lint_ok! {
+      PreferExponentiationOperator,
+      "a ** b;",
+      "Math.pow();",
+      "Math.pow(a);",
+      "Math.pow(a, b, c);",
+      "Math.pow(...args);",
+      "Math.pow(a, ...b);",
+      "Math['pow'](a, b);",
+      "Math.max(a, b);",
+      "foo.pow(a, b);",
+      "function foo(Math) { Math.pow(a, b); }",
+    };
+  }
+
+  #[test]
+  fn prefer_exponentiation_operator_invalid() {
+    assert_lint_err! {
+      PreferExponentiationOperator,
+      "const a = Math.pow(x, 2);": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const a = x ** 2;"),
+        }
+      ],
+      "Math.pow(a + b, c * d);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(a + b) ** (c * d);"),
+        }
+      ],
+      "Math.pow(-a, -b);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(-a) ** -b;"),
+        }
+      ],
+      "Math.pow(a ** b, c ** d);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(a ** b) ** c ** d;"),
+        }
+      ],
+      "Math.pow(foo.bar, baz());": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo.bar ** baz();"),
+        }
+      ],
+      "Math.pow(a ? b : c, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(a ? b : c) ** 2;"),
+        }
+      ],
+      "async function f() { Math.pow(await a, 2); }": [
+        {
+          col: 21,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { (await a) ** 2; }"),
+        }
+      ],
+      "const a = -Math.pow(x, 2);": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const a = -(x ** 2);"),
+        }
+      ],
+      "Math.pow(a, b).toFixed(2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(a ** b).toFixed(2);"),
+        }
+      ],
+      "Math.pow(a, b) ** c;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(a ** b) ** c;"),
+        }
+      ],
+      "c ** Math.pow(a, b);": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "c ** a ** b;"),
+        }
+      ],
+      "const a = Math.pow(x, 2) + 1;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const a = x ** 2 + 1;"),
+        }
+      ],
+      "Math.pow(function () {}.length, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(function () {}.length ** 2);"),
+        }
+      ],
+      "Math.pow({a}.x, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "({a}.x ** 2);"),
+        }
+      ],
+      "Math.pow(class {}.length, 2) + 1;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(class {}.length ** 2) + 1;"),
+        }
+      ],
+      "Math.pow(async function () {}.length, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(async function () {}.length ** 2);"),
+        }
+      ],
+      "Math.pow(let[0], 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "(let[0] ** 2);"),
+        }
+      ],
+      "const f = () => Math.pow({a}.x, 2);": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const f = () => ({a}.x ** 2);"),
+        }
+      ],
+      "Math.pow(functions.length, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "functions.length ** 2;"),
+        }
+      ],
+      "foo(Math.pow({a}.x, 2));": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo({a}.x ** 2);"),
+        }
+      ],
+      "Math.pow(x /* base */, 2);": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
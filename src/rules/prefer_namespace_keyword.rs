@@ -71,7 +71,7 @@ impl<'c, 'view> Visit for PreferNamespaceKeywordVisitor<'c, 'view> {
         self.context.add_diagnostic(mod_decl.span, CODE, MESSAGE)
       }
     }
-    for stmt in &mod_decl.body {
+    if let Some(stmt) = &mod_decl.body {
       self.visit_ts_namespace_body(stmt, parent)
     }
   }
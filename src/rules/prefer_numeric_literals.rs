@@ -0,0 +1,249 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{Expr, Lit};
+use swc_ecmascript::utils::{ExprExt, Value};
+
+pub struct PreferNumericLiterals;
+
+const CODE: &str = "prefer-numeric-literals";
+const FIX_DESC: &str = "Replace with a numeric literal";
+
+#[derive(Display)]
+enum PreferNumericLiteralsMessage {
+  #[display(fmt = "Use a {} literal instead of `{}()`", _0, _1)]
+  UseLiteral(String, String),
+}
+
+#[derive(Display)]
+enum PreferNumericLiteralsHint {
+  #[display(fmt = "Replace it with `{}`", _0)]
+  Replace(String),
+}
+
+impl LintRule for PreferNumericLiterals {
+  fn new() -> Box<Self> {
+    Box::new(PreferNumericLiterals)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferNumericLiteralsHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using binary, octal and hexadecimal literals instead of `parseInt()`
+
+Numbers in base 2, 8 and 16 can be written directly as literals using the
+`0b`, `0o` and `0x` prefixes. Parsing a constant string with `parseInt()` or
+`Number.parseInt()` does the same at runtime, only less readably.
+
+### Invalid:
+```typescript
+parseInt("111110111", 2) === 503;
+parseInt("767", 8) === 503;
+Number.parseInt("1F7", 16) === 503;
+```
+
+### Valid:
+```typescript
+0b111110111 === 503;
+0o767 === 503;
+0x1F7 === 503;
+
+parseInt(input, 16);
+parseInt("42", 10);
+```
+"#
+  }
+}
+
+/// Returns the name of the function if the callee is the global `parseInt`
+/// or `Number.parseInt`.
+fn parse_int_name(callee: &AstView::Expr, ctx: &Context) -> Option<String> {
+  match callee {
+    AstView::Expr::Ident(ident)
+      if *ident.sym() == *"parseInt" && is_global(ident, ctx.scope()) =>
+    {
+      Some("parseInt".to_string())
+    }
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      match (&member_expr.obj, &member_expr.prop) {
+        (
+          AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+          AstView::Expr::Ident(prop),
+        ) if *obj.sym() == *"Number"
+          && *prop.sym() == *"parseInt"
+          && is_global(obj, ctx.scope()) =>
+        {
+          Some("Number.parseInt".to_string())
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Returns the value of a string literal or a template literal without any
+/// expressions.
+fn static_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      tpl.quasis.first().map(|quasi| quasi.raw.value.to_string())
+    }
+    _ => None,
+  }
+}
+
+/// Returns the radix as an integer together with the name of the literal kind
+/// and its prefix, if there's a literal for it.
+fn literal_kind(radix: f64) -> Option<(u32, &'static str, &'static str)> {
+  [
+    (2, "binary", "0b"),
+    (8, "octal", "0o"),
+    (16, "hexadecimal", "0x"),
+  ]
+  .iter()
+  .copied()
+  .find(|(r, _, _)| (radix - f64::from(*r)).abs() < f64::EPSILON)
+}
+
+struct PreferNumericLiteralsHandler;
+
+impl Handler for PreferNumericLiteralsHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    let name = match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(callee) => match parse_int_name(callee, ctx) {
+        Some(name) => name,
+        None => return,
+      },
+      AstView::ExprOrSuper::Super(_) => return,
+    };
+
+    let (digits, radix) = match &call_expr.inner.args[..] {
+      [digits, radix] if digits.spread.is_none() && radix.spread.is_none() => {
+        (&*digits.expr, &*radix.expr)
+      }
+      _ => return,
+    };
+
+    let (radix, kind, prefix) = match radix.as_number() {
+      Value::Known(radix) => match literal_kind(radix) {
+        Some(kind) => kind,
+        None => return,
+      },
+      Value::Unknown => return,
+    };
+
+    let digits = match static_string(digits) {
+      Some(digits) => digits,
+      None => return,
+    };
+    // `parseInt()` stops at the first invalid character, so only strings
+    // made up of valid digits are equivalent to a literal.
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+      return;
+    }
+
+    let literal = format!("{}{}", prefix, digits);
+    ctx.add_diagnostic_with_fix(
+      call_expr.span(),
+      CODE,
+      PreferNumericLiteralsMessage::UseLiteral(kind.to_string(), name),
+      PreferNumericLiteralsHint::Replace(literal.clone()),
+      FIX_DESC,
+      vec![(call_expr.span(), literal)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_numeric_literals_valid() {
+    assert_lint_ok! {
+      PreferNumericLiterals,
+      "parseInt(1);",
+      "parseInt(1, 3);",
+      "parseInt('1', 10);",
+      "parseInt(foo, 2);",
+      "parseInt('12', 2);",
+      "parseInt('', 16);",
+      "parseInt(' ff', 16);",
+      "parseInt('-101', 2);",
+      "parseInt(`1${foo}`, 2);",
+      "parseInt('101', radix);",
+      "parseInt('101');",
+      "Number.parseInt('101', 10);",
+      "foo.parseInt('101', 2);",
+      "function f(parseInt) { parseInt('101', 2); }",
+      "0b101;",
+    };
+  }
+
+  #[test]
+  fn prefer_numeric_literals_invalid() {
+    assert_lint_err! {
+      PreferNumericLiterals,
+      "parseInt('111110111', 2) === 503;": [
+        {
+          col: 0,
+          message: variant!(PreferNumericLiteralsMessage, UseLiteral, "binary", "parseInt"),
+          hint: variant!(PreferNumericLiteralsHint, Replace, "0b111110111"),
+          fix: (FIX_DESC, "0b111110111 === 503;"),
+        }
+      ],
+      "parseInt('767', 8);": [
+        {
+          col: 0,
+          message: variant!(PreferNumericLiteralsMessage, UseLiteral, "octal", "parseInt"),
+          hint: variant!(PreferNumericLiteralsHint, Replace, "0o767"),
+          fix: (FIX_DESC, "0o767;"),
+        }
+      ],
+      "const a = Number.parseInt('1F7', 16);": [
+        {
+          col: 10,
+          message: variant!(PreferNumericLiteralsMessage, UseLiteral, "hexadecimal", "Number.parseInt"),
+          hint: variant!(PreferNumericLiteralsHint, Replace, "0x1F7"),
+          fix: (FIX_DESC, "const a = 0x1F7;"),
+        }
+      ],
+      "parseInt(`11`, 2);": [
+        {
+          col: 0,
+          message: variant!(PreferNumericLiteralsMessage, UseLiteral, "binary", "parseInt"),
+          hint: variant!(PreferNumericLiteralsHint, Replace, "0b11"),
+          fix: (FIX_DESC, "0b11;"),
+        }
+      ],
+      "parseInt('ff', 0x10);": [
+        {
+          col: 0,
+          message: variant!(PreferNumericLiteralsMessage, UseLiteral, "hexadecimal", "parseInt"),
+          hint: variant!(PreferNumericLiteralsHint, Replace, "0xff"),
+          fix: (FIX_DESC, "0xff;"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,333 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{enclosing_stmt_or_arrow_at_start, is_global};
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use if_chain::if_chain;
+use swc_common::Spanned;
+
+pub struct PreferObjectSpread;
+
+const CODE: &str = "prefer-object-spread";
+
+#[derive(Display)]
+enum PreferObjectSpreadMessage {
+  #[display(fmt = "Use an object spread instead of `Object.assign`")]
+  UseSpread,
+  #[display(fmt = "Use an object literal instead of `Object.assign`")]
+  UseLiteral,
+}
+
+#[derive(Display)]
+enum PreferObjectSpreadHint {
+  #[display(fmt = "Rewrite it like `{{ ...foo, ...bar }}`")]
+  UseSpread,
+  #[display(fmt = "Rewrite it like `{{ foo: bar }}`")]
+  UseLiteral,
+}
+
+#[derive(Display)]
+enum PreferObjectSpreadFix {
+  #[display(fmt = "Replace with an object spread")]
+  UseSpread,
+  #[display(fmt = "Replace with an object literal")]
+  UseLiteral,
+}
+
+impl LintRule for PreferObjectSpread {
+  fn new() -> Box<Self> {
+    Box::new(PreferObjectSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferObjectSpreadHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using object spread instead of `Object.assign` with an object literal as the first argument
+
+When `Object.assign` is called with an object literal as its first argument,
+it's just creating a new object from the other arguments. Object spread syntax
+does the same thing in a more concise way.
+
+### Invalid:
+```typescript
+Object.assign({}, foo);
+Object.assign({}, foo, bar);
+Object.assign({ foo: 1 }, bar);
+Object.assign({});
+```
+
+### Valid:
+```typescript
+({ ...foo });
+({ ...foo, ...bar });
+({ foo: 1, ...bar });
+
+// the first argument is mutated, which is intended
+Object.assign(foo, bar);
+```
+"#
+  }
+}
+
+/// Checks if the callee is the global `Object.assign`.
+fn is_object_assign(call_expr: &AstView::CallExpr, ctx: &Context) -> bool {
+  if_chain! {
+    if let AstView::ExprOrSuper::Expr(AstView::Expr::Member(callee)) =
+      &call_expr.callee;
+    if !callee.computed();
+    if let AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)) = &callee.obj;
+    if *obj.sym() == *"Object";
+    if is_global(obj, ctx.scope());
+    if let AstView::Expr::Ident(prop) = &callee.prop;
+    then {
+      *prop.sym() == *"assign"
+    } else {
+      false
+    }
+  }
+}
+
+/// Checks if the object literal has a getter or a setter, which can't simply be
+/// moved into another object literal.
+fn has_accessor(object_lit: &AstView::ObjectLit) -> bool {
+  object_lit.props.iter().any(|prop| {
+    matches!(
+      prop,
+      AstView::PropOrSpread::Prop(AstView::Prop::Getter(_))
+        | AstView::PropOrSpread::Prop(AstView::Prop::Setter(_))
+    )
+  })
+}
+
+/// Builds an object literal equivalent to the given arguments of
+/// `Object.assign`.
+fn build_object_lit(args: &[&AstView::ExprOrSpread]) -> String {
+  let mut parts = Vec::new();
+  for arg in args {
+    if let AstView::Expr::Object(object_lit) = arg.expr {
+      parts.extend(object_lit.props.iter().map(|p| p.text().to_string()));
+    } else {
+      parts.push(format!("...{}", arg.expr.text()));
+    }
+  }
+
+  if parts.is_empty() {
+    "{}".to_string()
+  } else {
+    format!("{{ {} }}", parts.join(", "))
+  }
+}
+
+struct PreferObjectSpreadHandler;
+
+impl Handler for PreferObjectSpreadHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if_chain! {
+      if is_object_assign(call_expr, ctx);
+      if let Some(first) = call_expr.args.first();
+      if let AstView::Expr::Object(_) = first.expr;
+      if call_expr.args.iter().all(|arg| arg.spread().is_none());
+      if !call_expr.args.iter().any(|arg| {
+        matches!(arg.expr, AstView::Expr::Object(o) if has_accessor(o))
+      });
+      then {
+        let (message, hint, fix) = if call_expr.args.len() == 1 {
+          (
+            PreferObjectSpreadMessage::UseLiteral,
+            PreferObjectSpreadHint::UseLiteral,
+            PreferObjectSpreadFix::UseLiteral,
+          )
+        } else {
+          (
+            PreferObjectSpreadMessage::UseSpread,
+            PreferObjectSpreadHint::UseSpread,
+            PreferObjectSpreadFix::UseSpread,
+          )
+        };
+
+        let span = call_expr.span();
+        // Comments would be lost when rewriting, so only offer a fix for
+        // calls without any.
+        let has_comments = ctx
+          .all_comments()
+          .any(|comment| span.contains(comment.span));
+        if has_comments {
+          ctx.add_diagnostic_with_hint(span, CODE, message, hint);
+          return;
+        }
+
+        let mut object_lit = build_object_lit(&call_expr.args);
+        // An object literal at the start of a statement or an arrow function
+        // body would be parsed as a block.
+        if enclosing_stmt_or_arrow_at_start(call_expr.into_node()).is_some() {
+          object_lit = format!("({})", object_lit);
+        }
+        ctx.add_diagnostic_with_fix(
+          span,
+          CODE,
+          message,
+          hint,
+          fix,
+          vec![(span, object_lit)],
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_object_spread_valid() {
+    assert_lint_ok! {
+      PreferObjectSpread,
+      "Object.assign();",
+      "Object.assign(foo);",
+      "Object.assign(foo, bar);",
+      "Object.assign(foo, { bar: 1 });",
+      "Object.assign({}, ...objects);",
+      "Object.assign(...objects);",
+      "Object.assign({ get a() { return 1; } }, bar);",
+      "Object.assign({}, { set a(v) {} });",
+      "Object['assign']({}, foo);",
+      "foo.assign({}, bar);",
+      "({ ...foo });",
+      "const Object = {}; Object.assign({}, foo);",
+      "function f(Object) { Object.assign({}, foo); }",
+    };
+  }
+
+  #[test]
+  fn prefer_object_spread_invalid() {
+    assert_lint_err! {
+      PreferObjectSpread,
+      "const a = Object.assign({}, foo);": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "const a = { ...foo };"),
+        }
+      ],
+      "const a = Object.assign({}, foo, bar);": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "const a = { ...foo, ...bar };"),
+        }
+      ],
+      "const a = Object.assign({ a: 1, b }, foo, { c: 2 });": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "const a = { a: 1, b, ...foo, c: 2 };"),
+        }
+      ],
+      "const a = Object.assign({}, foo.bar(), a ? b : c, (d, e));": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (
+            PreferObjectSpreadFix::UseSpread,
+            "const a = { ...foo.bar(), ...a ? b : c, ...(d, e) };"
+          ),
+        }
+      ],
+      "const a = Object.assign({});": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseLiteral,
+          hint: PreferObjectSpreadHint::UseLiteral,
+          fix: (PreferObjectSpreadFix::UseLiteral, "const a = {};"),
+        }
+      ],
+      "const a = Object.assign({ foo: bar });": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseLiteral,
+          hint: PreferObjectSpreadHint::UseLiteral,
+          fix: (PreferObjectSpreadFix::UseLiteral, "const a = { foo: bar };"),
+        }
+      ],
+      "Object.assign({}, foo);": [
+        {
+          col: 0,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "({ ...foo });"),
+        }
+      ],
+      "Object.assign({}, foo).bar;": [
+        {
+          col: 0,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "({ ...foo }).bar;"),
+        }
+      ],
+      "const f = () => Object.assign({}, foo);": [
+        {
+          col: 16,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "const f = () => ({ ...foo });"),
+        }
+      ],
+      "const f = () => bar(Object.assign({}, foo));": [
+        {
+          col: 20,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (PreferObjectSpreadFix::UseSpread, "const f = () => bar({ ...foo });"),
+        }
+      ],
+      "const a = Object.assign({}, foo, Object.assign({}, bar));": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (
+            PreferObjectSpreadFix::UseSpread,
+            "const a = { ...foo, ...Object.assign({}, bar) };"
+          ),
+        },
+        {
+          col: 33,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+          fix: (
+            PreferObjectSpreadFix::UseSpread,
+            "const a = Object.assign({}, foo, { ...bar });"
+          ),
+        }
+      ],
+      "const a = Object.assign({}, /* comment */ foo);": [
+        {
+          col: 10,
+          message: PreferObjectSpreadMessage::UseSpread,
+          hint: PreferObjectSpreadHint::UseSpread,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,279 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use dprint_swc_ecma_ast_view as AstView;
+use if_chain::if_chain;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  AssignOp, BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Pat,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct PreferPromiseRejectErrors;
+
+const CODE: &str = "prefer-promise-reject-errors";
+const MESSAGE: &str = "Expected the Promise rejection reason to be an Error";
+const HINT: &str =
+  "Reject with an `Error` object, e.g. `new Error(\"something failed\")`";
+
+impl LintRule for PreferPromiseRejectErrors {
+  fn new() -> Box<Self> {
+    Box::new(PreferPromiseRejectErrors)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferPromiseRejectErrorsHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires Promises to be rejected with `Error` objects
+
+Like thrown values, rejection reasons end up in `catch` handlers. Only `Error`
+objects carry a stack trace and behave the way such handlers usually expect, so
+rejecting with a literal, an object literal or nothing at all makes failures
+harder to debug. This is the Promise counterpart of `no-throw-literal`.
+
+Calls to `Promise.reject()` and to the `reject` function of a `new Promise()`
+executor are checked.
+
+### Invalid:
+```typescript
+Promise.reject("something failed");
+Promise.reject();
+
+new Promise((resolve, reject) => {
+  reject({ code: 404 });
+});
+```
+
+### Valid:
+```typescript
+Promise.reject(new Error("something failed"));
+Promise.reject(err);
+
+new Promise((resolve, reject) => {
+  reject(new NotFoundError());
+});
+```
+"#
+  }
+}
+
+/// Checks if the expression may evaluate to an `Error` object. Anything that
+/// can't be known statically, like an identifier or a call, is assumed to be
+/// one.
+fn could_be_error(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(ident) => ident.sym != *"undefined",
+    Expr::This(_)
+    | Expr::Call(_)
+    | Expr::New(_)
+    | Expr::Member(_)
+    | Expr::TaggedTpl(_)
+    | Expr::Yield(_)
+    | Expr::Await(_)
+    | Expr::OptChain(_) => true,
+    Expr::Assign(assign_expr) => match assign_expr.op {
+      AssignOp::Assign | AssignOp::AndAssign => {
+        could_be_error(&assign_expr.right)
+      }
+      AssignOp::OrAssign | AssignOp::NullishAssign => true,
+      _ => false,
+    },
+    Expr::Seq(seq_expr) => seq_expr
+      .exprs
+      .last()
+      .map_or(false, |expr| could_be_error(expr)),
+    Expr::Bin(bin_expr) => match bin_expr.op {
+      BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+        could_be_error(&bin_expr.left) || could_be_error(&bin_expr.right)
+      }
+      BinaryOp::LogicalAnd => could_be_error(&bin_expr.right),
+      _ => false,
+    },
+    Expr::Cond(cond_expr) => {
+      could_be_error(&cond_expr.cons) || could_be_error(&cond_expr.alt)
+    }
+    Expr::Paren(paren_expr) => could_be_error(&paren_expr.expr),
+    Expr::TsAs(ts_as) => could_be_error(&ts_as.expr),
+    Expr::TsNonNull(ts_non_null) => could_be_error(&ts_non_null.expr),
+    Expr::TsTypeAssertion(assertion) => could_be_error(&assertion.expr),
+    _ => false,
+  }
+}
+
+fn check_reason(span: Span, args: &[ExprOrSpread], ctx: &mut Context) {
+  let is_error = match args.first() {
+    Some(reason) => reason.spread.is_some() || could_be_error(&reason.expr),
+    None => false,
+  };
+  if !is_error {
+    ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+fn is_global_promise(expr: &AstView::Expr, ctx: &Context) -> bool {
+  match expr {
+    AstView::Expr::Ident(ident) => {
+      *ident.sym() == *"Promise" && is_global(ident, ctx.scope())
+    }
+    _ => false,
+  }
+}
+
+/// Returns the name bound to the second parameter of the executor, which is
+/// the `reject` function.
+fn reject_param(executor: &Expr) -> Option<Id> {
+  let pat = match executor {
+    Expr::Paren(paren_expr) => return reject_param(&paren_expr.expr),
+    Expr::Fn(fn_expr) => &fn_expr.function.params.get(1)?.pat,
+    Expr::Arrow(arrow_expr) => arrow_expr.params.get(1)?,
+    _ => return None,
+  };
+  match pat {
+    Pat::Ident(binding_ident) => Some(binding_ident.id.to_id()),
+    _ => None,
+  }
+}
+
+/// Collects calls of the `reject` function inside an executor.
+struct RejectCallCollector<'c, 'view> {
+  reject: Id,
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> Visit for RejectCallCollector<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Ident(ident) = &**callee {
+        if ident.to_id() == self.reject {
+          check_reason(call_expr.span, &call_expr.args, self.context);
+        }
+      }
+    }
+  }
+}
+
+struct PreferPromiseRejectErrorsHandler;
+
+impl Handler for PreferPromiseRejectErrorsHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if_chain! {
+      if let AstView::ExprOrSuper::Expr(AstView::Expr::Member(callee)) =
+        &call_expr.callee;
+      if !callee.computed();
+      if let AstView::ExprOrSuper::Expr(obj) = &callee.obj;
+      if is_global_promise(obj, ctx);
+      if let AstView::Expr::Ident(prop) = &callee.prop;
+      if *prop.sym() == *"reject";
+      then {
+        check_reason(call_expr.span(), &call_expr.inner.args, ctx);
+      }
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &AstView::NewExpr, ctx: &mut Context) {
+    if !is_global_promise(&new_expr.callee, ctx) {
+      return;
+    }
+    let executor = match new_expr.inner.args.as_deref() {
+      Some([executor, ..]) if executor.spread.is_none() => &*executor.expr,
+      _ => return,
+    };
+    if let Some(reject) = reject_param(executor) {
+      let mut collector = RejectCallCollector {
+        reject,
+        context: ctx,
+      };
+      executor.visit_with(&DUMMY_NODE, &mut collector);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_promise_reject_errors_valid() {
+    assert_lint_ok! {
+      PreferPromiseRejectErrors,
+      "Promise.reject(new Error('foo'));",
+      "Promise.reject(new TypeError('foo'));",
+      "Promise.reject(err);",
+      "Promise.reject(createError());",
+      "Promise.reject(this.error);",
+      "Promise.reject(foo || new Error());",
+      "Promise.reject(cond ? err : new Error());",
+      "Promise.reject(...args);",
+      "Promise.resolve('foo');",
+      "foo.reject('foo');",
+      "Promise['reject']('foo');",
+      "function f(Promise) { Promise.reject('foo'); }",
+      "new Promise((resolve, reject) => { reject(new Error('foo')); });",
+      "new Promise(function (resolve, reject) { reject(err); });",
+      "new Promise((resolve, reject) => { setTimeout(() => reject(new Error()), 10); });",
+      "new Promise((resolve) => { resolve('foo'); });",
+      "new Promise((resolve, reject) => { const f = (reject) => reject('foo'); });",
+      "new Promise((resolve, { reject }) => { reject('foo'); });",
+      "new Promise(executor);",
+      "new Foo((resolve, reject) => { reject('foo'); });",
+      "reject('foo');",
+    };
+  }
+
+  #[test]
+  fn prefer_promise_reject_errors_invalid() {
+    assert_lint_err! {
+      PreferPromiseRejectErrors,
+      "Promise.reject('foo');": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Promise.reject(1);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Promise.reject(null);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Promise.reject(undefined);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Promise.reject();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Promise.reject({ message: 'foo' });": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "Promise.reject(`foo ${bar}`);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "Promise.reject(cond ? 'foo' : 'bar');": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "const p = Promise.reject(a + b);": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "new Promise((resolve, reject) => { reject('foo'); });": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
+      "new Promise(function (resolve, reject) { reject({ code: 404 }); });": [
+        { col: 41, message: MESSAGE, hint: HINT }
+      ],
+      "new Promise((resolve, fail) => { setTimeout(() => fail(), 10); });": [
+        { col: 50, message: MESSAGE, hint: HINT }
+      ],
+      "new Promise((resolve, reject) => { if (a) reject(); else reject(new Error()); });": [
+        { col: 42, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,467 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::static_member_prop_name;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, Accessibility};
+use if_chain::if_chain;
+use std::collections::HashSet;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, Class, Constructor, Expr, ExprOrSuper, ForInStmt,
+  ForOfStmt, Function, MemberExpr, ObjectPatProp, Pat, PatOrExpr, UpdateExpr,
+  VarDeclOrPat,
+};
+use swc_ecmascript::visit::{Node, Visit, VisitWith};
+
+pub struct PreferReadonly;
+
+const CODE: &str = "prefer-readonly";
+const HINT: &str = "Mark it as `readonly`";
+const FIX_DESC: &str = "Add `readonly`";
+
+#[derive(Display)]
+enum PreferReadonlyMessage {
+  #[display(fmt = "Private member `{}` is never reassigned", _0)]
+  NeverReassigned(String),
+}
+
+impl LintRule for PreferReadonly {
+  fn new() -> Box<Self> {
+    Box::new(PreferReadonly)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferReadonlyHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires private members that are never reassigned to be marked `readonly`
+
+A private field can only be changed from inside its class, so if it's only
+assigned in its initializer or in the constructor, it's effectively read-only.
+Marking it `readonly` documents that and lets the type checker enforce it.
+
+This applies to fields declared with `private`, to `#private` fields and to
+`private` constructor parameter properties. Assignments in functions nested in
+the constructor, including arrow functions, count as reassignments, since
+`readonly` members can't be assigned there either.
+
+### Invalid:
+```typescript
+class Foo {
+  private bar = 1;
+  #baz: string;
+
+  constructor(private qux: number) {
+    this.#baz = "baz";
+  }
+
+  getBar() {
+    return this.bar;
+  }
+}
+```
+
+### Valid:
+```typescript
+class Foo {
+  private readonly bar = 1;
+  private count = 0;
+  readonly #baz: string;
+
+  constructor(private readonly qux: number) {
+    this.#baz = "baz";
+  }
+
+  increment() {
+    this.count++;
+  }
+}
+```
+"#
+  }
+}
+
+/// A private member that could be `readonly`.
+struct Candidate {
+  name: String,
+  span: Span,
+  /// Where `readonly` should be inserted.
+  insert_at: Span,
+}
+
+fn insertion_point(span: Span) -> Span {
+  Span::new(span.lo, span.lo, Default::default())
+}
+
+fn class_prop_candidate(prop: &AstView::ClassProp) -> Option<Candidate> {
+  if prop.accessibility() != Some(Accessibility::Private)
+    || prop.readonly()
+    || prop.declare()
+    || prop.computed()
+  {
+    return None;
+  }
+
+  match prop.key {
+    AstView::Expr::Ident(key) => Some(Candidate {
+      name: key.sym().to_string(),
+      span: prop.span(),
+      insert_at: insertion_point(key.span()),
+    }),
+    _ => None,
+  }
+}
+
+fn collect_candidates(class: &AstView::Class) -> Vec<Candidate> {
+  let mut candidates = Vec::new();
+
+  for member in &class.body {
+    match member {
+      AstView::ClassMember::ClassProp(prop) => {
+        candidates.extend(class_prop_candidate(prop));
+      }
+      AstView::ClassMember::PrivateProp(prop) if !prop.readonly() => {
+        candidates.push(Candidate {
+          name: format!("#{}", prop.key.id.sym()),
+          span: prop.span(),
+          insert_at: insertion_point(prop.key.span()),
+        });
+      }
+      AstView::ClassMember::Constructor(constructor) => {
+        for param in &constructor.params {
+          if_chain! {
+            if let AstView::ParamOrTsParamProp::TsParamProp(param_prop) = param;
+            if param_prop.accessibility() == Some(Accessibility::Private);
+            if !param_prop.readonly();
+            then {
+              let (ident, span) = match &param_prop.param {
+                AstView::TsParamPropParam::Ident(binding) => {
+                  (binding.id, binding.span())
+                }
+                AstView::TsParamPropParam::Assign(assign) => {
+                  match &assign.left {
+                    AstView::Pat::Ident(binding) => (binding.id, assign.span()),
+                    _ => continue,
+                  }
+                }
+              };
+              candidates.push(Candidate {
+                name: ident.sym().to_string(),
+                span: param_prop.span(),
+                insert_at: insertion_point(span),
+              });
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  candidates
+}
+
+/// Collects the names of members that are assigned outside of the
+/// constructor, e.g. `bar` for `this.bar = 1` and `#baz` for `other.#baz++`.
+#[derive(Default)]
+struct ModificationCollector {
+  modified: HashSet<String>,
+  /// Whether we're directly in the constructor body, where assignments to
+  /// `this` members are allowed.
+  in_constructor: bool,
+}
+
+impl ModificationCollector {
+  fn with_constructor(
+    &mut self,
+    in_constructor: bool,
+    f: impl FnOnce(&mut Self),
+  ) {
+    let prev = self.in_constructor;
+    self.in_constructor = in_constructor;
+    f(self);
+    self.in_constructor = prev;
+  }
+
+  fn mark_expr(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Member(member_expr) => self.mark_member(member_expr),
+      Expr::Paren(paren) => self.mark_expr(&paren.expr),
+      _ => {}
+    }
+  }
+
+  fn mark_member(&mut self, member_expr: &MemberExpr) {
+    let name = match &*member_expr.prop {
+      Expr::PrivateName(private_name) => format!("#{}", private_name.id.sym),
+      _ => match static_member_prop_name(member_expr) {
+        Some(name) => name,
+        None => return,
+      },
+    };
+    let is_this = matches!(
+      &member_expr.obj,
+      ExprOrSuper::Expr(obj) if matches!(&**obj, Expr::This(_))
+    );
+
+    if !(self.in_constructor && is_this) {
+      self.modified.insert(name);
+    }
+  }
+
+  fn mark_pat(&mut self, pat: &Pat) {
+    match pat {
+      Pat::Expr(expr) => self.mark_expr(expr),
+      Pat::Array(array_pat) => {
+        for elem in array_pat.elems.iter().flatten() {
+          self.mark_pat(elem);
+        }
+      }
+      Pat::Object(object_pat) => {
+        for prop in &object_pat.props {
+          match prop {
+            ObjectPatProp::KeyValue(key_value) => {
+              self.mark_pat(&key_value.value)
+            }
+            ObjectPatProp::Rest(rest) => self.mark_pat(&rest.arg),
+            ObjectPatProp::Assign(_) => {}
+          }
+        }
+      }
+      Pat::Assign(assign_pat) => self.mark_pat(&assign_pat.left),
+      Pat::Rest(rest_pat) => self.mark_pat(&rest_pat.arg),
+      Pat::Ident(_) | Pat::Invalid(_) => {}
+    }
+  }
+}
+
+impl Visit for ModificationCollector {
+  fn visit_class(&mut self, _class: &Class, _: &dyn Node) {
+    // Nested classes are checked on their own.
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_constructor(true, |collector| {
+      constructor.visit_children_with(collector)
+    });
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_constructor(false, |collector| {
+      function.visit_children_with(collector)
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_constructor(false, |collector| {
+      arrow_expr.visit_children_with(collector)
+    });
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    match &assign_expr.left {
+      PatOrExpr::Expr(expr) => self.mark_expr(expr),
+      PatOrExpr::Pat(pat) => self.mark_pat(pat),
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _: &dyn Node) {
+    self.mark_expr(&update_expr.arg);
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_in_stmt.left {
+      self.mark_pat(pat);
+    }
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_of_stmt.left {
+      self.mark_pat(pat);
+    }
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+struct PreferReadonlyHandler;
+
+impl Handler for PreferReadonlyHandler {
+  fn class(&mut self, class: &AstView::Class, ctx: &mut Context) {
+    let candidates = collect_candidates(class);
+    if candidates.is_empty() {
+      return;
+    }
+
+    let mut collector = ModificationCollector::default();
+    for member in &class.inner.body {
+      member.visit_with(&DUMMY_NODE, &mut collector);
+    }
+
+    for candidate in candidates {
+      if collector.modified.contains(&candidate.name) {
+        continue;
+      }
+      ctx.add_diagnostic_with_fix(
+        candidate.span,
+        CODE,
+        PreferReadonlyMessage::NeverReassigned(candidate.name),
+        HINT,
+        FIX_DESC,
+        vec![(candidate.insert_at, "readonly ".to_string())],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_readonly_valid() {
+    assert_lint_ok! {
+      PreferReadonly,
+      "class Foo { bar = 1; }",
+      "class Foo { public bar = 1; protected baz = 1; }",
+      "class Foo { private readonly bar = 1; }",
+      "class Foo { readonly #bar = 1; }",
+      "class Foo { private [bar] = 1; }",
+      "class Foo { private bar = 1; baz() { this.bar = 2; } }",
+      "class Foo { private bar = 1; baz() { this['bar'] = 2; } }",
+      "class Foo { private bar = 1; baz() { this[`bar`]++; } }",
+      "class Foo { private bar = 1; baz() { this.bar++; } }",
+      "class Foo { private bar = 1; baz() { this.bar += 1; } }",
+      "class Foo { private bar = 1; baz() { [this.bar] = [2]; } }",
+      "class Foo { private bar = 1; baz() { ({ a: this.bar } = { a: 2 }); } }",
+      "class Foo { private bar = 1; baz() { for (this.bar of [1]) {} } }",
+      "class Foo { #bar = 1; baz(other: Foo) { other.#bar = 2; } }",
+      "class Foo { #bar = 1; baz() { (this.#bar) = 2; } }",
+      "class Foo { private bar = 1; constructor() { setTimeout(() => { this.bar = 2; }); } }",
+      "class Foo { private bar = 1; constructor() { function baz() { this.bar = 2; } } }",
+      "class Foo { private static bar = 1; static baz() { Foo.bar = 2; } }",
+      "class Foo { constructor(private readonly bar: number) {} }",
+      "class Foo { constructor(public bar: number) {} }",
+      "class Foo { constructor(private bar: number) {} baz() { this.bar = 1; } }",
+      "class Foo { constructor(private bar = 1) {} baz() { this.bar = 1; } }",
+    };
+  }
+
+  #[test]
+  fn prefer_readonly_invalid() {
+    assert_lint_err! {
+      PreferReadonly,
+      "class Foo { private bar = 1; }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { private readonly bar = 1; }"),
+        }
+      ],
+      "class Foo { private static bar = 1; }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { private static readonly bar = 1; }"),
+        }
+      ],
+      "class Foo { #bar = 1; baz() { return this.#bar; } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "#bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { readonly #bar = 1; baz() { return this.#bar; } }"),
+        }
+      ],
+      "class Foo { private bar: number; constructor() { this.bar = 1; } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar: number; constructor() { this.bar = 1; } }"
+          ),
+        }
+      ],
+      "class Foo { private bar: number; constructor() { this['bar'] = 1; } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar: number; constructor() { this['bar'] = 1; } }"
+          ),
+        }
+      ],
+      "class Foo { private bar = 1; baz() { this[bar] = 2; } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar = 1; baz() { this[bar] = 2; } }"
+          ),
+        }
+      ],
+      "class Foo { private bar = 1; baz() { this.qux = 2; other.bar; } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar = 1; baz() { this.qux = 2; other.bar; } }"
+          ),
+        }
+      ],
+      "class Foo { constructor(private bar: number) {} }": [
+        {
+          col: 24,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { constructor(private readonly bar: number) {} }"),
+        }
+      ],
+      "class Foo { constructor(private bar = 1) {} }": [
+        {
+          col: 24,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (FIX_DESC, "class Foo { constructor(private readonly bar = 1) {} }"),
+        }
+      ],
+      "class Foo { private bar = 1; baz() { class Qux { bar = 1; m() { this.bar = 2; } } } }": [
+        {
+          col: 12,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "bar"),
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar = 1; baz() { class Qux { bar = 1; m() { this.bar = 2; } } } }"
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,302 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{is_global, StringRepr};
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{Expr, ExprOrSpread, Lit};
+
+#[derive(Default)]
+pub struct PreferRegexLiterals {
+  options: PreferRegexLiteralsOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct PreferRegexLiteralsOptions {
+  /// Also reports regex literals needlessly passed to `RegExp`, like
+  /// `new RegExp(/abc/)`.
+  disallow_redundant_wrapping: bool,
+}
+
+const CODE: &str = "prefer-regex-literals";
+
+#[derive(Display)]
+enum PreferRegexLiteralsMessage {
+  #[display(
+    fmt = "Use a regular expression literal instead of the `RegExp` constructor"
+  )]
+  UseLiteral,
+  #[display(fmt = "Regular expression literal is unnecessarily wrapped")]
+  RedundantWrapping,
+}
+
+#[derive(Display)]
+enum PreferRegexLiteralsHint {
+  #[display(fmt = "Write the pattern as a regular expression literal")]
+  UseLiteral,
+  #[display(fmt = "Remove the `RegExp` call and use `{}` directly", _0)]
+  RedundantWrapping(String),
+}
+
+impl LintRule for PreferRegexLiterals {
+  fn new() -> Box<Self> {
+    Box::new(PreferRegexLiterals::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferRegexLiteralsHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using regular expression literals instead of the `RegExp` constructor
+
+When the pattern is known in advance, a regular expression literal is shorter
+and doesn't need its backslashes escaped twice, which makes it much easier to
+read. It's also checked for syntax errors when the code is parsed rather than
+when the line runs. The constructor is only needed for patterns built
+dynamically.
+
+Calls with string literals or template literals without expressions are
+reported. With `disallowRedundantWrapping` (`false` by default), regular
+expression literals passed to `RegExp` are reported as well, unless the call
+replaces the flags of the literal, like `new RegExp(/abc/u, "g")`.
+
+### Invalid:
+```typescript
+const digits = new RegExp("\\d+");
+const word = RegExp("abc", "u");
+const tpl = new RegExp(`^\\d\\.$`);
+
+// with { "disallowRedundantWrapping": true }
+const wrapped = new RegExp(/abc/);
+```
+
+### Valid:
+```typescript
+const digits = /\d+/;
+const word = /abc/u;
+const dynamic = new RegExp(`^${prefix}`);
+const fromInput = new RegExp(input, "g");
+```
+"#
+  }
+}
+
+/// Checks if the reference to `RegExp` is a global one.
+fn is_global_regexp(callee: &AstView::Expr, ctx: &Context) -> bool {
+  match callee {
+    AstView::Expr::Ident(ident) => {
+      *ident.sym() == *"RegExp" && is_global(ident, ctx.scope())
+    }
+    _ => false,
+  }
+}
+
+/// Returns the value of the expression if it's a string literal or a template
+/// literal without any expressions.
+fn static_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    Expr::Tpl(tpl) => tpl.string_repr(),
+    _ => None,
+  }
+}
+
+struct PreferRegexLiteralsHandler {
+  options: PreferRegexLiteralsOptions,
+}
+
+impl PreferRegexLiteralsHandler {
+  fn check(&self, span: Span, args: &[ExprOrSpread], ctx: &mut Context) {
+    let (pattern, flags) = match args {
+      [pattern] => (pattern, None),
+      [pattern, flags] => (pattern, Some(flags)),
+      _ => return,
+    };
+    if pattern.spread.is_some() {
+      return;
+    }
+    let flags = match flags {
+      Some(ExprOrSpread { spread: None, expr }) => match static_string(expr) {
+        Some(flags) => Some(flags),
+        None => return,
+      },
+      Some(_) => return,
+      None => None,
+    };
+
+    let (message, hint) = match &*pattern.expr {
+      expr if static_string(expr).is_some() => (
+        PreferRegexLiteralsMessage::UseLiteral,
+        PreferRegexLiteralsHint::UseLiteral,
+      ),
+      Expr::Lit(Lit::Regex(regex))
+        if self.options.disallow_redundant_wrapping =>
+      {
+        // The flags argument replaces the flags of the literal, so the call
+        // is only redundant if the literal doesn't have any.
+        let flags = match flags {
+          Some(_) if !regex.flags.is_empty() => return,
+          Some(flags) => flags,
+          None => regex.flags.to_string(),
+        };
+        (
+          PreferRegexLiteralsMessage::RedundantWrapping,
+          PreferRegexLiteralsHint::RedundantWrapping(format!(
+            "/{}/{}",
+            regex.exp, flags
+          )),
+        )
+      }
+      _ => return,
+    };
+    ctx.add_diagnostic_with_hint(span, CODE, message, hint);
+  }
+}
+
+impl Handler for PreferRegexLiteralsHandler {
+  fn new_expr(&mut self, new_expr: &AstView::NewExpr, ctx: &mut Context) {
+    if is_global_regexp(&new_expr.callee, ctx) {
+      let args = new_expr.inner.args.as_deref().unwrap_or_default();
+      self.check(new_expr.span(), args, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if let AstView::ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if is_global_regexp(callee, ctx) {
+        self.check(call_expr.span(), &call_expr.inner.args, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_regex_literals_valid() {
+    assert_lint_ok! {
+      PreferRegexLiterals,
+      "/abc/;",
+      "new RegExp(pattern);",
+      "RegExp(pattern, 'g');",
+      "new RegExp('abc', flags);",
+      "new RegExp(`^${prefix}`);",
+      "new RegExp('a' + 'b');",
+      "new RegExp();",
+      "new RegExp(...args);",
+      "new RegExp('abc', ...flags);",
+      "new RegExp('abc', 'g', extra);",
+      "new RegExp(/abc/);",
+      "new foo.RegExp('abc');",
+      "function f(RegExp) { new RegExp('abc'); }",
+      "class RegExp {} new RegExp('abc');",
+    };
+
+    assert_lint_ok! {
+      PreferRegexLiterals,
+      options: { "disallowRedundantWrapping": true },
+      "new RegExp(/abc/, flags);",
+      "new RegExp(pattern);",
+      "new RegExp(/x/u, 'g');",
+      "RegExp(/abc/i, 'i');",
+    };
+  }
+
+  #[test]
+  fn prefer_regex_literals_invalid() {
+    assert_lint_err! {
+      PreferRegexLiterals,
+      "new RegExp('abc');": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::UseLiteral,
+          hint: PreferRegexLiteralsHint::UseLiteral,
+        }
+      ],
+      "RegExp('abc', 'u');": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::UseLiteral,
+          hint: PreferRegexLiteralsHint::UseLiteral,
+        }
+      ],
+      "const re = new RegExp(`^\\d\\.$`, `g`);": [
+        {
+          col: 11,
+          message: PreferRegexLiteralsMessage::UseLiteral,
+          hint: PreferRegexLiteralsHint::UseLiteral,
+        }
+      ],
+      "function f() { return new RegExp('\\\\d+'); }": [
+        {
+          col: 22,
+          message: PreferRegexLiteralsMessage::UseLiteral,
+          hint: PreferRegexLiteralsHint::UseLiteral,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferRegexLiterals,
+      options: { "disallowRedundantWrapping": true },
+      "new RegExp(/abc/);": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::RedundantWrapping,
+          hint: variant!(PreferRegexLiteralsHint, RedundantWrapping, "/abc/"),
+        }
+      ],
+      "new RegExp(/abc/gi);": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::RedundantWrapping,
+          hint: variant!(PreferRegexLiteralsHint, RedundantWrapping, "/abc/gi"),
+        }
+      ],
+      "RegExp(/abc/, 'u');": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::RedundantWrapping,
+          hint: variant!(PreferRegexLiteralsHint, RedundantWrapping, "/abc/u"),
+        }
+      ],
+      "new RegExp('abc');": [
+        {
+          col: 0,
+          message: PreferRegexLiteralsMessage::UseLiteral,
+          hint: PreferRegexLiteralsHint::UseLiteral,
+        }
+      ],
+    };
+  }
+}
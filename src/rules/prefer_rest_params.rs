@@ -0,0 +1,225 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use swc_ecmascript::ast::{
+  Constructor, Expr, ExprOrSuper, Function, GetterProp, Ident, MemberExpr,
+  Prop, SetterProp,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+pub struct PreferRestParams;
+
+const CODE: &str = "prefer-rest-params";
+const MESSAGE: &str = "Use the rest parameters instead of `arguments`";
+const HINT: &str =
+  "Declare a rest parameter like `function foo(...args) {}` and use it instead";
+
+impl LintRule for PreferRestParams {
+  fn new() -> Box<Self> {
+    Box::new(PreferRestParams)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferRestParamsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using rest parameters instead of the `arguments` object
+
+The `arguments` object is array-like but not an array, so common operations
+like `map` or `slice` aren't available on it without conversion. It also
+obscures which arguments a function actually accepts. Rest parameters are a
+real array and make the signature explicit.
+
+Accessing a property like `arguments.length` is not reported.
+
+### Invalid:
+```typescript
+function foo() {
+  console.log(arguments);
+}
+
+function bar(action) {
+  const args = Array.prototype.slice.call(arguments, 1);
+  action.apply(null, args);
+}
+```
+
+### Valid:
+```typescript
+function foo(...args) {
+  console.log(args);
+}
+
+function bar(action, ...args) {
+  action.apply(null, args);
+}
+
+function baz(arguments) {
+  console.log(arguments); // this is a parameter
+}
+```
+"#
+  }
+}
+
+struct PreferRestParamsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  in_function: bool,
+}
+
+impl<'c, 'view> PreferRestParamsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self {
+      context,
+      in_function: false,
+    }
+  }
+
+  fn with_function<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let prev = self.in_function;
+    self.in_function = true;
+    op(self);
+    self.in_function = prev;
+  }
+
+  fn check(&mut self, ident: &Ident) {
+    // `arguments` outside of any function is just a global variable.
+    if !self.in_function || ident.sym != *"arguments" {
+      return;
+    }
+
+    // Shadowed by a user-defined binding, e.g. `function foo(arguments) {}`
+    if self.context.scope().var(&ident.to_id()).is_some() {
+      return;
+    }
+
+    self
+      .context
+      .add_diagnostic_with_hint(ident.span, CODE, MESSAGE, HINT);
+  }
+}
+
+impl<'c, 'view> Visit for PreferRestParamsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_function(|a| function.visit_children_with(a));
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_function(|a| constructor.visit_children_with(a));
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp, _: &dyn Node) {
+    self.with_function(|a| getter.visit_children_with(a));
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp, _: &dyn Node) {
+    self.with_function(|a| setter.visit_children_with(a));
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    if !member_expr.computed {
+      // `arguments.length` etc. is fine
+      if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+        if !matches!(&**obj, Expr::Ident(ident) if ident.sym == *"arguments") {
+          obj.visit_with(member_expr, self);
+        }
+      }
+      return;
+    }
+
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check(ident);
+    }
+
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _: &dyn Node) {
+    if let Prop::Shorthand(ident) = prop {
+      self.check(ident);
+    }
+
+    prop.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_rest_params_valid() {
+    assert_lint_ok! {
+      PreferRestParams,
+      "arguments;",
+      "function foo(arguments) { arguments; }",
+      "function foo() { var arguments; arguments; }",
+      "var foo = () => arguments;",
+      "function foo(...args) { args; }",
+      "function foo() { arguments.length; }",
+      "function foo() { arguments.callee; }",
+      "function foo() { bar.arguments; }",
+      "function foo() { const obj = { arguments: 1 }; }",
+      "class A { arguments() {} }",
+    };
+  }
+
+  #[test]
+  fn prefer_rest_params_invalid() {
+    assert_lint_err! {
+      PreferRestParams,
+      "function foo() { arguments; }": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { arguments[0]; }": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { arguments[1]; }": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { arguments[Symbol.iterator]; }": [
+        { col: 17, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { Array.prototype.slice.call(arguments, 1); }": [
+        { col: 44, message: MESSAGE, hint: HINT }
+      ],
+      "const foo = function () { return { arguments }; };": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
+      "function foo() { return () => arguments; }": [
+        { col: 30, message: MESSAGE, hint: HINT }
+      ],
+      "class A { constructor() { arguments; } }": [
+        { col: 26, message: MESSAGE, hint: HINT }
+      ],
+      "class A { foo() { arguments; } }": [
+        { col: 18, message: MESSAGE, hint: HINT }
+      ],
+      "const obj = { get foo() { return arguments; } };": [
+        { col: 33, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
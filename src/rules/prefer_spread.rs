@@ -0,0 +1,158 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{equal_tokens, is_null_or_undefined, skip_wrappers};
+use dprint_swc_ecma_ast_view as AstView;
+use if_chain::if_chain;
+use swc_common::Spanned;
+
+pub struct PreferSpread;
+
+const CODE: &str = "prefer-spread";
+const MESSAGE: &str = "Use the spread operator instead of `.apply()`";
+const HINT: &str =
+  "Call the function directly with spread arguments, e.g. `foo(...args)`";
+
+impl LintRule for PreferSpread {
+  fn new() -> Box<Self> {
+    Box::new(PreferSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    PreferSpreadHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Suggests using spread syntax instead of `Function.prototype.apply()`
+
+Before ES2015, `.apply()` was the only way to call a function with a variable
+number of arguments. Spread syntax does the same thing and is easier to read.
+
+Calls are only reported when the `this` passed to `.apply()` is the same as
+the one the function would be called with anyway, that is, `null` or
+`undefined` for plain functions and the object itself for methods.
+
+### Invalid:
+```typescript
+foo.apply(undefined, args);
+foo.apply(null, args);
+obj.foo.apply(obj, args);
+```
+
+### Valid:
+```typescript
+foo(...args);
+obj.foo(...args);
+
+// `this` differs
+foo.apply(obj, args);
+obj.foo.apply(null, args);
+obj.foo.apply(otherObj, args);
+
+// the arguments are not variadic
+foo.apply(obj, [1, 2, 3]);
+```
+"#
+  }
+}
+
+struct PreferSpreadHandler;
+
+impl Handler for PreferSpreadHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if_chain! {
+      if let AstView::ExprOrSuper::Expr(callee) = &call_expr.callee;
+      if let AstView::Expr::Member(callee) = skip_wrappers(callee);
+      if !callee.computed();
+      if let AstView::Expr::Ident(prop) = callee.prop;
+      if *prop.sym() == *"apply";
+      if let [this_arg, args] = call_expr.args.as_slice();
+      if this_arg.spread().is_none() && args.spread().is_none();
+      if !matches!(args.expr, AstView::Expr::Array(_));
+      if let AstView::ExprOrSuper::Expr(applied) = &callee.obj;
+      then {
+        let is_valid_this = match skip_wrappers(applied) {
+          AstView::Expr::Member(applied) => match &applied.obj {
+            AstView::ExprOrSuper::Expr(expected_this) => {
+              equal_tokens(expected_this, &this_arg.expr)
+            }
+            AstView::ExprOrSuper::Super(_) => false,
+          },
+          _ => is_null_or_undefined(&this_arg.expr),
+        };
+
+        if is_valid_this {
+          ctx.add_diagnostic_with_hint(call_expr.span(), CODE, MESSAGE, HINT);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_spread_valid() {
+    assert_lint_ok! {
+      PreferSpread,
+      "foo.apply(obj, args);",
+      "obj.foo.apply(null, args);",
+      "obj.foo.apply(otherObj, args);",
+      "a.b(x, y).c.foo.apply(a.b(x, z).c, args);",
+      "a.b.foo.apply(a.b.c, args);",
+      "foo.apply(undefined, [1, 2]);",
+      "foo.apply(null, [1, 2]);",
+      "obj.foo.apply(obj, [1, 2]);",
+      "foo.call(null, ...args);",
+      "foo.apply(null, ...args);",
+      "foo.apply();",
+      "foo.apply(null);",
+      "obj.apply(null, args, 1);",
+      "foo[apply](null, args);",
+      "foo(...args);",
+    };
+  }
+
+  #[test]
+  fn prefer_spread_invalid() {
+    assert_lint_err! {
+      PreferSpread,
+      "foo.apply(undefined, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo.apply(void 0, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo.apply(null, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "obj.foo.apply(obj, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a.b.c.foo.apply(a.b.c, args);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "a.b(x, y).c.foo.apply(a.b(x, y).c, args);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "[].concat.apply([ ], args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "[].concat.apply([\n/*empty*/\n], args);": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "obj.foo.apply(obj, args).bar();": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "(foo.apply)(null, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo?.apply(undefined, args);": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const bar = () => obj.foo.apply(obj, args);": [
+        { col: 18, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
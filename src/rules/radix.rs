@@ -0,0 +1,346 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct Radix {
+  mode: RadixMode,
+}
+
+const CODE: &str = "radix";
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum RadixMode {
+  /// Requires the radix to be always passed.
+  Always,
+  /// Disallows passing `10`, which is the default radix.
+  AsNeeded,
+}
+
+impl Default for RadixMode {
+  fn default() -> Self {
+    RadixMode::Always
+  }
+}
+
+#[derive(Display)]
+enum RadixMessage {
+  #[display(fmt = "Missing parameters")]
+  MissingParameters,
+  #[display(fmt = "Missing radix parameter")]
+  MissingRadix,
+  #[display(
+    fmt = "Invalid radix parameter, must be an integer between 2 and 36"
+  )]
+  InvalidRadix,
+  #[display(fmt = "Redundant radix parameter")]
+  RedundantRadix,
+}
+
+#[derive(Display)]
+enum RadixHint {
+  #[display(
+    fmt = "Pass the string to parse and its radix, e.g. `parseInt(str, 10)`"
+  )]
+  MissingParameters,
+  #[display(fmt = "Pass the radix explicitly, e.g. `parseInt(str, 10)`")]
+  MissingRadix,
+  #[display(fmt = "Pass an integer between 2 and 36 as the radix")]
+  InvalidRadix,
+  #[display(fmt = "Remove the radix, `10` is the default")]
+  RedundantRadix,
+}
+
+impl LintRule for Radix {
+  fn new() -> Box<Self> {
+    Box::new(Radix::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.mode = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    RadixHandler { mode: self.mode }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces passing the radix argument to `parseInt()`
+
+Without a radix, `parseInt()` guesses it from the string, e.g. strings
+starting with `0x` are parsed as hexadecimal. Passing the radix explicitly
+avoids surprises. Radixes that are not an integer between 2 and 36 are always
+reported.
+
+With the `"as-needed"` option, the radix may be omitted, and passing `10`,
+which is the default, is reported instead.
+
+### Invalid:
+```typescript
+parseInt("071");
+parseInt(someValue);
+Number.parseInt("071");
+parseInt("071", 37);
+```
+
+### Valid:
+```typescript
+parseInt("071", 10);
+parseInt("071", 8);
+parseFloat(someValue);
+Number.parseInt(someValue, 10);
+```
+"#
+  }
+}
+
+/// Checks if the callee is the global `parseInt` or `Number.parseInt`.
+fn is_parse_int(callee: &AstView::Expr, ctx: &Context) -> bool {
+  match callee {
+    AstView::Expr::Ident(ident) => {
+      *ident.sym() == *"parseInt" && is_global(ident, ctx.scope())
+    }
+    AstView::Expr::Member(member_expr) if !member_expr.computed() => {
+      matches!(
+        (&member_expr.obj, &member_expr.prop),
+        (
+          AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+          AstView::Expr::Ident(prop),
+        ) if *obj.sym() == *"Number"
+          && is_global(obj, ctx.scope())
+          && *prop.sym() == *"parseInt"
+      )
+    }
+    _ => false,
+  }
+}
+
+fn is_valid_radix(radix: &AstView::Expr) -> bool {
+  match radix {
+    AstView::Expr::Lit(AstView::Lit::Num(num)) => {
+      let value = num.value();
+      value.fract() == 0.0 && (2.0..=36.0).contains(&value)
+    }
+    AstView::Expr::Lit(_) => false,
+    AstView::Expr::Ident(ident) => *ident.sym() != *"undefined",
+    _ => true,
+  }
+}
+
+fn is_default_radix(radix: &AstView::Expr) -> bool {
+  matches!(
+    radix,
+    AstView::Expr::Lit(AstView::Lit::Num(num)) if num.value() == 10.0
+  )
+}
+
+struct RadixHandler {
+  mode: RadixMode,
+}
+
+impl Handler for RadixHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    match &call_expr.callee {
+      AstView::ExprOrSuper::Expr(callee) if is_parse_int(callee, ctx) => {}
+      _ => return,
+    }
+
+    let (message, hint) = match call_expr.args.as_slice() {
+      [] => (
+        RadixMessage::MissingParameters,
+        RadixHint::MissingParameters,
+      ),
+      [arg] => {
+        if self.mode == RadixMode::AsNeeded || arg.spread().is_some() {
+          return;
+        }
+        (RadixMessage::MissingRadix, RadixHint::MissingRadix)
+      }
+      [_, radix, ..] => {
+        if radix.spread().is_some() {
+          return;
+        }
+        if !is_valid_radix(&radix.expr) {
+          (RadixMessage::InvalidRadix, RadixHint::InvalidRadix)
+        } else if self.mode == RadixMode::AsNeeded
+          && is_default_radix(&radix.expr)
+        {
+          (RadixMessage::RedundantRadix, RadixHint::RedundantRadix)
+        } else {
+          return;
+        }
+      }
+    };
+
+    ctx.add_diagnostic_with_hint(call_expr.span(), CODE, message, hint);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn radix_valid() {
+    assert_lint_ok! {
+      Radix,
+      "parseInt('10', 10);",
+      "parseInt('10', 2);",
+      "parseInt('10', 36);",
+      "parseInt('10', foo);",
+      "parseInt('10', foo.bar);",
+      "parseInt('10', 0x10);",
+      "parseInt(...args);",
+      "parseInt('10', ...args);",
+      "Number.parseInt('10', 10);",
+      "parseFloat('10');",
+      "Number.parseFloat('10');",
+      "Number['parseInt']('10');",
+      "foo.parseInt('10');",
+      "const parseInt = () => {}; parseInt('10');",
+      "function f(Number) { Number.parseInt('10'); }",
+    };
+
+    assert_lint_ok! {
+      Radix,
+      options: "as-needed",
+      "parseInt('10');",
+      "parseInt('10', 8);",
+      "parseInt('10', foo);",
+      "Number.parseInt('10');",
+    };
+  }
+
+  #[test]
+  fn radix_invalid() {
+    assert_lint_err! {
+      Radix,
+      "parseInt();": [
+        {
+          col: 0,
+          message: RadixMessage::MissingParameters,
+          hint: RadixHint::MissingParameters,
+        }
+      ],
+      "parseInt('10');": [
+        {
+          col: 0,
+          message: RadixMessage::MissingRadix,
+          hint: RadixHint::MissingRadix,
+        }
+      ],
+      "Number.parseInt('10');": [
+        {
+          col: 0,
+          message: RadixMessage::MissingRadix,
+          hint: RadixHint::MissingRadix,
+        }
+      ],
+      "const a = parseInt(b);": [
+        {
+          col: 10,
+          message: RadixMessage::MissingRadix,
+          hint: RadixHint::MissingRadix,
+        }
+      ],
+      "parseInt('10', 1);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+      "parseInt('10', 37);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+      "parseInt('10', 10.5);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+      "parseInt('10', '10');": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+      "parseInt('10', null);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+      "parseInt('10', undefined);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      Radix,
+      options: "as-needed",
+      "parseInt();": [
+        {
+          col: 0,
+          message: RadixMessage::MissingParameters,
+          hint: RadixHint::MissingParameters,
+        }
+      ],
+      "parseInt('10', 10);": [
+        {
+          col: 0,
+          message: RadixMessage::RedundantRadix,
+          hint: RadixHint::RedundantRadix,
+        }
+      ],
+      "Number.parseInt('10', 10);": [
+        {
+          col: 0,
+          message: RadixMessage::RedundantRadix,
+          hint: RadixHint::RedundantRadix,
+        }
+      ],
+      "parseInt('10', 37);": [
+        {
+          col: 0,
+          message: RadixMessage::InvalidRadix,
+          hint: RadixHint::InvalidRadix,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,514 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{enclosing_function, skip_paren_ancestors};
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, NodeTrait};
+use swc_common::{BytePos, Spanned};
+use swc_ecmascript::ast::BinaryOp;
+
+pub struct RulesOfHooks;
+
+const CODE: &str = "rules-of-hooks";
+
+#[derive(Display)]
+enum RulesOfHooksMessage {
+  #[display(fmt = "React Hook `{}` is called conditionally", _0)]
+  Conditional(String),
+  #[display(fmt = "React Hook `{}` is called in a loop", _0)]
+  Loop(String),
+  #[display(fmt = "React Hook `{}` is called in a nested function", _0)]
+  NestedFunction(String),
+  #[display(
+    fmt = "React Hook `{}` is called in a function that is neither a component nor a custom hook",
+    _0
+  )]
+  RegularFunction(String),
+  #[display(fmt = "React Hook `{}` cannot be called in a class component", _0)]
+  ClassComponent(String),
+  #[display(fmt = "React Hook `{}` is called at the top level", _0)]
+  TopLevel(String),
+}
+
+#[derive(Display)]
+enum RulesOfHooksHint {
+  #[display(
+    fmt = "Hooks have to be called in the same order on every render, so move the call before any condition or early return"
+  )]
+  SameOrder,
+  #[display(
+    fmt = "Hooks can only be called at the top level of a component or custom hook"
+  )]
+  TopLevelOfComponent,
+  #[display(
+    fmt = "Component names have to start with an uppercase letter and custom hook names with `use`"
+  )]
+  Naming,
+}
+
+impl LintRule for RulesOfHooks {
+  fn new() -> Box<Self> {
+    Box::new(RulesOfHooks)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    RulesOfHooksHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces the rules of hooks for React and Preact
+
+Hooks rely on being called in the same order on every render. So they must
+only be called at the top level of a function component or a custom hook, and
+never inside conditions, loops or nested functions, or after an early
+`return`.
+
+Calls of functions named `use` or `useXxx`, like `useState` or
+`React.useEffect`, are treated as hook calls. Functions with a name starting
+with an uppercase letter are treated as components and functions with a hook
+name as custom hooks. Anonymous functions passed to `memo` or `forwardRef`
+are treated as components, too.
+
+### Invalid:
+```typescript
+function Foo({ enabled }) {
+  if (enabled) {
+    useEffect(() => {});
+  }
+  for (const item of items) {
+    useState(item);
+  }
+  const onClick = () => {
+    useState();
+  };
+}
+
+function Bar({ hidden }) {
+  if (hidden) {
+    return null;
+  }
+  const [value, setValue] = useState();
+}
+
+function notAComponent() {
+  useState();
+}
+
+useState();
+```
+
+### Valid:
+```typescript
+function Foo({ enabled }) {
+  const [value, setValue] = useState();
+  useEffect(() => {
+    if (enabled) {
+      setValue(1);
+    }
+  });
+}
+
+const Bar = memo(() => {
+  const value = useContext(Context);
+});
+
+function useCustomHook() {
+  return useState();
+}
+```
+"#
+  }
+}
+
+fn is_hook_name(name: &str) -> bool {
+  name == "use"
+    || name.strip_prefix("use").map_or(false, |rest| {
+      rest.starts_with(|c: char| c.is_ascii_uppercase() || c.is_ascii_digit())
+    })
+}
+
+fn is_component_name(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Checks if the callee is a hook, like `useState` or `React.useState`.
+fn is_hook_callee(callee: &AstView::ExprOrSuper) -> bool {
+  match callee {
+    AstView::ExprOrSuper::Expr(AstView::Expr::Ident(ident)) => {
+      is_hook_name(ident.sym())
+    }
+    AstView::ExprOrSuper::Expr(AstView::Expr::Member(member_expr))
+      if !member_expr.computed() =>
+    {
+      match (&member_expr.obj, &member_expr.prop) {
+        (
+          AstView::ExprOrSuper::Expr(AstView::Expr::Ident(obj)),
+          AstView::Expr::Ident(prop),
+        ) => is_component_name(obj.sym()) && is_hook_name(prop.sym()),
+        _ => false,
+      }
+    }
+    _ => false,
+  }
+}
+
+/// Returns the name a function is known by, either its own name or the name
+/// of the variable it's assigned to.
+fn function_name<'a>(node: AstView::Node<'a>) -> Option<&'a str> {
+  let node = match node {
+    AstView::Node::Function(function) => match function.parent {
+      AstView::Node::FnDecl(fn_decl) => return Some(fn_decl.ident.sym()),
+      AstView::Node::FnExpr(fn_expr) => match fn_expr.ident {
+        Some(ident) => return Some(ident.sym()),
+        None => function.parent,
+      },
+      _ => return None,
+    },
+    _ => node,
+  };
+
+  match skip_paren_ancestors(node) {
+    (_, Some(AstView::Node::VarDeclarator(declarator))) => {
+      match &declarator.name {
+        AstView::Pat::Ident(binding) => Some(binding.id.sym()),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the function is passed to `memo(...)` or `forwardRef(...)`,
+/// which makes it a component even when it's anonymous.
+fn is_wrapped_component(node: AstView::Node) -> bool {
+  let node = match node {
+    AstView::Node::Function(function) => function.parent,
+    _ => node,
+  };
+  match skip_paren_ancestors(node) {
+    (_, Some(AstView::Node::ExprOrSpread(arg))) => matches!(
+      arg.parent,
+      AstView::Node::CallExpr(call_expr)
+        if is_component_wrapper(&call_expr.callee)
+    ),
+    _ => false,
+  }
+}
+
+/// Checks if the callee is `memo` or `forwardRef`, possibly accessed on a
+/// namespace like `React.memo`.
+fn is_component_wrapper(callee: &AstView::ExprOrSuper) -> bool {
+  let name = match callee {
+    AstView::ExprOrSuper::Expr(AstView::Expr::Ident(ident)) => ident.sym(),
+    AstView::ExprOrSuper::Expr(AstView::Expr::Member(member_expr))
+      if !member_expr.computed() =>
+    {
+      match &member_expr.prop {
+        AstView::Expr::Ident(prop) => prop.sym(),
+        _ => return false,
+      }
+    }
+    _ => return false,
+  };
+  name == "memo" || name == "forwardRef"
+}
+
+fn is_component_or_hook(function: AstView::Node) -> bool {
+  function_name(function)
+    .map_or(false, |name| is_component_name(name) || is_hook_name(name))
+    || is_wrapped_component(function)
+}
+
+fn is_class_member(function: AstView::Node) -> bool {
+  match function {
+    AstView::Node::Function(function) => matches!(
+      function.parent,
+      AstView::Node::ClassMethod(_) | AstView::Node::PrivateMethod(_)
+    ),
+    _ => false,
+  }
+}
+
+fn is_function(node: AstView::Node) -> bool {
+  matches!(
+    node,
+    AstView::Node::Function(_) | AstView::Node::ArrowExpr(_)
+  )
+}
+
+/// Checks if there's a `return` statement anywhere before `pos` in the given
+/// function, not counting nested functions.
+fn has_return_before(node: AstView::Node, pos: BytePos) -> bool {
+  node.children().into_iter().any(|child| match child {
+    AstView::Node::ReturnStmt(return_stmt) => return_stmt.span().hi <= pos,
+    AstView::Node::Class(_) => false,
+    _ if is_function(child) => false,
+    _ => child.span().lo < pos && has_return_before(child, pos),
+  })
+}
+
+fn is_nested_in_component_or_hook(function: AstView::Node) -> bool {
+  function
+    .ancestors()
+    .any(|ancestor| is_function(ancestor) && is_component_or_hook(ancestor))
+}
+
+/// How a hook call is nested inside its enclosing function.
+#[derive(Default)]
+struct CallPath {
+  conditional: bool,
+  in_loop: bool,
+}
+
+/// Walks up from the hook call to the enclosing function, recording whether
+/// the call is only evaluated conditionally or repeatedly on the way.
+fn call_path(call_expr: &AstView::CallExpr) -> CallPath {
+  let mut path = CallPath::default();
+  let mut child: AstView::Node = call_expr.into();
+  for ancestor in child.ancestors() {
+    let child_span = child.span();
+    match ancestor {
+      _ if is_function(ancestor) => break,
+      AstView::Node::IfStmt(if_stmt) if if_stmt.test.span() != child_span => {
+        path.conditional = true;
+      }
+      AstView::Node::CondExpr(cond_expr)
+        if cond_expr.test.span() != child_span =>
+      {
+        path.conditional = true;
+      }
+      AstView::Node::BinExpr(bin_expr)
+        if bin_expr.right.span() == child_span
+          && matches!(
+            bin_expr.op(),
+            BinaryOp::LogicalAnd
+              | BinaryOp::LogicalOr
+              | BinaryOp::NullishCoalescing
+          ) =>
+      {
+        path.conditional = true;
+      }
+      AstView::Node::OptChainExpr(_) | AstView::Node::SwitchCase(_) => {
+        path.conditional = true;
+      }
+      AstView::Node::ForStmt(for_stmt)
+        if for_stmt.init.map(|init| init.span()) != Some(child_span) =>
+      {
+        path.in_loop = true;
+      }
+      AstView::Node::ForInStmt(for_in_stmt)
+        if for_in_stmt.right.span() != child_span =>
+      {
+        path.in_loop = true;
+      }
+      AstView::Node::ForOfStmt(for_of_stmt)
+        if for_of_stmt.right.span() != child_span =>
+      {
+        path.in_loop = true;
+      }
+      AstView::Node::WhileStmt(_) | AstView::Node::DoWhileStmt(_) => {
+        path.in_loop = true;
+      }
+      _ => {}
+    }
+    child = ancestor;
+  }
+  path
+}
+
+struct RulesOfHooksHandler;
+
+impl Handler for RulesOfHooksHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if !is_hook_callee(&call_expr.callee) {
+      return;
+    }
+
+    let hook = call_expr.callee.text().to_string();
+    let (message, hint) =
+      match (enclosing_function(call_expr.into()), call_path(call_expr)) {
+        (None, _) => (
+          RulesOfHooksMessage::TopLevel(hook),
+          RulesOfHooksHint::TopLevelOfComponent,
+        ),
+        (Some(function), path) if is_component_or_hook(function) => {
+          if path.in_loop {
+            (RulesOfHooksMessage::Loop(hook), RulesOfHooksHint::SameOrder)
+          } else if path.conditional
+            || has_return_before(function, call_expr.span().lo)
+          {
+            (
+              RulesOfHooksMessage::Conditional(hook),
+              RulesOfHooksHint::SameOrder,
+            )
+          } else {
+            return;
+          }
+        }
+        (Some(function), _) if is_class_member(function) => (
+          RulesOfHooksMessage::ClassComponent(hook),
+          RulesOfHooksHint::TopLevelOfComponent,
+        ),
+        (Some(function), _) if is_nested_in_component_or_hook(function) => (
+          RulesOfHooksMessage::NestedFunction(hook),
+          RulesOfHooksHint::TopLevelOfComponent,
+        ),
+        (Some(_), _) => (
+          RulesOfHooksMessage::RegularFunction(hook),
+          RulesOfHooksHint::Naming,
+        ),
+      };
+
+    ctx.add_diagnostic_with_hint(call_expr.callee.span(), CODE, message, hint);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rules_of_hooks_valid() {
+    assert_lint_ok! {
+      RulesOfHooks,
+      "function Foo() { useState(); }",
+      "function Foo() { const [a, b] = useState(); useEffect(() => {}); }",
+      "function useFoo() { return useState(); }",
+      "function use() { useState(); }",
+      "const Foo = () => { useState(); };",
+      "const Foo = function () { useState(); };",
+      "const useFoo = () => useState();",
+      "function Foo() { React.useState(); }",
+      "function Foo() { const a = useState() + useRef(); }",
+      "function Foo() { if (useFoo()) {} }",
+      "function Foo() { for (const a of useFoo()) {} }",
+      "function Foo() { for (let i = useFoo(); i < 1; i++) {} }",
+      "const Foo = memo(() => { useState(); });",
+      "const Foo = React.forwardRef(function (props, ref) { useState(); });",
+      "export default memo(() => { useState(); });",
+      "function Foo() { useEffect(() => { if (a) { return; } }); useState(); }",
+      "function Foo() { function bar() { return 1; } useState(); }",
+      "function Foo() { useState(); if (a) { return null; } }",
+      "function foo() { user(); usefulThing(); }",
+      "function foo() { bar.useState(); }",
+      "useFoo;",
+    };
+  }
+
+  #[test]
+  fn rules_of_hooks_invalid() {
+    assert_lint_err! {
+      RulesOfHooks,
+      "function Foo() { if (a) { useState(); } }": [
+        {
+          col: 26,
+          message: variant!(RulesOfHooksMessage, Conditional, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { if (a) {} else useState(); }": [
+        {
+          col: 32,
+          message: variant!(RulesOfHooksMessage, Conditional, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "const Foo = () => { const a = b ? useState() : null; };": [
+        {
+          col: 34,
+          message: variant!(RulesOfHooksMessage, Conditional, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function useFoo() { a && useEffect(); }": [
+        {
+          col: 25,
+          message: variant!(RulesOfHooksMessage, Conditional, "useEffect"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { switch (a) { case 1: useState(); } }": [
+        {
+          col: 38,
+          message: variant!(RulesOfHooksMessage, Conditional, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { if (a) { return null; } React.useState(); }": [
+        {
+          col: 41,
+          message: variant!(RulesOfHooksMessage, Conditional, "React.useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { for (const a of b) { useState(); } }": [
+        {
+          col: 38,
+          message: variant!(RulesOfHooksMessage, Loop, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { while (a) { if (b) useState(); } }": [
+        {
+          col: 36,
+          message: variant!(RulesOfHooksMessage, Loop, "useState"),
+          hint: RulesOfHooksHint::SameOrder,
+        }
+      ],
+      "function Foo() { useEffect(() => { useState(); }); }": [
+        {
+          col: 35,
+          message: variant!(RulesOfHooksMessage, NestedFunction, "useState"),
+          hint: RulesOfHooksHint::TopLevelOfComponent,
+        }
+      ],
+      "function Foo() { function onClick() { useState(); } }": [
+        {
+          col: 38,
+          message: variant!(RulesOfHooksMessage, NestedFunction, "useState"),
+          hint: RulesOfHooksHint::TopLevelOfComponent,
+        }
+      ],
+      "function foo() { useState(); }": [
+        {
+          col: 17,
+          message: variant!(RulesOfHooksMessage, RegularFunction, "useState"),
+          hint: RulesOfHooksHint::Naming,
+        }
+      ],
+      "const foo = () => useState();": [
+        {
+          col: 18,
+          message: variant!(RulesOfHooksMessage, RegularFunction, "useState"),
+          hint: RulesOfHooksHint::Naming,
+        }
+      ],
+      "class Foo extends Component { render() { useState(); } }": [
+        {
+          col: 41,
+          message: variant!(RulesOfHooksMessage, ClassComponent, "useState"),
+          hint: RulesOfHooksHint::TopLevelOfComponent,
+        }
+      ],
+      "useState();": [
+        {
+          col: 0,
+          message: variant!(RulesOfHooksMessage, TopLevel, "useState"),
+          hint: RulesOfHooksHint::TopLevelOfComponent,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,107 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_global;
+use dprint_swc_ecma_ast_view as AstView;
+use if_chain::if_chain;
+use swc_common::Spanned;
+
+pub struct SymbolDescription;
+
+const CODE: &str = "symbol-description";
+const MESSAGE: &str = "Expected a description for `Symbol()`";
+const HINT: &str =
+  "Pass a description as the first argument, e.g. `Symbol(\"foo\")`";
+
+impl LintRule for SymbolDescription {
+  fn new() -> Box<Self> {
+    Box::new(SymbolDescription)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    SymbolDescriptionHandler.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires a description when creating a symbol
+
+The description of a symbol is shown when it's converted to a string or
+inspected, e.g. `Symbol(foo)`. Without it, all symbols look the same in logs
+and debuggers, which makes them hard to tell apart.
+
+### Invalid:
+```typescript
+const foo = Symbol();
+```
+
+### Valid:
+```typescript
+const foo = Symbol("foo");
+const bar = Symbol(description);
+```
+"#
+  }
+}
+
+struct SymbolDescriptionHandler;
+
+impl Handler for SymbolDescriptionHandler {
+  fn call_expr(&mut self, call_expr: &AstView::CallExpr, ctx: &mut Context) {
+    if_chain! {
+      if call_expr.args.is_empty();
+      if let AstView::ExprOrSuper::Expr(AstView::Expr::Ident(callee)) =
+        &call_expr.callee;
+      if *callee.sym() == *"Symbol";
+      if is_global(callee, ctx.scope());
+      then {
+        ctx.add_diagnostic_with_hint(call_expr.span(), CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn symbol_description_valid() {
+    assert_lint_ok! {
+      SymbolDescription,
+      "Symbol('foo');",
+      "const foo = 'foo'; Symbol(foo);",
+      "Symbol(bar());",
+      "Symbol.for('foo');",
+      "const Symbol = () => {}; Symbol();",
+      "function f(Symbol) { Symbol(); }",
+      "foo.Symbol();",
+    };
+  }
+
+  #[test]
+  fn symbol_description_invalid() {
+    assert_lint_err! {
+      SymbolDescription,
+      "Symbol();": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const foo = Symbol();": [{ col: 12, message: MESSAGE, hint: HINT }],
+      "function f() { return Symbol(); }": [
+        { col: 22, message: MESSAGE, hint: HINT }
+      ],
+      "function f() { const Symbol = 1; } Symbol();": [
+        { col: 35, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,251 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{import_type_fix, specifier_local, UsageCollector};
+use anyhow::Error as AnyError;
+use dprint_swc_ecma_ast_view as AstView;
+use serde::Deserialize;
+use swc_common::Spanned;
+use swc_ecmascript::visit::VisitWith;
+
+#[derive(Default)]
+pub struct VerbatimModuleSyntax {
+  options: VerbatimModuleSyntaxOptions,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct VerbatimModuleSyntaxOptions {
+  /// The function JSX is compiled to, like `"React.createElement"` or `"h"`.
+  jsx_factory: Option<String>,
+}
+
+const CODE: &str = "verbatim-module-syntax";
+const MESSAGE: &str =
+  "The declaration is kept in the emitted code, but none of its imports is used as a value";
+const HINT: &str =
+  "Use `import type` so that the module isn't loaded just for its side effects";
+const FIX_DESC: &str = "Use `import type`";
+
+impl LintRule for VerbatimModuleSyntax {
+  fn new() -> Box<Self> {
+    Box::new(VerbatimModuleSyntax::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    // Scripts can't contain imports.
+    let module = match program {
+      AstView::Program::Module(module) => module,
+      AstView::Program::Script(_) => return,
+    };
+
+    let mut usages =
+      UsageCollector::new(context, self.options.jsx_factory.as_deref());
+    module.inner.visit_with(&DUMMY_NODE, &mut usages);
+
+    VerbatimModuleSyntaxHandler { usages }.traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires `import type` for declarations that would only be kept for their side effects
+
+With TypeScript's `verbatimModuleSyntax`, an import declaration without `type`
+is kept in the emitted code as it's written, even if its imports are only used
+as types or not used at all. The module is then still loaded at runtime just
+for its side effects. This rule reports declarations that import types, but
+where none of the imports is used as a value.
+
+Declarations whose imports are all only used as types are reported by
+`consistent-type-imports` already, so this rule only reports the ones that
+combine them with unused imports.
+
+The JSX factory is used by every JSX element and fragment. It's read from a
+`@jsx` pragma like `/** @jsx h */`, or from the `jsxFactory` option, e.g.
+`{ "jsxFactory": "h" }`. Without either, all imports in a file with JSX are
+assumed to be needed at runtime.
+
+Inline `type` modifiers like in `import { type Foo } from "./foo.ts";` aren't
+supported by the parser yet, so declarations using them can't be checked.
+
+### Invalid:
+```typescript
+import { Foo, unused } from "./foo.ts";
+let foo: Foo;
+```
+
+### Valid:
+```typescript
+import type { Foo, unused } from "./foo.ts";
+let foo: Foo;
+
+import { Bar, createBar } from "./bar.ts";
+const bar: Bar = createBar();
+```
+"#
+  }
+}
+
+struct VerbatimModuleSyntaxHandler {
+  usages: UsageCollector,
+}
+
+impl Handler for VerbatimModuleSyntaxHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &AstView::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    if import_decl.type_only() {
+      return;
+    }
+
+    let locals = import_decl
+      .specifiers
+      .iter()
+      .map(|specifier| specifier_local(specifier).inner)
+      .collect::<Vec<_>>();
+    if locals
+      .iter()
+      .any(|local| self.usages.is_used_as_value(local))
+    {
+      return;
+    }
+    let type_count = locals
+      .iter()
+      .filter(|local| self.usages.is_used_as_type(local))
+      .count();
+    // Declarations with only type-only imports are left to
+    // `consistent-type-imports`.
+    if type_count == 0 || type_count == locals.len() {
+      return;
+    }
+
+    match import_type_fix(import_decl) {
+      Some(change) => ctx.add_diagnostic_with_fix(
+        import_decl.span(),
+        CODE,
+        MESSAGE,
+        HINT,
+        FIX_DESC,
+        vec![change],
+      ),
+      None => {
+        ctx.add_diagnostic_with_hint(import_decl.span(), CODE, MESSAGE, HINT)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn verbatim_module_syntax_valid() {
+    assert_lint_ok! {
+      VerbatimModuleSyntax,
+      "import type { Foo, unused } from './foo.ts'; let foo: Foo;",
+      "import { Foo } from './foo.ts'; let foo: Foo;",
+      "import { Foo, createFoo } from './foo.ts'; const foo: Foo = createFoo();",
+      "import { Foo, unused } from './foo.ts'; let foo: Foo = new Foo();",
+      "import { unused } from './foo.ts';",
+      "import './foo.ts';",
+      {
+        src: "import React, { FC } from 'react'; const Foo: FC = () => <div />;",
+        filename: "foo.tsx",
+      },
+      {
+        src: "import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;",
+        filename: "a.tsx",
+      },
+      {
+        src: "/** @jsx h */ import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;",
+        filename: "a.tsx",
+      },
+    };
+
+    assert_lint_ok! {
+      VerbatimModuleSyntax,
+      options: { "jsxFactory": "h" },
+      {
+        src: "import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;",
+        filename: "a.tsx",
+      },
+    };
+  }
+
+  #[test]
+  fn verbatim_module_syntax_invalid() {
+    assert_lint_err! {
+      VerbatimModuleSyntax,
+      "import { Foo, unused } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "import type { Foo, unused } from './foo.ts'; let foo: Foo;"
+          ),
+        }
+      ],
+      "import { Foo, Bar, unused } from './foo.ts'; let foo: Foo<Bar>;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            "import type { Foo, Bar, unused } from './foo.ts'; let foo: Foo<Bar>;"
+          ),
+        }
+      ],
+      "import Foo, { unused } from './foo.ts'; let foo: Foo;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      VerbatimModuleSyntax,
+      options: { "jsxFactory": "React.createElement" },
+      "import { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;": {
+        filename: "a.tsx",
+        errors: [
+          {
+            col: 0,
+            message: MESSAGE,
+            hint: HINT,
+            fix: (
+              FIX_DESC,
+              "import type { h, FunctionComponent } from 'preact'; const A: FunctionComponent = () => <div />;"
+            ),
+          }
+        ],
+      },
+    };
+  }
+}
@@ -0,0 +1,475 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, ProgramRef};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::equal_tokens;
+use anyhow::Error as AnyError;
+use derive_more::Display;
+use dprint_swc_ecma_ast_view::{self as AstView, BinaryOp, NodeTrait, UnaryOp};
+use serde::Deserialize;
+use swc_common::Spanned;
+
+#[derive(Default)]
+pub struct Yoda {
+  options: YodaOptions,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct YodaOptions {
+  mode: YodaMode,
+  /// Allows range checks like `0 <= x && x < 10`.
+  except_range: bool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+enum YodaMode {
+  /// Disallows literals on the left side of comparisons.
+  Never,
+  /// Requires literals to be on the left side of comparisons.
+  Always,
+}
+
+impl Default for YodaMode {
+  fn default() -> Self {
+    YodaMode::Never
+  }
+}
+
+const CODE: &str = "yoda";
+const FIX_DESC: &str = "Swap the operands";
+
+#[derive(Display)]
+enum YodaMessage {
+  #[display(fmt = "Expected literal to be on the right side of `{}`", _0)]
+  LiteralOnRight(String),
+  #[display(fmt = "Expected literal to be on the left side of `{}`", _0)]
+  LiteralOnLeft(String),
+}
+
+#[derive(Display)]
+enum YodaHint {
+  #[display(fmt = "Swap the operands, e.g. `count === 5`")]
+  LiteralOnRight,
+  #[display(fmt = "Swap the operands, e.g. `5 === count`")]
+  LiteralOnLeft,
+}
+
+impl LintRule for Yoda {
+  fn new() -> Box<Self> {
+    Box::new(Yoda::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn set_options(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), AnyError> {
+    self.options = serde_json::from_value(options.clone())?;
+    Ok(())
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: AstView::Program<'_>,
+  ) {
+    YodaHandler {
+      options: self.options,
+    }
+    .traverse(program, context);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows "Yoda conditions", comparisons with the literal on the left side
+
+Comparisons like `5 === count` read backwards compared to how you'd say them
+out loud. Putting the variable first is easier to follow.
+
+With `{ "mode": "always" }` the opposite is enforced, i.e. literals have to go
+first. Setting `exceptRange` to `true` permits range checks that read in
+order, like `0 <= x && x < 10` or `x < 0 || 10 <= x`.
+
+### Invalid:
+```typescript
+if (5 === count) {}
+if ("red" !== color) {}
+if (0 < x) {}
+```
+
+### Valid:
+```typescript
+if (count === 5) {}
+if (color !== "red") {}
+if (x > 0) {}
+
+// allowed with `exceptRange`
+if (0 <= x && x < 10) {}
+```
+"#
+  }
+}
+
+fn is_comparison(op: BinaryOp) -> bool {
+  use BinaryOp::*;
+  matches!(op, EqEq | NotEq | EqEqEq | NotEqEq | Lt | LtEq | Gt | GtEq)
+}
+
+/// Returns the operator that gives the same result with swapped operands.
+fn flip(op: BinaryOp) -> BinaryOp {
+  use BinaryOp::*;
+  match op {
+    Lt => Gt,
+    LtEq => GtEq,
+    Gt => Lt,
+    GtEq => LtEq,
+    op => op,
+  }
+}
+
+fn is_literal(expr: &AstView::Expr) -> bool {
+  match expr {
+    AstView::Expr::Lit(_) => true,
+    AstView::Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    AstView::Expr::Unary(unary_expr) => {
+      unary_expr.op() == UnaryOp::Minus
+        && matches!(
+          unary_expr.arg,
+          AstView::Expr::Lit(AstView::Lit::Num(_))
+            | AstView::Expr::Lit(AstView::Lit::BigInt(_))
+        )
+    }
+    _ => false,
+  }
+}
+
+fn number_value(expr: &AstView::Expr) -> Option<f64> {
+  match expr {
+    AstView::Expr::Lit(AstView::Lit::Num(num)) => Some(num.value()),
+    AstView::Expr::Unary(unary_expr) if unary_expr.op() == UnaryOp::Minus => {
+      number_value(&unary_expr.arg).map(|value| -value)
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the bounds of a range are in order. Bounds that aren't number
+/// literals can't be checked, so they are assumed to be.
+fn is_ordered(lower: &AstView::Expr, upper: &AstView::Expr) -> bool {
+  match (number_value(lower), number_value(upper)) {
+    (Some(lower), Some(upper)) => lower <= upper,
+    _ => true,
+  }
+}
+
+fn is_less_than(bin_expr: &AstView::BinExpr) -> bool {
+  matches!(bin_expr.op(), BinaryOp::Lt | BinaryOp::LtEq)
+}
+
+/// Checks if the comparison is part of a range check that reads in order,
+/// i.e. `a < x && x < b` or `x < a || b < x`.
+fn is_range_test(bin_expr: &AstView::BinExpr) -> bool {
+  let logical_expr = match bin_expr.parent {
+    AstView::Node::BinExpr(logical_expr) => logical_expr,
+    _ => return false,
+  };
+
+  let (left, right) = match (logical_expr.left, logical_expr.right) {
+    (AstView::Expr::Bin(left), AstView::Expr::Bin(right))
+      if is_less_than(left) && is_less_than(right) =>
+    {
+      (left, right)
+    }
+    _ => return false,
+  };
+
+  match logical_expr.op() {
+    BinaryOp::LogicalAnd => {
+      equal_tokens(&left.right, &right.left)
+        && is_ordered(&left.left, &right.right)
+    }
+    BinaryOp::LogicalOr => {
+      equal_tokens(&left.left, &right.right)
+        && is_ordered(&left.right, &right.left)
+    }
+    _ => false,
+  }
+}
+
+/// Returns the text of an operand of the comparison, wrapped in parentheses if
+/// it would be grouped differently on the other side, e.g. `a == b` in
+/// `a == b == 1`.
+fn operand_text(operand: &AstView::Expr, op: BinaryOp) -> String {
+  match operand {
+    AstView::Expr::Bin(bin_expr)
+      if bin_expr.op().precedence() <= op.precedence() =>
+    {
+      format!("({})", operand.text())
+    }
+    _ => operand.text().to_string(),
+  }
+}
+
+struct YodaHandler {
+  options: YodaOptions,
+}
+
+impl Handler for YodaHandler {
+  fn bin_expr(&mut self, bin_expr: &AstView::BinExpr, ctx: &mut Context) {
+    let op = bin_expr.op();
+    if !is_comparison(op) {
+      return;
+    }
+
+    let (message, hint) = match self.options.mode {
+      YodaMode::Never
+        if is_literal(&bin_expr.left) && !is_literal(&bin_expr.right) =>
+      {
+        (
+          YodaMessage::LiteralOnRight(op.to_string()),
+          YodaHint::LiteralOnRight,
+        )
+      }
+      YodaMode::Always
+        if is_literal(&bin_expr.right) && !is_literal(&bin_expr.left) =>
+      {
+        (
+          YodaMessage::LiteralOnLeft(op.to_string()),
+          YodaHint::LiteralOnLeft,
+        )
+      }
+      _ => return,
+    };
+
+    if self.options.except_range && is_range_test(bin_expr) {
+      return;
+    }
+
+    let span = bin_expr.span();
+    // Comments would be lost when rewriting, so only offer a fix for
+    // comparisons without any.
+    let has_comments = ctx
+      .all_comments()
+      .any(|comment| span.contains(comment.span));
+    if has_comments {
+      ctx.add_diagnostic_with_hint(span, CODE, message, hint);
+      return;
+    }
+
+    let swapped = format!(
+      "{} {} {}",
+      operand_text(&bin_expr.right, op),
+      flip(op),
+      operand_text(&bin_expr.left, op)
+    );
+    ctx.add_diagnostic_with_fix(
+      span,
+      CODE,
+      message,
+      hint,
+      FIX_DESC,
+      vec![(span, swapped)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn yoda_valid() {
+    assert_lint_ok! {
+      Yoda,
+      "if (value === 'red') {}",
+      "if (value === value) {}",
+      "if (value != 5) {}",
+      "if (5 & foo) {}",
+      "if (5 === 5) {}",
+      "if (x < -1) {}",
+      "if (x <= `foo`) {}",
+      "if (`${foo}` === x) {}",
+      "if (-x === 1) {}",
+      "if (a + 1 > b) {}",
+    };
+
+    assert_lint_ok! {
+      Yoda,
+      options: { "mode": "always" },
+      "if ('red' === value) {}",
+      "if (5 > value) {}",
+      "if (value === value) {}",
+      "if (-1 < x) {}",
+    };
+
+    assert_lint_ok! {
+      Yoda,
+      options: { "exceptRange": true },
+      "if (0 < x && x <= 1) {}",
+      "if (x < 0 || 1 <= x) {}",
+      "if (0 <= x && x < 0) {}",
+      "if (a < x && x < b) {}",
+      "if (0 <= a.b && a.b < 1) {}",
+      "if (-1 < x && x < 0) {}",
+    };
+  }
+
+  #[test]
+  fn yoda_invalid() {
+    assert_lint_err! {
+      Yoda,
+      "if ('red' == value) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "=="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (value == 'red') {}"),
+        }
+      ],
+      "if (5 === count) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "==="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (count === 5) {}"),
+        }
+      ],
+      "if (null !== foo.bar) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "!=="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (foo.bar !== null) {}"),
+        }
+      ],
+      "if (5 < value) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<"),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (value > 5) {}"),
+        }
+      ],
+      "if (-1 >= value) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, ">="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (value <= -1) {}"),
+        }
+      ],
+      "if (`red` <= value()) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (value() >= `red`) {}"),
+        }
+      ],
+      "if (0 < x && x < 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<"),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (x > 0 && x < 1) {}"),
+        }
+      ],
+      "if (5 /* five */ === count) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "==="),
+          hint: YodaHint::LiteralOnRight,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      Yoda,
+      options: { "mode": "always" },
+      "if (value === 'red') {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnLeft, "==="),
+          hint: YodaHint::LiteralOnLeft,
+          fix: (FIX_DESC, "if ('red' === value) {}"),
+        }
+      ],
+      "if (value > 5) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnLeft, ">"),
+          hint: YodaHint::LiteralOnLeft,
+          fix: (FIX_DESC, "if (5 < value) {}"),
+        }
+      ],
+      "if (a == b == 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnLeft, "=="),
+          hint: YodaHint::LiteralOnLeft,
+          fix: (FIX_DESC, "if (1 == (a == b)) {}"),
+        }
+      ],
+      "if (a < b < 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnLeft, "<"),
+          hint: YodaHint::LiteralOnLeft,
+          fix: (FIX_DESC, "if (1 > (a < b)) {}"),
+        }
+      ],
+      "if (a + b > 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnLeft, ">"),
+          hint: YodaHint::LiteralOnLeft,
+          fix: (FIX_DESC, "if (1 < a + b) {}"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      Yoda,
+      options: { "exceptRange": true },
+      "if (1 < x && x < 0) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<"),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (x > 1 && x < 0) {}"),
+        }
+      ],
+      "if (0 < x && y < 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<"),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (x > 0 && y < 1) {}"),
+        }
+      ],
+      "if (0 < x || x < 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "<"),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (x > 0 || x < 1) {}"),
+        }
+      ],
+      "if (0 === x && x < 1) {}": [
+        {
+          col: 4,
+          message: variant!(YodaMessage, LiteralOnRight, "==="),
+          hint: YodaHint::LiteralOnRight,
+          fix: (FIX_DESC, "if (x === 0 && x < 1) {}"),
+        }
+      ],
+    };
+  }
+}
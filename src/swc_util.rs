@@ -1,10 +1,21 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::context::Context;
 use crate::scopes::Scope;
+use dprint_swc_ecma_ast_view::{
+  self as AstView, NodeTrait, SpannedExt, UnaryOp,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+use swc_common::Span;
 use swc_ecmascript::ast::{
-  ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr, PatOrExpr,
-  PrivateName, Prop, PropName, PropOrSpread, Str, Tpl,
+  ComputedPropName, ExportNamedSpecifier, Expr, ExprOrSpread, ExprOrSuper,
+  Ident, JSXElement, JSXElementName, JSXFragment, JSXObject, Lit, MemberExpr,
+  PatOrExpr, PrivateName, Prop, PropName, PropOrSpread, Str, Tpl, TsEntityName,
+  TsExprWithTypeArgs, TsType,
 };
-use swc_ecmascript::utils::{find_ids, ident::IdentLike};
+use swc_ecmascript::utils::{find_ids, ident::IdentLike, ExprExt, Id};
+use swc_ecmascript::visit::{Node, Visit, VisitWith};
 
 /// Extracts regex string from an expression, using ScopeManager.
 /// If the passed expression is not regular expression, this will return `None`.
@@ -161,3 +172,359 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Returns the name of the property accessed by the member expression if it's
+/// known statically, e.g. for `foo.bar`, `foo["bar"]` and `` foo[`bar`] ``,
+/// but not for `foo[bar]`.
+pub(crate) fn static_member_prop_name(
+  member_expr: &MemberExpr,
+) -> Option<String> {
+  if member_expr.computed && matches!(&*member_expr.prop, Expr::Ident(_)) {
+    return None;
+  }
+  member_expr.string_repr()
+}
+
+/// Checks if the two nodes consist of the same sequence of tokens.
+pub(crate) fn equal_tokens<'a>(
+  a: &impl NodeTrait<'a>,
+  b: &impl NodeTrait<'a>,
+) -> bool {
+  a.tokens()
+    .iter()
+    .map(|t| &t.token)
+    .eq(b.tokens().iter().map(|t| &t.token))
+}
+
+/// Strips parentheses and optional chaining wrapping the given expression.
+pub(crate) fn skip_wrappers<'a>(expr: &AstView::Expr<'a>) -> AstView::Expr<'a> {
+  match expr {
+    AstView::Expr::Paren(paren) => skip_wrappers(&paren.expr),
+    AstView::Expr::OptChain(opt_chain) => skip_wrappers(&opt_chain.expr),
+    _ => *expr,
+  }
+}
+
+/// Skips the parentheses around an expression or a type, returning the
+/// outermost parenthesized node and its parent.
+pub(crate) fn skip_paren_ancestors<'a>(
+  mut node: AstView::Node<'a>,
+) -> (AstView::Node<'a>, Option<AstView::Node<'a>>) {
+  loop {
+    match node.parent() {
+      Some(parent @ AstView::Node::ParenExpr(_))
+      | Some(parent @ AstView::Node::TsParenthesizedType(_)) => node = parent,
+      parent => return (node, parent),
+    }
+  }
+}
+
+/// Returns the closest function or arrow function that contains the node.
+pub(crate) fn enclosing_function<'a>(
+  node: AstView::Node<'a>,
+) -> Option<AstView::Node<'a>> {
+  node.ancestors().find(|ancestor| {
+    matches!(
+      ancestor,
+      AstView::Node::Function(_) | AstView::Node::ArrowExpr(_)
+    )
+  })
+}
+
+/// Returns the expression statement or the arrow function if the node is at
+/// the very start of the statement or of the arrow function's body, where e.g.
+/// `{` would be parsed as a block.
+pub(crate) fn enclosing_stmt_or_arrow_at_start<'a>(
+  node: AstView::Node<'a>,
+) -> Option<AstView::Node<'a>> {
+  let lo = node.lo();
+  for ancestor in node.ancestors() {
+    match ancestor {
+      AstView::Node::ExprStmt(_) => return Some(ancestor),
+      AstView::Node::ArrowExpr(arrow_expr) => {
+        return match arrow_expr.body {
+          AstView::BlockStmtOrExpr::Expr(body) if body.lo() == lo => {
+            Some(ancestor)
+          }
+          _ => None,
+        };
+      }
+      _ if ancestor.lo() != lo => return None,
+      _ => {}
+    }
+  }
+  None
+}
+
+/// Checks if the identifier refers to a global, i.e. isn't declared anywhere in
+/// the module.
+pub(crate) fn is_global(ident: &AstView::Ident, scope: &Scope) -> bool {
+  scope.var(&ident.inner.to_id()).is_none()
+}
+
+/// Checks if the expression is `null`, `undefined` or `void` something.
+pub(crate) fn is_null_or_undefined(expr: &AstView::Expr) -> bool {
+  match skip_wrappers(expr) {
+    AstView::Expr::Lit(AstView::Lit::Null(_)) => true,
+    AstView::Expr::Ident(ident) => *ident.sym() == *"undefined",
+    AstView::Expr::Unary(unary) => unary.op() == UnaryOp::Void,
+    _ => false,
+  }
+}
+
+/// Checks if evaluating the expression may have side effects, like calling,
+/// assigning or awaiting something.
+///
+/// This builds on `ExprExt::may_have_side_effects`, but also looks into
+/// property accesses, template literals and `new.target` instead of assuming
+/// that they have side effects, treats `delete` as one, and doesn't panic on
+/// JSX, which is assumed to have side effects.
+pub(crate) fn may_have_side_effects(expr: &Expr) -> bool {
+  let prop_name_has_side_effects = |key: &PropName| matches!(key, PropName::Computed(computed) if may_have_side_effects(&computed.expr));
+  match expr {
+    Expr::Member(member_expr) => {
+      let obj_has_side_effects = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => may_have_side_effects(obj),
+        ExprOrSuper::Super(_) => false,
+      };
+      obj_has_side_effects
+        || (member_expr.computed && may_have_side_effects(&member_expr.prop))
+    }
+    Expr::Tpl(tpl) => tpl.exprs.iter().any(|expr| may_have_side_effects(expr)),
+    Expr::MetaProp(_) => false,
+    Expr::Unary(unary_expr) => {
+      unary_expr.op == UnaryOp::Delete || may_have_side_effects(&unary_expr.arg)
+    }
+    Expr::Array(array_lit) => array_lit
+      .elems
+      .iter()
+      .flatten()
+      .any(|elem| may_have_side_effects(&elem.expr)),
+    Expr::Object(object_lit) => {
+      object_lit.props.iter().any(|prop| match prop {
+        PropOrSpread::Spread(spread) => may_have_side_effects(&spread.expr),
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Shorthand(_) => false,
+          Prop::KeyValue(key_value) => {
+            prop_name_has_side_effects(&key_value.key)
+              || may_have_side_effects(&key_value.value)
+          }
+          Prop::Assign(assign) => may_have_side_effects(&assign.value),
+          Prop::Getter(getter) => prop_name_has_side_effects(&getter.key),
+          Prop::Setter(setter) => prop_name_has_side_effects(&setter.key),
+          Prop::Method(method) => prop_name_has_side_effects(&method.key),
+        },
+      })
+    }
+    Expr::Bin(bin_expr) => {
+      may_have_side_effects(&bin_expr.left)
+        || may_have_side_effects(&bin_expr.right)
+    }
+    Expr::Cond(cond_expr) => {
+      may_have_side_effects(&cond_expr.test)
+        || may_have_side_effects(&cond_expr.cons)
+        || may_have_side_effects(&cond_expr.alt)
+    }
+    Expr::Seq(seq_expr) => seq_expr
+      .exprs
+      .iter()
+      .any(|expr| may_have_side_effects(expr)),
+    Expr::Paren(paren_expr) => may_have_side_effects(&paren_expr.expr),
+    Expr::OptChain(opt_chain) => may_have_side_effects(&opt_chain.expr),
+    Expr::TsAs(ts_as) => may_have_side_effects(&ts_as.expr),
+    Expr::TsNonNull(ts_non_null) => may_have_side_effects(&ts_non_null.expr),
+    Expr::TsTypeAssertion(assertion) => may_have_side_effects(&assertion.expr),
+    Expr::TsConstAssertion(assertion) => may_have_side_effects(&assertion.expr),
+    Expr::JSXMember(_)
+    | Expr::JSXNamespacedName(_)
+    | Expr::JSXEmpty(_)
+    | Expr::JSXElement(_)
+    | Expr::JSXFragment(_) => true,
+    _ => expr.may_have_side_effects(),
+  }
+}
+
+fn leftmost_ident(entity_name: &TsEntityName) -> Id {
+  match entity_name {
+    TsEntityName::TsQualifiedName(qualified) => leftmost_ident(&qualified.left),
+    TsEntityName::Ident(ident) => ident.to_id(),
+  }
+}
+
+/// Collects where identifiers are referenced, separating references in type
+/// positions from ones that need the value at runtime.
+pub(crate) struct UsageCollector {
+  values: HashSet<Id>,
+  types: HashSet<Id>,
+  type_depth: usize,
+  has_jsx: bool,
+  /// The identifier JSX elements and fragments use implicitly, if it's known.
+  jsx_factory: Option<String>,
+}
+
+static JSX_PRAGMA: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"@jsx\s+([\w$.]+)").unwrap());
+
+impl UsageCollector {
+  /// Creates a collector for a file whose JSX uses the given factory, like
+  /// `React.createElement` or `h`. A `/** @jsx h */` pragma in the file takes
+  /// precedence.
+  pub(crate) fn new(ctx: &Context, jsx_factory: Option<&str>) -> Self {
+    let pragma = ctx.all_comments().find_map(|comment| {
+      JSX_PRAGMA
+        .captures(&comment.text)
+        .and_then(|captures| captures.get(1))
+    });
+    let jsx_factory = pragma.map(|m| m.as_str()).or(jsx_factory);
+    Self {
+      values: HashSet::new(),
+      types: HashSet::new(),
+      type_depth: 0,
+      has_jsx: false,
+      jsx_factory: jsx_factory
+        .and_then(|factory| factory.split('.').next())
+        .map(|ident| ident.to_string()),
+    }
+  }
+
+  /// Checks if the identifier is referenced as a value, including implicitly
+  /// by JSX. Without knowing the JSX factory, every identifier in a file with
+  /// JSX may be it.
+  pub(crate) fn is_used_as_value(&self, ident: &Ident) -> bool {
+    if self.values.contains(&ident.to_id()) {
+      return true;
+    }
+    match &self.jsx_factory {
+      Some(factory) => self.has_jsx && *ident.sym == **factory,
+      None => self.has_jsx,
+    }
+  }
+
+  /// Checks if the identifier is referenced in a type position.
+  pub(crate) fn is_used_as_type(&self, ident: &Ident) -> bool {
+    self.types.contains(&ident.to_id())
+  }
+
+  fn in_type(&mut self, f: impl FnOnce(&mut Self)) {
+    self.type_depth += 1;
+    f(self);
+    self.type_depth -= 1;
+  }
+}
+
+impl Visit for UsageCollector {
+  fn visit_ts_type(&mut self, ty: &TsType, _: &dyn Node) {
+    self.in_type(|collector| ty.visit_children_with(collector));
+  }
+
+  fn visit_ts_expr_with_type_args(
+    &mut self,
+    n: &TsExprWithTypeArgs,
+    _: &dyn Node,
+  ) {
+    self.in_type(|collector| n.visit_children_with(collector));
+  }
+
+  fn visit_ts_entity_name(&mut self, entity_name: &TsEntityName, _: &dyn Node) {
+    let id = leftmost_ident(entity_name);
+    if self.type_depth > 0 {
+      self.types.insert(id);
+    } else {
+      // e.g. `import Foo = Bar.Baz`
+      self.values.insert(id);
+    }
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    match expr {
+      // Expressions in types, like computed keys, still need the value.
+      Expr::Ident(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      _ => expr.visit_children_with(self),
+    }
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _: &dyn Node) {
+    match prop {
+      Prop::Shorthand(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_export_named_specifier(
+    &mut self,
+    export: &ExportNamedSpecifier,
+    _: &dyn Node,
+  ) {
+    self.values.insert(export.orig.to_id());
+  }
+
+  fn visit_jsx_element(&mut self, element: &JSXElement, _: &dyn Node) {
+    self.has_jsx = true;
+    element.visit_children_with(self);
+  }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment, _: &dyn Node) {
+    self.has_jsx = true;
+    fragment.visit_children_with(self);
+  }
+
+  fn visit_jsx_element_name(&mut self, name: &JSXElementName, _: &dyn Node) {
+    match name {
+      JSXElementName::Ident(ident) => {
+        self.values.insert(ident.to_id());
+      }
+      JSXElementName::JSXMemberExpr(member_expr) => {
+        let mut obj = &member_expr.obj;
+        while let JSXObject::JSXMemberExpr(inner) = obj {
+          obj = &inner.obj;
+        }
+        if let JSXObject::Ident(ident) = obj {
+          self.values.insert(ident.to_id());
+        }
+      }
+      JSXElementName::JSXNamespacedName(_) => {}
+    }
+  }
+}
+
+/// Returns the change that turns the import declaration into `import type`,
+/// unless it combines a default import with other ones, since
+/// `import type Foo, { Bar }` isn't allowed.
+pub(crate) fn import_type_fix(
+  import_decl: &AstView::ImportDecl,
+) -> Option<(Span, String)> {
+  let has_default = import_decl
+    .specifiers
+    .iter()
+    .any(|specifier| matches!(specifier, AstView::ImportSpecifier::Default(_)));
+  if has_default && import_decl.specifiers.len() > 1 {
+    return None;
+  }
+  let import_keyword = import_decl.tokens()[0].span;
+  Some((
+    Span::new(import_keyword.hi, import_keyword.hi, Default::default()),
+    " type".to_string(),
+  ))
+}
+
+/// Returns the local binding of an import specifier.
+pub(crate) fn specifier_local<'a>(
+  specifier: &AstView::ImportSpecifier<'a>,
+) -> &'a AstView::Ident<'a> {
+  match specifier {
+    AstView::ImportSpecifier::Named(named) => named.local,
+    AstView::ImportSpecifier::Default(default) => default.local,
+    AstView::ImportSpecifier::Namespace(namespace) => namespace.local,
+  }
+}
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 
 use crate::ast_parser;
-use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::{LintDiagnostic, LintFix};
 use crate::linter::LinterBuilder;
 use crate::rules::LintRule;
 use dprint_swc_ecma_ast_view::TokenAndSpan;
@@ -10,14 +10,23 @@ use std::rc::Rc;
 use swc_common::comments::SingleThreadedComments;
 use swc_common::SourceMap;
 use swc_ecmascript::ast::Program;
-use swc_ecmascript::parser::{Syntax, TsConfig};
 
 #[macro_export]
 macro_rules! assert_lint_ok {
+  ($rule:ty, options: $options:tt, $($test:tt),+ $(,)?) => {
+    $(
+      let (src, filename) = parse_ok_test!($test);
+      $crate::test_util::assert_lint_ok::<$rule>(
+        src,
+        filename,
+        Some(serde_json::json!($options)),
+      );
+    )*
+  };
   ($rule:ty, $($test:tt),+ $(,)?) => {
     $(
       let (src, filename) = parse_ok_test!($test);
-      $crate::test_util::assert_lint_ok::<$rule>(src, filename);
+      $crate::test_util::assert_lint_ok::<$rule>(src, filename, None);
     )*
   };
 }
@@ -26,6 +35,7 @@ macro_rules! assert_lint_ok {
 macro_rules! assert_lint_err {
   (
     $rule:ty,
+    options: $options:tt,
     $($src:literal : $test:tt),+
     $(,)?
   ) => {
@@ -35,6 +45,23 @@ macro_rules! assert_lint_err {
         $src,
         errors,
         filename,
+        Some(serde_json::json!($options)),
+      );
+      tester.run();
+    )*
+  };
+  (
+    $rule:ty,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let (errors, filename) = parse_err_test!($test);
+      let tester = $crate::test_util::LintErrTester::<$rule>::new(
+        $src,
+        errors,
+        filename,
+        None,
       );
       tester.run();
     )*
@@ -93,7 +120,7 @@ macro_rules! parse_err_test {
     }
   ) => {{
     let (errors, _) = parse_err_test!($errors);
-    (errors, $filename)
+    (errors, Some($filename))
   }};
 }
 
@@ -102,6 +129,7 @@ pub struct LintErrTester<T: LintRule + 'static> {
   src: &'static str,
   errors: Vec<LintErr>,
   filename: String,
+  options: Option<serde_json::Value>,
   rule: PhantomData<T>,
 }
 
@@ -110,6 +138,7 @@ impl<T: LintRule + 'static> LintErrTester<T> {
     src: &'static str,
     errors: Vec<LintErr>,
     filename: Option<&str>,
+    options: Option<serde_json::Value>,
   ) -> Self {
     Self {
       src,
@@ -118,12 +147,13 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         Some(f) => f.to_string(),
         None => "deno_lint_err_test.ts".to_string(),
       },
+      options,
       rule: PhantomData,
     }
   }
 
   pub fn run(self) {
-    let rule = T::new();
+    let rule = create_rule::<T>(self.options.as_ref());
     let rule_code = rule.code();
     let diagnostics = lint(rule, self.src, self.filename);
     assert_eq!(
@@ -141,6 +171,8 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         col,
         message,
         hint,
+        fix,
+        related,
       } = error;
       assert_diagnostic_2(
         diagnostic,
@@ -151,6 +183,20 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         message,
         hint.as_deref(),
       );
+      match fix {
+        Some((description, fixed_src)) => {
+          assert_fix(diagnostic, self.src, description, fixed_src)
+        }
+        None => assert!(
+          diagnostic.fixes.is_empty(),
+          "no fix expected, but got {:?}.\n\nsource:\n{}\n",
+          diagnostic.fixes,
+          self.src,
+        ),
+      }
+      if let Some((line, col, message)) = related {
+        assert_related(diagnostic, self.src, *line, *col, message);
+      }
     }
   }
 }
@@ -161,6 +207,11 @@ pub struct LintErr {
   pub col: usize,
   pub message: String,
   pub hint: Option<String>,
+  /// Description of the expected fix and the source code after applying it,
+  /// or `None` if the diagnostic must not have a fix
+  pub fix: Option<(String, String)>,
+  /// Line, column and message of the expected related location
+  pub related: Option<(usize, usize, String)>,
 }
 
 #[derive(Default)]
@@ -169,6 +220,8 @@ pub struct LintErrBuilder {
   col: Option<usize>,
   message: Option<String>,
   hint: Option<String>,
+  fix: Option<(String, String)>,
+  related: Option<(usize, usize, String)>,
 }
 
 impl LintErrBuilder {
@@ -198,24 +251,45 @@ impl LintErrBuilder {
     self
   }
 
+  pub fn fix(
+    &mut self,
+    (description, fixed_src): (impl ToString, impl ToString),
+  ) -> &mut Self {
+    self.fix = Some((description.to_string(), fixed_src.to_string()));
+    self
+  }
+
+  pub fn related(
+    &mut self,
+    (line, col, message): (usize, usize, impl ToString),
+  ) -> &mut Self {
+    assert!(line >= 1);
+    self.related = Some((line, col, message.to_string()));
+    self
+  }
+
   pub fn build(self) -> LintErr {
     LintErr {
       line: self.line.unwrap_or(1),
       col: self.col.unwrap_or(0),
       message: self.message.unwrap_or_else(|| "".to_string()),
       hint: self.hint,
+      fix: self.fix,
+      related: self.related,
     }
   }
 }
 
-fn get_ts_config_with_tsx() -> Syntax {
-  let ts_config = TsConfig {
-    dynamic_import: true,
-    decorators: true,
-    tsx: true,
-    ..Default::default()
-  };
-  Syntax::Typescript(ts_config)
+fn create_rule<T: LintRule + 'static>(
+  options: Option<&serde_json::Value>,
+) -> Box<T> {
+  let mut rule = T::new();
+  if let Some(options) = options {
+    if let Err(err) = rule.set_options(options) {
+      panic!("Failed to set options {} to the rule: {}", options, err);
+    }
+  }
+  rule
 }
 
 fn lint(
@@ -227,7 +301,7 @@ fn lint(
     .lint_unused_ignore_directives(false)
     .lint_unknown_rules(false)
     .syntax(if filename.ends_with(".tsx") {
-      get_ts_config_with_tsx()
+      ast_parser::get_default_tsx_config()
     } else {
       ast_parser::get_default_ts_config()
     })
@@ -308,11 +382,84 @@ fn assert_diagnostic_2(
   );
 }
 
+fn apply_fix(source: &str, fix: &LintFix) -> String {
+  let mut changes = fix.changes.iter().collect::<Vec<_>>();
+  changes.sort_by_key(|change| change.range.start.byte_pos);
+  let mut fixed = source.to_string();
+  for change in changes.into_iter().rev() {
+    fixed.replace_range(
+      change.range.start.byte_pos..change.range.end.byte_pos,
+      &change.new_text,
+    );
+  }
+  fixed
+}
+
+fn assert_fix(
+  diagnostic: &LintDiagnostic,
+  source: &str,
+  description: &str,
+  fixed_src: &str,
+) {
+  let fix = match diagnostic.fixes.as_slice() {
+    [fix] => fix,
+    fixes => panic!(
+      "Exactly one fix is expected, but got {}\n\nsource:\n{}\n",
+      fixes.len(),
+      source
+    ),
+  };
+  assert_eq!(
+    description, fix.description,
+    "Fix description is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    description, fix.description, source
+  );
+  assert_eq!(
+    fixed_src,
+    apply_fix(source, fix),
+    "Fixed source is not as expected\n\nsource:\n{}\n",
+    source
+  );
+}
+
+fn assert_related(
+  diagnostic: &LintDiagnostic,
+  source: &str,
+  line: usize,
+  col: usize,
+  message: &str,
+) {
+  let related = match diagnostic.related.as_slice() {
+    [related] => related,
+    related => panic!(
+      "Exactly one related location is expected, but got {}\n\nsource:\n{}\n",
+      related.len(),
+      source
+    ),
+  };
+  assert_eq!(
+    (line, col),
+    (related.range.start.line, related.range.start.col),
+    "Related location is expected to be at {}:{}, but got {}:{}\n\nsource:\n{}\n",
+    line,
+    col,
+    related.range.start.line,
+    related.range.start.col,
+    source
+  );
+  assert_eq!(
+    message, related.message,
+    "Related message is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    message, related.message, source
+  );
+}
+
 pub fn assert_lint_ok<T: LintRule + 'static>(
   source: &str,
   filename: Option<&str>,
+  options: Option<serde_json::Value>,
 ) {
-  let rule = T::new();
+  let rule = create_rule::<T>(options.as_ref());
   let filename = match filename {
     Some(f) => f.to_string(),
     None => "deno_lint_ok_test.ts".to_string(),